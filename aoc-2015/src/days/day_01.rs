@@ -1,8 +1,10 @@
 //!day_01.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     input: &'a str,
 }
 
@@ -44,29 +46,29 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_01.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_01 part 1: {result_part1}");
-    assert_eq!(result_part1, 232);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_01 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_783);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_01_part_1() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_01_example_part_1.txt");
+        let input = read_file(2015, "day_01_example_part_1.txt")?;
 
         let solutions = [0, 0, 3, 3, 3, -1, -1, -3, -3];
         assert_eq!(input.lines().count(), solutions.len());
@@ -84,7 +86,7 @@ mod tests {
 
     #[test]
     fn test_example_day_01_part_2() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_01_example_part_2.txt");
+        let input = read_file(2015, "day_01_example_part_2.txt")?;
 
         let solutions = [1, 5];
         assert_eq!(input.lines().count(), solutions.len());
@@ -1,10 +1,12 @@
 //!day_04.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use rayon::prelude::*;
 use std::collections::BTreeSet;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     input: String,
 }
 
@@ -68,29 +70,30 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_04.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_04 part 1: {result_part1}");
-    assert_eq!(result_part1, 254_575);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(result_part1);
-    println!("result day_04 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_038_736);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let start = self.solution_part_1();
+        Ok(self.solution_part_2(start))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_04() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_04_example.txt");
+        let input = read_file(2015, "day_04_example.txt")?;
 
         let solutions = [609043, 1048970];
 
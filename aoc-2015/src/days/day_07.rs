@@ -1,7 +1,9 @@
 //!day_07.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 enum Source<'a> {
@@ -86,7 +88,7 @@ impl<'a> Action<'a> {
     }
 }
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     mapping: HashMap<&'a str, Action<'a>>,
 }
 
@@ -117,29 +119,30 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_07.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1("a");
-    println!("result day_07 part 1: {result_part1}");
-    assert_eq!(result_part1, 3_176);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(result_part1);
-    println!("result day_07 part 2: {result_part2}");
-    assert_eq!(result_part2, 14_710);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1("a"))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let a_part_1 = self.solution_part_1("a");
+        Ok(self.solution_part_2(a_part_1))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_07() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_07_example.txt");
+        let input = read_file(2015, "day_07_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1("d");
@@ -1,14 +1,20 @@
 //!day_08.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     input: &'a str,
+    result_part2: Option<u64>,
 }
 
 impl<'a> From<&'a str> for ChallengeInput<'a> {
     fn from(input: &'a str) -> Self {
-        ChallengeInput { input }
+        ChallengeInput {
+            input,
+            result_part2: None,
+        }
     }
 }
 
@@ -60,28 +66,33 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_08.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_08 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_333);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_08 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_046);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_08() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_08_example.txt");
+        let input = read_file(2015, "day_08_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
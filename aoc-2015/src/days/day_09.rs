@@ -1,11 +1,14 @@
 //!day_09.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     nodes: HashSet<&'a str>,
     edges: HashMap<(&'a str, &'a str), u64>,
+    result_part2: Option<u64>,
 }
 
 impl<'a> From<&'a str> for ChallengeInput<'a> {
@@ -22,7 +25,11 @@ impl<'a> From<&'a str> for ChallengeInput<'a> {
             edges.insert((min, max), distance.parse().unwrap());
         }
 
-        ChallengeInput { nodes, edges }
+        ChallengeInput {
+            nodes,
+            edges,
+            result_part2: None,
+        }
     }
 }
 
@@ -50,28 +57,33 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_09.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_09 part 1: {result_part1}");
-    assert_eq!(result_part1, 117);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_09 part 2: {result_part2}");
-    assert_eq!(result_part2, 909);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_09() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_09_example.txt");
+        let input = read_file(2015, "day_09_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
@@ -1,10 +1,12 @@
 //!day_11.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     pw: [u8; 8],
 }
 
@@ -68,27 +70,27 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_11.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_11 part 1: {result_part1}");
-    assert_eq!(result_part1, "vzbxxyzz");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let mut challenge = ChallengeInput::from(result_part1.as_str());
-    challenge.inc();
-    let result_part2 = challenge.solution_part_1();
-    println!("result day_11 part 2: {result_part2}");
-    assert_eq!(result_part2, "vzcaabcc");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let mut challenge = ChallengeInput::from(self.solution_part_1().as_str());
+        challenge.inc();
+        Ok(challenge.solution_part_1())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_inc() {
@@ -111,7 +113,7 @@ mod tests {
 
     #[test]
     fn test_example_day_11() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_11_example.txt");
+        let input = read_file(2015, "day_11_example.txt")?;
 
         let solutions = ["abcdffaa", "ghjaabcc"];
 
@@ -1,9 +1,11 @@
 //!day_12.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use serde_json::Value;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     json: Value,
 }
 
@@ -42,19 +44,18 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_12.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_12 part 1: {result_part1}");
-    assert_eq!(result_part1, 191_164);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_12 part 2: {result_part2}");
-    assert_eq!(result_part2, 87_842);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 // no testing because for parsing we use serde_json
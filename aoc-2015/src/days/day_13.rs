@@ -1,9 +1,11 @@
 //!day_13.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     nodes: HashSet<&'a str>,
     edges: HashMap<(&'a str, &'a str), i64>,
 }
@@ -73,29 +75,29 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_13.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_13 part 1: {result_part1}");
-    assert_eq!(result_part1, 709);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_13 part 2: {result_part2}");
-    assert_eq!(result_part2, 668);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_13() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_13_example.txt");
+        let input = read_file(2015, "day_13_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
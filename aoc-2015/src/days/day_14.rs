@@ -1,6 +1,8 @@
 //!day_14.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 struct Reindeer {
@@ -31,7 +33,7 @@ impl Reindeer {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     reindeers: Vec<Reindeer>,
 }
 
@@ -64,29 +66,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_14.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(2_503);
-    println!("result day_14 part 1: {result_part1}");
-    assert_eq!(result_part1, 2_640);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(2_503);
-    println!("result day_14 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_102);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(2_503))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2(2_503))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_14() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_14_example.txt");
+        let input = read_file(2015, "day_14_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1(1_000);
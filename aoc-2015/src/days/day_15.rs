@@ -1,7 +1,9 @@
 //!day_15.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_algo_collection::collect_all_n_from_m_elements;
+use std::fmt::Display;
 use std::{
     collections::{BTreeSet, HashSet},
     ops::Add,
@@ -70,7 +72,7 @@ impl Ingredient {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     ingredients: Vec<Ingredient>,
 }
 
@@ -134,25 +136,25 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_15.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_15 part 1: {result_part1}");
-    assert_eq!(result_part1, 21_367_368);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_15 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_766_400);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_collect_groups() {
@@ -168,7 +170,7 @@ mod tests {
 
     #[test]
     fn test_example_day_15() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_15_example.txt");
+        let input = read_file(2015, "day_15_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,7 +1,9 @@
 //!day_16.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug, Clone)]
 struct Sue<'a> {
@@ -47,7 +49,7 @@ impl<'a> Sue<'a> {
     }
 }
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     mfcsam: Sue<'a>,
     all_sues: HashMap<u16, Sue<'a>>,
 }
@@ -88,17 +90,16 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_16.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_16 part 1: {result_part1}");
-    assert_eq!(result_part1, 373);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_16 part 2: {result_part2}");
-    assert_eq!(result_part2, 260);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
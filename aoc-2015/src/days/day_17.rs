@@ -1,16 +1,20 @@
 //!day_17.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     containers: Vec<u64>,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
         ChallengeInput {
             containers: value.lines().filter_map(|v| v.parse().ok()).collect(),
+            result_part2: None,
         }
     }
 }
@@ -58,28 +62,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_17.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2(150);
-    println!("result day_17 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_638);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_17 part 2: {result_part2}");
-    assert_eq!(result_part2, 17);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2(150);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_17() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_17_example.txt");
+        let input = read_file(2015, "day_17_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2(25);
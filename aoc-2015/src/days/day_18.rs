@@ -1,10 +1,12 @@
 //!day_18.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::HashSet;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     map: HashSet<Point>,
     max_x: i64,
     max_y: i64,
@@ -90,29 +92,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_18.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(100);
-    println!("result day_18 part 1: {result_part1}");
-    assert_eq!(result_part1, 821);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(100);
-    println!("result day_18 part 2: {result_part2}");
-    assert_eq!(result_part2, 886);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(100))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2(100))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_18_example.txt");
+        let input = read_file(2015, "day_18_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1(4);
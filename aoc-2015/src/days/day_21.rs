@@ -1,7 +1,10 @@
 //!day_21.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use aoc_common::read_file;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Default, Debug)]
 struct Item {
@@ -43,6 +46,14 @@ impl<'a> From<&'a str> for ItemShop<'a> {
     }
 }
 
+impl ItemShop<'static> {
+    fn load() -> Result<Self> {
+        let mut item_shop = ItemShop::from(read_file(2015, "day_21_items.txt")?);
+        item_shop.add_no_armor_and_no_ring();
+        Ok(item_shop)
+    }
+}
+
 impl<'a> ItemShop<'a> {
     fn add_no_armor_and_no_ring(&mut self) {
         self.shop
@@ -114,9 +125,10 @@ impl Character {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     me: Character,
     opp: Character,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -124,6 +136,7 @@ impl From<&str> for ChallengeInput {
         ChallengeInput {
             me: Character::default(),
             opp: Character::from(value),
+            result_part2: None,
         }
     }
 }
@@ -172,22 +185,22 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_21.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let item_shop = include_str!("../../../../aoc_input/aoc-2015/day_21_items.txt");
-    let mut item_shop = ItemShop::from(item_shop);
-    item_shop.add_no_armor_and_no_ring();
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2(&item_shop);
-    println!("result day_21 part 1: {result_part1}");
-    assert_eq!(result_part1, 78);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_21 part 2: {result_part2}");
-    assert_eq!(result_part2, 148);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2(&ItemShop::load()?);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
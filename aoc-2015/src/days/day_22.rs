@@ -2,10 +2,12 @@
 
 use super::day_21::Character;
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::{BTreeSet, HashSet};
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     me: Character,
     opp: Character,
     shield: Option<u64>,
@@ -162,19 +164,18 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_22.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_22 part 1: {result_part1}");
-    assert_eq!(result_part1, 953);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_22 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_289);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
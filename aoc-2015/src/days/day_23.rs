@@ -1,7 +1,9 @@
 //!day_23.rs
 
 use anyhow::Result;
-use std::collections::HashMap;
+use aoc_common::Solution;
+use aoc_common::register_machine::{Flow, InstructionSet, Machine, Registers};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 enum Instruction {
@@ -34,42 +36,25 @@ impl From<&str> for Instruction {
     }
 }
 
-impl Instruction {
-    // returns offset to index of instructions
-    fn apply_to_registers(&self, registers: &mut HashMap<char, u64>) -> i64 {
+impl InstructionSet for Instruction {
+    type State = ();
+
+    fn execute(self, machine: &mut Machine<Self>) -> Flow {
+        let registers = &mut machine.registers;
         match self {
-            Instruction::Hlf(r) => {
-                registers.entry(*r).and_modify(|v| *v /= 2).or_insert(0);
-                1
-            }
-            Instruction::Tpl(r) => {
-                registers.entry(*r).and_modify(|v| *v *= 3).or_insert(0);
-                1
-            }
-            Instruction::Inc(r) => {
-                registers.entry(*r).and_modify(|v| *v += 1).or_insert(1);
-                1
-            }
-            Instruction::Jmp(o) => *o,
-            Instruction::Jie(r, o) => {
-                if registers.get(r).copied().unwrap_or_default() & 1 == 0 {
-                    *o
-                } else {
-                    1
-                }
-            }
-            Instruction::Jio(r, o) => {
-                if registers.get(r).copied().unwrap_or_default() == 1 {
-                    *o
-                } else {
-                    1
-                }
-            }
+            Instruction::Hlf(r) => registers[r] /= 2,
+            Instruction::Tpl(r) => registers[r] *= 3,
+            Instruction::Inc(r) => registers[r] += 1,
+            Instruction::Jmp(o) => return Flow::Jump(o),
+            Instruction::Jie(r, o) if registers[r] & 1 == 0 => return Flow::Jump(o),
+            Instruction::Jio(r, o) if registers[r] == 1 => return Flow::Jump(o),
+            Instruction::Jie(..) | Instruction::Jio(..) => (),
         }
+        Flow::NEXT
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     instructions: Vec<Instruction>,
 }
 
@@ -82,50 +67,43 @@ impl From<&str> for ChallengeInput {
 }
 
 impl ChallengeInput {
-    fn solution_part_1(&self, reg: char) -> u64 {
-        let mut registers: HashMap<char, u64> = HashMap::new();
-        self.execute_instructions(&mut registers);
-        registers.get(&reg).copied().unwrap_or_default()
+    fn solution_part_1(&self, reg: char) -> i64 {
+        self.execute_instructions(0)[reg]
     }
-    fn solution_part_2(&self) -> u64 {
-        let mut registers: HashMap<char, u64> = HashMap::new();
-        registers.insert('a', 1);
-        self.execute_instructions(&mut registers);
-        registers.get(&'b').copied().unwrap_or_default()
+    fn solution_part_2(&self) -> i64 {
+        self.execute_instructions(1)['b']
     }
-    fn execute_instructions(&self, registers: &mut HashMap<char, u64>) {
-        let mut index = 0_i64;
-        while index >= 0
-            && let Some(ins) = self.instructions.get(index as usize)
-        {
-            index += ins.apply_to_registers(registers);
-        }
+    fn execute_instructions(&self, a: i64) -> Registers {
+        let mut machine = Machine::new(self.instructions.clone());
+        machine.registers['a'] = a;
+        machine.run(u64::MAX);
+        machine.registers
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_23.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1('b');
-    println!("result day_23 part 1: {result_part1}");
-    assert_eq!(result_part1, 307);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_23 part 2: {result_part2}");
-    assert_eq!(result_part2, 160);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1('b'))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_23() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_23_example.txt");
+        let input = read_file(2015, "day_23_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1('a');
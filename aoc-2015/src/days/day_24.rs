@@ -1,8 +1,10 @@
 //!day_24.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_algo_collection::collect_all_n_from_m_elements;
 use std::collections::{BTreeSet, HashSet};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
 struct Group {
@@ -11,7 +13,7 @@ struct Group {
     packages: Vec<u64>,
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     weights: HashSet<u64>,
 }
 
@@ -97,29 +99,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2015/day_24.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(3);
-    println!("result day_24 part 1: {result_part1}");
-    assert_eq!(result_part1, 11_266_889_531);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_24 part 2: {result_part2}");
-    assert_eq!(result_part2, 77_387_711);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(3))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn check_sum_is_multiple_of_three() {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_24_example.txt");
+        let input = read_file(2015, "day_24_example.txt").unwrap();
         let example = ChallengeInput::from(input);
 
         let sum_example: u64 = example.weights.iter().sum();
@@ -127,7 +129,7 @@ mod tests {
         assert_eq!(sum_example % 3, 0);
         assert_eq!(sum_example % 4, 0);
 
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_24.txt");
+        let input = read_file(2015, "day_24.txt").unwrap();
         let challenge = ChallengeInput::from(input);
 
         let sum_challenge: u64 = challenge.weights.iter().sum();
@@ -138,7 +140,7 @@ mod tests {
 
     #[test]
     fn test_example_day_24() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2015/day_24_example.txt");
+        let input = read_file(2015, "day_24_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1(3);
@@ -2,34 +2,36 @@
 
 pub mod days;
 
-use anyhow::Result;
+use aoc_common::Registry;
 
-pub fn run() -> Result<()> {
-    days::day_01::solution()?;
-    days::day_02::solution()?;
-    days::day_03::solution()?;
-    days::day_04::solution()?;
-    days::day_05::solution()?;
-    days::day_06::solution()?;
-    days::day_07::solution()?;
-    days::day_08::solution()?;
-    days::day_09::solution()?;
-    days::day_10::solution()?;
-    days::day_11::solution()?;
-    days::day_12::solution()?;
-    days::day_13::solution()?;
-    days::day_14::solution()?;
-    days::day_15::solution()?;
-    days::day_16::solution()?;
-    days::day_17::solution()?;
-    days::day_18::solution()?;
-    days::day_19::solution()?;
-    days::day_20::solution()?;
-    days::day_21::solution()?;
-    days::day_22::solution()?;
-    days::day_23::solution()?;
-    days::day_24::solution()?;
-    days::day_25::solution()?;
-
-    Ok(())
+/// Solvers of all days of 2015.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<days::day_01::ChallengeInput<'static>>(2015, 1)
+        .register::<days::day_02::ChallengeInput>(2015, 2)
+        .register::<days::day_03::ChallengeInput>(2015, 3)
+        .register::<days::day_04::ChallengeInput>(2015, 4)
+        .register::<days::day_05::ChallengeInput<'static>>(2015, 5)
+        .register::<days::day_06::ChallengeInput>(2015, 6)
+        .register::<days::day_07::ChallengeInput<'static>>(2015, 7)
+        .register::<days::day_08::ChallengeInput<'static>>(2015, 8)
+        .register::<days::day_09::ChallengeInput<'static>>(2015, 9)
+        .register::<days::day_10::ChallengeInput>(2015, 10)
+        .register::<days::day_11::ChallengeInput>(2015, 11)
+        .register::<days::day_12::ChallengeInput>(2015, 12)
+        .register::<days::day_13::ChallengeInput<'static>>(2015, 13)
+        .register::<days::day_14::ChallengeInput>(2015, 14)
+        .register::<days::day_15::ChallengeInput>(2015, 15)
+        .register::<days::day_16::ChallengeInput<'static>>(2015, 16)
+        .register::<days::day_17::ChallengeInput>(2015, 17)
+        .register::<days::day_18::ChallengeInput>(2015, 18)
+        .register::<days::day_19::ChallengeInput<'static>>(2015, 19)
+        .register::<days::day_20::ChallengeInput>(2015, 20)
+        .register::<days::day_21::ChallengeInput>(2015, 21)
+        .register::<days::day_22::ChallengeInput>(2015, 22)
+        .register::<days::day_23::ChallengeInput>(2015, 23)
+        .register::<days::day_24::ChallengeInput>(2015, 24)
+        .register::<days::day_25::ChallengeInput>(2015, 25);
+    registry
 }
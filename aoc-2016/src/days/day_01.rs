@@ -1,8 +1,10 @@
 //!day_01.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::HashSet;
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 enum Instruction {
@@ -35,7 +37,7 @@ impl Instruction {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     instructions: Vec<Instruction>,
 }
 
@@ -77,29 +79,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_01.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_01 part 1: {result_part1}");
-    assert_eq!(result_part1, 242);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_01 part 2: {result_part2}");
-    assert_eq!(result_part2, 150);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_01_part_1() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_01_example_part_1.txt");
+        let input = read_file(2016, "day_01_example_part_1.txt")?;
 
         let solutions = [5, 2, 12];
 
@@ -116,7 +118,7 @@ mod tests {
 
     #[test]
     fn test_example_day_01_part_2() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_01_example_part_2.txt");
+        let input = read_file(2016, "day_01_example_part_2.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part2 = example.solution_part_2();
@@ -1,8 +1,10 @@
 //!day_02.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::HashMap;
+use std::fmt::Display;
 
 fn get_keypad_part_1() -> HashMap<Point, char> {
     let mut keypad = HashMap::new();
@@ -36,7 +38,7 @@ fn get_keypad_part_2() -> HashMap<Point, char> {
     keypad
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     lines_of_instructions: Vec<Vec<Compass>>,
 }
 
@@ -83,29 +85,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_02.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_02 part 1: {result_part1}");
-    assert_eq!(result_part1, "92435");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_02 part 2: {result_part2}");
-    assert_eq!(result_part2, "C1A88");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_02() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_02_example.txt");
+        let input = read_file(2016, "day_02_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
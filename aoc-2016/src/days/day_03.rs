@@ -1,6 +1,8 @@
 //!day_03.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Default, Debug)]
 struct Triangle {
@@ -70,30 +72,37 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_03.txt");
-    let challenge = ChallengeInput::from(input);
+pub struct Day03 {
+    part_1: ChallengeInput,
+    part_2: ChallengeInput,
+}
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_03 part 1: {result_part1}");
-    assert_eq!(result_part1, 982);
+impl Solution for Day03 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            part_1: ChallengeInput::from(input),
+            part_2: ChallengeInput::parse_part_2(input),
+        })
+    }
 
-    let challenge = ChallengeInput::parse_part_2(input);
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_03 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_826);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.part_1.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.part_2.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_03() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_03_example.txt");
+        let input = read_file(2016, "day_03_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,16 +1,20 @@
 //!day_05.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use rayon::prelude::*;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     input: String,
+    result_part2: Option<String>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
         ChallengeInput {
             input: value.to_string(),
+            result_part2: None,
         }
     }
 }
@@ -57,28 +61,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_05.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_05 part 1: {result_part1}");
-    assert_eq!(result_part1, "f97c354d");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_05 part 2: {result_part2}");
-    assert_eq!(result_part2, "863dde27");
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_05() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_05_example.txt");
+        let input = read_file(2016, "day_05_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
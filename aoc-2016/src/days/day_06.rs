@@ -1,10 +1,13 @@
 //!day_06.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     code: Vec<Vec<char>>,
+    result_part2: Option<String>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -18,7 +21,10 @@ impl From<&str> for ChallengeInput {
                 code[x].push(c);
             }
         }
-        ChallengeInput { code }
+        ChallengeInput {
+            code,
+            result_part2: None,
+        }
     }
 }
 
@@ -53,28 +59,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_06.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_06 part 1: {result_part1}");
-    assert_eq!(result_part1, "agmwzecr");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_06 part 2: {result_part2}");
-    assert_eq!(result_part2, "owlaxqvq");
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_06() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_06_example.txt");
+        let input = read_file(2016, "day_06_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
@@ -1,7 +1,9 @@
 //!day_08.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 struct Screen<const X: usize, const Y: usize> {
@@ -68,7 +70,7 @@ impl Instruction {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     instructions: Vec<Instruction>,
 }
 
@@ -89,37 +91,31 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_08.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let mut screen = Screen::<50, 6>::new();
-    let result_part1 = challenge.solution_part_1(&mut screen);
-    println!("result day_08 part 1: {result_part1}");
-    assert_eq!(result_part1, 110);
-
-    println!("result day_08 part 2:\n{}", screen.screen);
-    assert_eq!(
-        format!("{}", screen.screen),
-        "####...##.#..#.###..#..#..##..###..#....#...#..##.\n\
-         ...#....#.#..#.#..#.#.#..#..#.#..#.#....#...#...#.\n\
-         ..#.....#.####.#..#.##...#....#..#.#.....#.#....#.\n\
-         .#......#.#..#.###..#.#..#....###..#......#.....#.\n\
-         #....#..#.#..#.#.#..#.#..#..#.#....#......#..#..#.\n\
-         ####..##..#..#.#..#.#..#..##..#....####...#...##.."
-    );
-
-    Ok(())
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(&mut Screen::<50, 6>::new()))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        let mut screen = Screen::<50, 6>::new();
+        self.solution_part_1(&mut screen);
+        Ok(screen.screen)
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_08() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_08_example.txt");
+        let input = read_file(2016, "day_08_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let mut screen = Screen::<7, 3>::new();
@@ -1,7 +1,9 @@
 //!day_10.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 enum Action {
@@ -65,7 +67,7 @@ impl Bot {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     bots: HashMap<u64, Bot>,
     outputs: HashMap<u64, u64>,
 }
@@ -148,29 +150,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_10.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(17, 61);
-    println!("result day_10 part 1: {result_part1}");
-    assert_eq!(result_part1, 27);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_10 part 2: {result_part2}");
-    assert_eq!(result_part2, 13_727);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(17, 61))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_10() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_10_example.txt");
+        let input = read_file(2016, "day_10_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1(2, 5);
@@ -1,6 +1,7 @@
 //!day_11.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use petgraph::{
     Direction,
     graph::{DiGraph, NodeIndex},
@@ -208,7 +209,7 @@ impl<'a> From<&ChallengeInput<'a>> for NormalizedFloors {
 }
 
 #[derive(Clone, PartialEq, Eq, Hash)]
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     floors: [Floor<'a>; 4],
     pos: usize,
 }
@@ -377,30 +378,29 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_11.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(false);
-    println!("result day_11 part 1: {result_part1}");
-    assert_eq!(result_part1, 33);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let mut challenge = ChallengeInput::from(input);
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_11 part 2: {result_part2}");
-    //assert_eq!(result_part2, YYY);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(false))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_11() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_11_example.txt");
+        let input = read_file(2016, "day_11_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1(true);
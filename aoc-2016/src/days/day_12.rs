@@ -1,44 +1,11 @@
 //!day_12.rs
 
 use anyhow::Result;
-use std::collections::HashMap;
+use aoc_common::Solution;
+use aoc_common::register_machine::{Flow, InstructionSet, Machine, Value};
+use std::fmt::Display;
 
-pub type Register = HashMap<char, i64>;
-
-#[derive(Clone, Copy)]
-pub enum Value {
-    Digit(i64),
-    Char(char),
-}
-
-impl From<&str> for Value {
-    fn from(value: &str) -> Self {
-        let c = value.chars().next().unwrap();
-        if c.is_alphabetic() {
-            Value::Char(c)
-        } else {
-            Value::Digit(value.parse().unwrap())
-        }
-    }
-}
-
-impl Value {
-    pub fn value(&self, register: &Register) -> i64 {
-        match self {
-            Value::Char(reg) => *register.get(reg).unwrap_or(&0),
-            Value::Digit(digit) => *digit,
-        }
-    }
-    pub fn register(&self) -> Option<char> {
-        if let Value::Char(c) = *self {
-            Some(c)
-        } else {
-            None
-        }
-    }
-}
-
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Action {
     Cpy(Value, Value),
     Inc(Value),
@@ -46,6 +13,15 @@ pub enum Action {
     Jnz(Value, Value),
     Tgl(Value),
     Out(Value),
+    // fused actions of optimizer
+    /// Add(src, dst): dst += src; src = 0. Replaces add-loop of 3 actions.
+    Add(Value, Value),
+    /// Mul(src, factor, tmp, dst): dst += src * factor; tmp = 0; factor = 0. Replaces
+    /// multiply-loop of 6 actions.
+    Mul(Value, Value, Value, Value),
+    /// Padding behind fused action. Only reached by jumping into optimized block, in which
+    /// case the original action is executed.
+    Nop,
 }
 
 impl From<&str> for Action {
@@ -63,61 +39,162 @@ impl From<&str> for Action {
     }
 }
 
-impl Action {
-    pub fn apply(&self, register: &mut Register) -> (i64, Option<i64>, Option<i64>) {
+/// State of assembunny besides registers: original actions, which are toggled and
+/// optimized again, and last output.
+#[derive(Debug, Default)]
+pub struct Assembunny {
+    pub actions: Vec<Action>,
+    pub output: Option<i64>,
+}
+
+impl InstructionSet for Action {
+    type State = Assembunny;
+
+    fn execute(self, machine: &mut Machine<Self>) -> Flow {
+        let registers = &mut machine.registers;
         match self {
             Action::Cpy(val, reg) => {
-                let Some(reg) = reg.register() else {
-                    return (1, None, None);
-                };
-                let val = val.value(register);
-                register.insert(reg, val);
-                (1, None, None)
+                if let Some(reg) = reg.register() {
+                    registers[reg] = val.value(registers);
+                }
             }
             Action::Inc(reg) => {
-                let Some(reg) = reg.register() else {
-                    return (1, None, None);
-                };
-                register.entry(reg).and_modify(|v| *v += 1).or_insert(1);
-                (1, None, None)
+                if let Some(reg) = reg.register() {
+                    registers[reg] += 1;
+                }
             }
             Action::Dec(reg) => {
-                let Some(reg) = reg.register() else {
-                    return (1, None, None);
-                };
-                register.entry(reg).and_modify(|v| *v -= 1).or_insert(-1);
-                (1, None, None)
-            }
-            Action::Jnz(reg, jump) => {
-                let val = reg.value(register);
-                let jump = jump.value(register);
-                if val != 0 {
-                    (jump, None, None)
-                } else {
-                    (1, None, None)
+                if let Some(reg) = reg.register() {
+                    registers[reg] -= 1;
                 }
             }
+            Action::Jnz(reg, jump) if reg.value(registers) != 0 => {
+                return Flow::Jump(jump.value(registers));
+            }
+            Action::Jnz(..) => (),
             Action::Tgl(delta) => {
-                let delta = delta.value(register);
-                (1, Some(delta), None)
+                let toggle_index = machine.pc + delta.value(registers);
+                if let Ok(toggle_index) = usize::try_from(toggle_index)
+                    && let Some(action) = machine.state.actions.get_mut(toggle_index)
+                {
+                    *action = action.toggle();
+                    machine.program = optimize(&machine.state.actions);
+                }
             }
             Action::Out(out) => {
-                let out = out.value(register);
-                (1, None, Some(out))
+                machine.state.output = Some(out.value(registers));
+                return Flow::Yield(1);
+            }
+            Action::Add(src, dst) => {
+                let (Some(src), Some(dst)) = (src.register(), dst.register()) else {
+                    unreachable!("optimizer fuses only register loops");
+                };
+                registers[dst] += registers[src];
+                registers[src] = 0;
+                return Flow::Jump(3);
             }
+            Action::Mul(src, factor, tmp, dst) => {
+                let (Some(factor), Some(tmp), Some(dst)) =
+                    (factor.register(), tmp.register(), dst.register())
+                else {
+                    unreachable!("optimizer fuses only register loops");
+                };
+                registers[dst] += src.value(registers) * registers[factor];
+                registers[tmp] = 0;
+                registers[factor] = 0;
+                return Flow::Jump(6);
+            }
+            // jump into optimized block
+            Action::Nop => return machine.state.actions[machine.pc as usize].execute(machine),
         }
+        Flow::NEXT
     }
+}
+
+impl Action {
     pub fn toggle(&self) -> Action {
         match self {
             Action::Inc(v) => Action::Dec(*v),
             Action::Dec(v) | Action::Tgl(v) | Action::Out(v) => Action::Inc(*v),
             Action::Jnz(v1, v2) => Action::Cpy(*v1, *v2),
             Action::Cpy(v1, v2) => Action::Jnz(*v1, *v2),
+            Action::Add(..) | Action::Mul(..) | Action::Nop => {
+                unreachable!("only original actions are toggled")
+            }
+        }
+    }
+}
+
+/// Peephole optimizer, which replaces add-loops and multiply-loops by fused actions and pads
+/// them with Nop, so that all jump offsets stay valid. Loops are expected to count down
+/// positive counters, otherwise they would not terminate anyway.
+pub fn optimize(actions: &[Action]) -> Vec<Action> {
+    let mut optimized = actions.to_vec();
+    let mut index = 0;
+    while index < actions.len() {
+        if let Some(mul) = multiply_loop(&actions[index..]) {
+            optimized[index] = mul;
+            optimized[index + 1..index + 6].fill(Action::Nop);
+            index += 6;
+        } else if let Some(add) = add_loop(&actions[index..]) {
+            optimized[index] = add;
+            optimized[index + 1..index + 3].fill(Action::Nop);
+            index += 3;
+        } else {
+            index += 1;
         }
     }
+    optimized
+}
+
+// inc dst; dec src; jnz src -2 (or dec src first) -> Add(src, dst)
+fn add_loop(actions: &[Action]) -> Option<Action> {
+    let [first, second, Action::Jnz(counter, Value::Digit(-2)), ..] = actions else {
+        return None;
+    };
+    let (dst, src) = match (first, second) {
+        (Action::Inc(dst), Action::Dec(src)) | (Action::Dec(src), Action::Inc(dst)) => (dst, src),
+        _ => return None,
+    };
+    (src.register().is_some() && dst.register().is_some() && src == counter && src != dst)
+        .then_some(Action::Add(*src, *dst))
+}
+
+// cpy src tmp; add-loop of tmp into dst; dec factor; jnz factor -5 -> Mul(src, factor, tmp, dst)
+fn multiply_loop(actions: &[Action]) -> Option<Action> {
+    let [
+        Action::Cpy(src, tmp),
+        _,
+        _,
+        _,
+        Action::Dec(factor),
+        Action::Jnz(counter, Value::Digit(-5)),
+        ..,
+    ] = actions
+    else {
+        return None;
+    };
+    let Some(Action::Add(add_src, dst)) = add_loop(&actions[1..]) else {
+        return None;
+    };
+    let registers = [tmp, factor, &dst];
+    let distinct = registers.iter().all(|r| r.register().is_some())
+        && tmp != factor
+        && tmp != &dst
+        && factor != &dst;
+    (distinct && add_src == *tmp && counter == factor && !registers.contains(&src))
+        .then_some(Action::Mul(*src, *factor, *tmp, dst))
 }
 
-struct ChallengeInput {
+/// Machine, which runs optimized actions. Toggling an action optimizes the toggled actions
+/// again. Each output yields the machine.
+pub fn assembunny(actions: &[Action]) -> Machine<Action> {
+    let mut machine = Machine::new(optimize(actions));
+    machine.state.actions = actions.to_vec();
+    machine
+}
+
+pub struct ChallengeInput {
     actions: Vec<Action>,
 }
 
@@ -131,49 +208,43 @@ impl From<&str> for ChallengeInput {
 
 impl ChallengeInput {
     fn solution_part_1(&self) -> i64 {
-        let mut index = 0;
-        let num_actions = self.actions.len() as i64;
-        let mut register: Register = HashMap::new();
-        while index >= 0 && index < num_actions {
-            index += self.actions[index as usize].apply(&mut register).0;
-        }
-        *register.get(&'a').unwrap()
+        self.run(0)
     }
     fn solution_part_2(&self) -> i64 {
-        let mut index = 0;
-        let num_actions = self.actions.len() as i64;
-        let mut register: Register = HashMap::new();
-        register.insert('c', 1);
-        while index >= 0 && index < num_actions {
-            index += self.actions[index as usize].apply(&mut register).0;
-        }
-        *register.get(&'a').unwrap()
+        self.run(1)
+    }
+    fn run(&self, c: i64) -> i64 {
+        let mut machine = assembunny(&self.actions);
+        machine.registers['c'] = c;
+        machine.run(u64::MAX);
+        machine.registers['a']
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_12.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_12 part 1: {result_part1}");
-    assert_eq!(result_part1, 317_993);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_12 part 2: {result_part2}");
-    assert_eq!(result_part2, 9_227_647);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
+    use aoc_common::register_machine::Registers;
 
     #[test]
     fn test_example_day_12() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_12_example.txt");
+        let input = read_file(2016, "day_12_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -182,4 +253,44 @@ mod tests {
 
         Ok(())
     }
+
+    fn run(program: &str) -> (Vec<Action>, Registers) {
+        let actions: Vec<Action> = program.lines().map(Action::from).collect();
+        let mut machine = assembunny(&actions);
+        machine.run(u64::MAX);
+        (optimize(&actions), machine.registers)
+    }
+
+    #[test]
+    fn test_optimize() {
+        // multiply-loop
+        let (optimized, registers) =
+            run("cpy 3 b\ncpy 4 d\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5");
+        assert_eq!(
+            optimized[2],
+            Action::Mul(
+                Value::Char('b'),
+                Value::Char('d'),
+                Value::Char('c'),
+                Value::Char('a')
+            )
+        );
+        assert!(optimized[3..].iter().all(|a| *a == Action::Nop));
+        assert_eq!(registers['a'], 12);
+        assert_eq!(registers['c'], 0);
+        assert_eq!(registers['d'], 0);
+
+        // jump into add-loop executes original actions
+        let (optimized, registers) = run("cpy 3 b\njnz 1 2\ninc a\ndec b\njnz b -2");
+        assert_eq!(
+            optimized[2],
+            Action::Add(Value::Char('b'), Value::Char('a'))
+        );
+        assert_eq!(registers['a'], 2);
+
+        // tgl turns jnz of add-loop into cpy, therefore loop runs only once
+        let (_, registers) = run("cpy 4 c\ntgl c\ncpy 3 b\ninc a\ndec b\njnz b -2");
+        assert_eq!(registers['a'], 1);
+        assert_eq!(registers['b'], 2);
+    }
 }
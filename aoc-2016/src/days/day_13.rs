@@ -1,10 +1,12 @@
 //!day_13.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     seed: u64,
 }
 
@@ -66,29 +68,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_13.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1((31, 39).into());
-    println!("result day_13 part 1: {result_part1}");
-    assert_eq!(result_part1, 96);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_13 part 2: {result_part2}");
-    assert_eq!(result_part2, 141);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1((31, 39).into()))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_13() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_13_example.txt");
+        let input = read_file(2016, "day_13_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1((7, 4).into());
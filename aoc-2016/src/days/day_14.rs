@@ -1,9 +1,11 @@
 //!day_14.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use md5::{Digest, compute};
 use rayon::prelude::*;
 use std::collections::BTreeMap;
+use std::fmt::Display;
 
 const fn u8_to_hex(byte: u8) -> [u8; 2] {
     let low = byte & 0x0f;
@@ -20,7 +22,7 @@ fn digest_to_hex(bytes: Digest, hex_bytes: &mut [u8; 32]) {
     }
 }
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     seed: &'a str,
 }
 
@@ -96,29 +98,29 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_14.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_14 part 1: {result_part1}");
-    assert_eq!(result_part1, 23_890);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_14 part 2: {result_part2}");
-    assert_eq!(result_part2, 22_696);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_hex_conversion() {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_14_example.txt");
+        let input = read_file(2016, "day_14_example.txt").unwrap();
         let example = ChallengeInput::from(input);
 
         let index = 18;
@@ -154,7 +156,7 @@ mod tests {
 
     #[test]
     fn test_example_day_14_first_key() {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_14_example.txt");
+        let input = read_file(2016, "day_14_example.txt").unwrap();
         let example = ChallengeInput::from(input);
 
         // part 1
@@ -186,7 +188,7 @@ mod tests {
 
     #[test]
     fn test_example_day_14() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_14_example.txt");
+        let input = read_file(2016, "day_14_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,8 +1,10 @@
 //!day_16.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     bits: Vec<bool>,
 }
 
@@ -44,29 +46,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_16.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(272);
-    println!("result day_16 part 1: {result_part1}");
-    assert_eq!(result_part1, "11100111011101111");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_16 part 2: {result_part2}");
-    assert_eq!(result_part2, "10001110010000110");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(272))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_16() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_16_example.txt");
+        let input = read_file(2016, "day_16_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1(20);
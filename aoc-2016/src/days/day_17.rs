@@ -1,7 +1,9 @@
 //!day_17.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
+use std::fmt::Display;
 use std::{cmp::Ordering, collections::BTreeSet};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -61,7 +63,7 @@ impl Room {
     }
 }
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     seed: &'a str,
 }
 
@@ -111,25 +113,25 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_17.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_17 part 1: {result_part1}");
-    assert_eq!(result_part1, "RLDRUDRDDR");
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_17 part 2: {result_part2}");
-    assert_eq!(result_part2, 498);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_no_solution() {
@@ -139,7 +141,7 @@ mod tests {
 
     #[test]
     fn test_example_day_17() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_17_example.txt");
+        let input = read_file(2016, "day_17_example.txt")?;
 
         let solutions = [
             ("DDRRRD", 370_usize),
@@ -1,6 +1,8 @@
 //!day_19.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 use std::{cell::RefCell, rc::Rc};
 
 struct Elf {
@@ -52,7 +54,7 @@ impl Elf {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     num_elves: u64,
 }
 
@@ -105,29 +107,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_19.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_19 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_815_603);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_19 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_410_630);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_19() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_19_example.txt");
+        let input = read_file(2016, "day_19_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
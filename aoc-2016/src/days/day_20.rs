@@ -1,7 +1,9 @@
 //!day_20.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::VecDeque;
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 struct Range {
@@ -43,14 +45,16 @@ impl Range {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     ranges: Vec<Range>,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
         ChallengeInput {
             ranges: value.lines().map(Range::from).collect(),
+            result_part2: None,
         }
     }
 }
@@ -79,28 +83,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_20.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2(4_294_967_295);
-    println!("result day_20 part 1: {result_part1}");
-    assert_eq!(result_part1, 4_793_564);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_20 part 2: {result_part2}");
-    assert_eq!(result_part2, 146);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2(4_294_967_295);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_20() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_20_example.txt");
+        let input = read_file(2016, "day_20_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2(9);
@@ -1,6 +1,8 @@
 //!day_21.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 enum Action {
@@ -138,7 +140,7 @@ impl Action {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     actions: Vec<Action>,
 }
 
@@ -167,29 +169,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_21.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1("abcdefgh");
-    println!("result day_21 part 1: {result_part1}");
-    assert_eq!(result_part1, "cbeghdaf");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2("fbgdceah");
-    println!("result day_21 part 2: {result_part2}");
-    assert_eq!(result_part2, "bacdefgh");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1("abcdefgh"))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2("fbgdceah"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_21() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_21_example.txt");
+        let input = read_file(2016, "day_21_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1("abcde");
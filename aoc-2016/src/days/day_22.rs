@@ -1,7 +1,9 @@
 //!day_22.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
+use std::fmt::Display;
 use std::{
     cmp::Ordering,
     collections::{BTreeSet, HashMap, HashSet, VecDeque},
@@ -107,7 +109,7 @@ impl State {
 }
 
 #[derive(Clone)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     nodes: HashMap<Point, Node>,
     max_x: i64,
     max_y: i64,
@@ -249,29 +251,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_22.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_22 part 1: {result_part1}");
-    assert_eq!(result_part1, 910);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_22 part 2: {result_part2}");
-    assert_eq!(result_part2, 222);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_22() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_22_example.txt");
+        let input = read_file(2016, "day_22_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part2 = example.solution_part_2();
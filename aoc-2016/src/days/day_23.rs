@@ -1,10 +1,11 @@
 //!day_23.rs
 
-use super::day_12::{Action, Register};
+use super::day_12::{Action, assembunny};
 use anyhow::Result;
-use std::collections::HashMap;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     actions: Vec<Action>,
 }
 
@@ -18,73 +19,43 @@ impl From<&str> for ChallengeInput {
 
 impl ChallengeInput {
     fn solution_part_1(&self) -> i64 {
-        let mut register: Register = HashMap::new();
-        register.insert('a', 7);
-        let mut index = 0;
-        let mut actions = self.actions.clone();
-        let num_actions = self.actions.len() as i64;
-        while index >= 0 && index < num_actions {
-            let (delta_index, toggle, _) = actions[index as usize].apply(&mut register);
-            if let Some(delta_toggle) = toggle {
-                let toggle_index = index + delta_toggle;
-                if toggle_index >= 0 && toggle_index < num_actions {
-                    actions[toggle_index as usize] = actions[toggle_index as usize].toggle();
-                }
-            }
-            index += delta_index;
-        }
-        *register.get(&'a').unwrap()
+        self.run(7)
     }
     fn solution_part_2(&self) -> i64 {
-        let mut register: Register = HashMap::new();
-        register.insert('a', 12);
-        let mut index = 0;
-        let mut actions = self.actions.clone();
-        let num_actions = self.actions.len() as i64;
-        while index >= 0 && index < num_actions {
-            if index == 8 {
-                let d = *register.get(&'d').unwrap();
-                register.entry('a').and_modify(|a| *a *= d);
-                register.entry('d').and_modify(|d| *d = 0);
-                index += 1;
-            } else {
-                let (delta_index, toggle, _) = actions[index as usize].apply(&mut register);
-                if let Some(delta_toggle) = toggle {
-                    let toggle_index = index + delta_toggle;
-                    if toggle_index >= 0 && toggle_index < num_actions {
-                        actions[toggle_index as usize] = actions[toggle_index as usize].toggle();
-                    }
-                }
-                index += delta_index;
-            }
-        }
-        *register.get(&'a').unwrap()
+        // optimizer replaces the multiply-loop, which takes billions of steps with a = 12
+        self.run(12)
+    }
+    fn run(&self, a: i64) -> i64 {
+        let mut machine = assembunny(&self.actions);
+        machine.registers['a'] = a;
+        machine.run(u64::MAX);
+        machine.registers['a']
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_23.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_23 part 1: {result_part1}");
-    assert_eq!(result_part1, 10_223);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_23 part 2: {result_part2}");
-    assert_eq!(result_part2, 479_006_783);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_23() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_23_example.txt");
+        let input = read_file(2016, "day_23_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
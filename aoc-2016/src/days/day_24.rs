@@ -1,11 +1,14 @@
 //!day_24.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     map: HashMap<Point, char>,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -21,6 +24,7 @@ impl From<&str> for ChallengeInput {
                         .filter(|(_, c)| *c != '#')
                 })
                 .collect(),
+            result_part2: None,
         }
     }
 }
@@ -99,28 +103,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_24.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_24 part 1: {result_part1}");
-    assert_eq!(result_part1, 456);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_24 part 2: {result_part2}");
-    assert_eq!(result_part2, 704);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_24() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_24_example.txt");
+        let input = read_file(2016, "day_24_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1_and_2().0;
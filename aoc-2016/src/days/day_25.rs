@@ -1,10 +1,12 @@
 //!day_25.rs
 
-use super::day_12::{Action, Register};
+use super::day_12::{Action, assembunny};
 use anyhow::Result;
-use std::collections::HashMap;
+use aoc_common::Solution;
+use aoc_common::register_machine::Status;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     actions: Vec<Action>,
 }
 
@@ -18,53 +20,38 @@ impl From<&str> for ChallengeInput {
 
 impl ChallengeInput {
     fn solution_part_1(&self) -> i64 {
-        let mut actions = self.actions.clone();
-        let num_actions = self.actions.len() as i64;
-        'outer: for a in 0..i64::MAX {
+        for a in 0..i64::MAX {
             let mut last_out = None::<i64>;
             let mut out_count = 0;
-            let mut register: Register = HashMap::new();
-            register.insert('a', a);
-            let mut index = 0;
-            while index >= 0 && index < num_actions {
-                let (delta_index, toggle, out) = actions[index as usize].apply(&mut register);
-                if let Some(delta_toggle) = toggle {
-                    let toggle_index = index + delta_toggle;
-                    if toggle_index >= 0 && toggle_index < num_actions {
-                        actions[toggle_index as usize] = actions[toggle_index as usize].toggle();
-                    }
+            let mut machine = assembunny(&self.actions);
+            machine.registers['a'] = a;
+            // stop at first output, which breaks clock signal 0, 1, 0, 1, ...
+            while out_count < 200 && machine.run(u64::MAX) == Status::Yielded {
+                let next_out = machine.state.output.take();
+                if next_out > Some(1) || last_out == next_out {
+                    break;
                 }
-                if let Some(next_out) = out {
-                    if next_out > 1 {
-                        continue 'outer;
-                    }
-                    if let Some(lo) = last_out
-                        && lo == next_out
-                    {
-                        continue 'outer;
-                    }
-                    out_count += 1;
-                    if out_count == 200 {
-                        return a;
-                    }
-                    last_out = out;
-                }
-                index += delta_index;
+                out_count += 1;
+                last_out = next_out;
+            }
+            if out_count == 200 {
+                return a;
             }
         }
         0
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_25.txt");
-    let challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const HAS_PART_2: bool = false;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_25 part 1: {result_part1}");
-    assert_eq!(result_part1, 158);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 }
 
 // No tests for day 25
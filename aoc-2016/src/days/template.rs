@@ -1,8 +1,10 @@
 //!day_01.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {}
+pub struct ChallengeInput {}
 
 impl From<&str> for ChallengeInput {
     fn from(_value: &str) -> Self {
@@ -19,29 +21,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2016/day_01.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_01 part 1: {result_part1}");
-    //assert_eq!(result_part1, XXX);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_01 part 2: {result_part2}");
-    //assert_eq!(result_part2, YYY);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_01() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2016/day_01_example.txt");
+        let input = read_file(2016, "day_01_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
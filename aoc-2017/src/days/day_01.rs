@@ -1,8 +1,10 @@
 //!day_01.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     digits: Vec<u32>,
 }
 
@@ -33,29 +35,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_01.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_01 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_343);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_01 part 2: {result_part2}");
-    //assert_eq!(result_part2, YYY);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_01_part_1() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_01_example_part_1.txt");
+        let input = read_file(2017, "day_01_example_part_1.txt")?;
         let solutions = [3, 4, 0, 9];
 
         for (line, solution) in input.lines().zip(solutions) {
@@ -71,7 +73,7 @@ mod tests {
 
     #[test]
     fn test_example_day_01_part_2() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_01_example_part_2.txt");
+        let input = read_file(2017, "day_01_example_part_2.txt")?;
         let solutions = [6, 0, 4, 12, 4];
 
         for (line, solution) in input.lines().zip(solutions) {
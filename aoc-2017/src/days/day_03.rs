@@ -1,10 +1,12 @@
 //!day_03.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     square: i64,
 }
 
@@ -106,29 +108,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_03.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_03 part 1: {result_part1}");
-    assert_eq!(result_part1, 475);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_03 part 2: {result_part2}");
-    assert_eq!(result_part2, 279_138);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_03_part_1() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_03_example_part_1.txt");
+        let input = read_file(2017, "day_03_example_part_1.txt")?;
         let solutions = [0, 3, 2, 31];
 
         for (line, solution) in input.lines().zip(solutions) {
@@ -1,9 +1,11 @@
 //!day_04.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     words: Vec<Vec<String>>,
 }
 
@@ -46,29 +48,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_04.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_04 part 1: {result_part1}");
-    assert_eq!(result_part1, 337);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_04 part 2: {result_part2}");
-    assert_eq!(result_part2, 231);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_04_part_1() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_04_example_part_1.txt");
+        let input = read_file(2017, "day_04_example_part_1.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -80,7 +82,7 @@ mod tests {
 
     #[test]
     fn test_example_day_04_part_2() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_04_example_part_2.txt");
+        let input = read_file(2017, "day_04_example_part_2.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part2 = example.solution_part_2();
@@ -1,9 +1,11 @@
 //!day_06.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
-struct ChallengeInput<const N: usize> {
+pub struct ChallengeInput<const N: usize> {
     banks: [u32; N],
 }
 
@@ -46,29 +48,29 @@ impl<const N: usize> ChallengeInput<N> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_06.txt");
-    let mut challenge = ChallengeInput::<16>::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_06 part 1: {result_part1}");
-    assert_eq!(result_part1, 6_681);
+impl<const N: usize> Solution for ChallengeInput<N> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_06 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_392);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_06() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_06_example.txt");
+        let input = read_file(2017, "day_06_example.txt")?;
         let mut example = ChallengeInput::<4>::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,10 +1,12 @@
 //!day_07.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use petgraph::{Direction, graphmap::DiGraphMap};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     weights: HashMap<&'a str, u64>,
     map: DiGraphMap<&'a str, ()>,
 }
@@ -76,29 +78,30 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_07.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_07 part 1: {result_part1}");
-    assert_eq!(result_part1, "cyrupz");
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(result_part1);
-    println!("result day_07 part 2: {result_part2}");
-    assert_eq!(result_part2, 193);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let root = self.solution_part_1();
+        Ok(self.solution_part_2(root))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_07() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_07_example.txt");
+        let input = read_file(2017, "day_07_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
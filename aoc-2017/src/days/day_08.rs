@@ -1,7 +1,9 @@
 //!day_08.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 enum Action {
@@ -110,14 +112,16 @@ impl<'a> Instruction<'a> {
     }
 }
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     instructions: Vec<Instruction<'a>>,
+    result_part2: Option<i64>,
 }
 
 impl<'a> From<&'a str> for ChallengeInput<'a> {
     fn from(value: &'a str) -> Self {
         ChallengeInput {
             instructions: value.lines().map(Instruction::from).collect(),
+            result_part2: None,
         }
     }
 }
@@ -136,28 +140,33 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_08.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_08 part 1: {result_part1}");
-    assert_eq!(result_part1, 4_832);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_08 part 2: {result_part2}");
-    assert_eq!(result_part2, 5_443);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_08() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_08_example.txt");
+        let input = read_file(2017, "day_08_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
@@ -1,6 +1,8 @@
 //!day_09.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 enum Stream {
     Group { value: u64, sub_stream: Vec<Stream> },
@@ -77,13 +79,17 @@ impl Stream {
     }
 }
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     input: &'a str,
+    result_part2: Option<u64>,
 }
 
 impl<'a> From<&'a str> for ChallengeInput<'a> {
     fn from(value: &'a str) -> Self {
-        ChallengeInput { input: value }
+        ChallengeInput {
+            input: value,
+            result_part2: None,
+        }
     }
 }
 
@@ -96,28 +102,33 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_09.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_09 part 1: {result_part1}");
-    assert_eq!(result_part1, 10_616);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_09 part 2: {result_part2}");
-    assert_eq!(result_part2, 5_101);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_09() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_09_example.txt");
+        let input = read_file(2017, "day_09_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
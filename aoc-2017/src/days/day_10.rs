@@ -1,6 +1,8 @@
 //!day_10.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 pub struct KnotHash {
     ring: Vec<u64>,
@@ -76,7 +78,7 @@ impl KnotHash {
     }
 }
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     input: &'a str,
 }
 
@@ -108,29 +110,29 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_10.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(256);
-    println!("result day_10 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_935);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_10 part 2: {result_part2}");
-    assert_eq!(result_part2, "dc7e7dee710d4c7201ce42713e6b8359");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(256))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_10_part_1() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_10_example_part_1.txt");
+        let input = read_file(2017, "day_10_example_part_1.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1(5);
@@ -142,7 +144,7 @@ mod tests {
 
     #[test]
     fn test_example_day_10_part_2() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_10_example_part_2.txt");
+        let input = read_file(2017, "day_10_example_part_2.txt")?;
 
         let solutions = [
             "a2582a3a0e66e6e86e3812dcb672a272",
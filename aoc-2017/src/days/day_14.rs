@@ -1,17 +1,23 @@
 //!day_14.rs
 
 use super::day_10::KnotHash;
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     key: String,
+    result_part2: Option<usize>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
-        ChallengeInput { key: value.into() }
+        ChallengeInput {
+            key: value.into(),
+            result_part2: None,
+        }
     }
 }
 
@@ -53,28 +59,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_14.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_14 part 1: {result_part1}");
-    assert_eq!(result_part1, 8_222);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_14 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_086);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_14() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_14_example.txt");
+        let input = read_file(2017, "day_14_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
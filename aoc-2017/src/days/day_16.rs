@@ -1,7 +1,9 @@
 //!day_16.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 fn generate_chars(size: u8) -> Vec<char> {
     (0..size).map(|o| (97 + o) as char).collect()
@@ -45,14 +47,16 @@ impl Action {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     actions: Vec<Action>,
+    result_part2: Option<String>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
         ChallengeInput {
             actions: value.split(",").map(Action::from).collect(),
+            result_part2: None,
         }
     }
 }
@@ -129,30 +133,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_16.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let mut chars = generate_chars(16);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2(&mut chars);
-    println!("result day_16 part 1: {result_part1}");
-    assert_eq!(result_part1, "ebjpfdgmihonackl");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_16 part 2: {result_part2}");
-    assert_eq!(result_part2, "abocefghijklmndp");
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2(&mut generate_chars(16));
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_16() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_16_example.txt");
+        let input = read_file(2017, "day_16_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let mut chars = generate_chars(5);
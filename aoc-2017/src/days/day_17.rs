@@ -1,6 +1,8 @@
 //!day_17.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 use std::{cell::RefCell, rc::Rc};
 
 struct Spinlock {
@@ -42,7 +44,7 @@ impl Spinlock {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     steps: u64,
 }
 
@@ -88,29 +90,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_17.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_17 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_311);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_17 part 2: {result_part2}");
-    assert_eq!(result_part2, 39_170_601);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_17() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_17_example.txt");
+        let input = read_file(2017, "day_17_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,97 +1,20 @@
 //!day_18.rs
 
 use anyhow::Result;
-use std::collections::{HashMap, VecDeque};
-
-#[derive(Clone, Copy)]
-pub enum Value {
-    Digit(i64),
-    Char(char),
-}
-
-impl From<&str> for Value {
-    fn from(value: &str) -> Self {
-        let c = value.chars().next().unwrap();
-        if c.is_alphabetic() {
-            Value::Char(c)
-        } else {
-            Value::Digit(value.parse().unwrap())
-        }
-    }
-}
-
-impl Value {
-    pub fn value(&self, register: &Register) -> i64 {
-        match self {
-            Value::Char(reg) => *register.registers.get(reg).unwrap_or(&0),
-            Value::Digit(digit) => *digit,
-        }
-    }
-    pub fn register(&self) -> char {
-        let Value::Char(c) = *self else {
-            panic!("expected char")
-        };
-        c
-    }
-}
-
-#[derive(Clone)]
-pub struct Register {
-    pub registers: HashMap<char, i64>,
-    pub action_index: i64,
-    pub actions: Vec<Action>,
+use aoc_common::Solution;
+use aoc_common::register_machine::{Flow, InstructionSet, Machine, Status, Value};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+/// State of duet besides registers: played sounds, recovered frequencies and number of
+/// executed mul actions.
+#[derive(Debug, Clone, Default)]
+pub struct Duet {
     pub frequency: VecDeque<i64>,
     pub recovered_frequency: VecDeque<i64>,
     pub count_mul: u64,
 }
 
-impl Register {
-    pub fn new(actions: Vec<Action>) -> Self {
-        Self {
-            registers: HashMap::new(),
-            action_index: 0,
-            actions,
-            frequency: VecDeque::new(),
-            recovered_frequency: VecDeque::new(),
-            count_mul: 0,
-        }
-    }
-    pub fn get_action(&self) -> Option<Action> {
-        if self.action_index >= 0 && (self.action_index as usize) < self.actions.len() {
-            self.actions.get(self.action_index as usize).copied()
-        } else {
-            None
-        }
-    }
-    pub fn increment_index(&mut self) {
-        self.action_index += 1;
-    }
-    pub fn set_frequency(&mut self, val: i64) {
-        self.frequency.push_back(val);
-    }
-    pub fn set_recovered_frequency(&mut self, val: i64) {
-        if val != 0
-            && let Some(pop_val) = self.frequency.pop_back()
-        {
-            self.recovered_frequency.push_back(pop_val);
-        }
-    }
-    pub fn jump_g(&mut self, val: i64, jump: i64) {
-        if val > 0 {
-            self.action_index += jump;
-        } else {
-            self.action_index += 1;
-        }
-    }
-    pub fn jump_n(&mut self, val: i64, jump: i64) {
-        if val != 0 {
-            self.action_index += jump;
-        } else {
-            self.action_index += 1;
-        }
-    }
-}
-
 #[derive(Clone, Copy)]
 pub enum Action {
     Snd(Value),
@@ -123,70 +46,47 @@ impl From<&str> for Action {
     }
 }
 
-impl Action {
-    pub fn apply(&self, register: &mut Register) {
+impl InstructionSet for Action {
+    type State = Duet;
+
+    fn execute(self, machine: &mut Machine<Self>) -> Flow {
+        let registers = &mut machine.registers;
+        let duet = &mut machine.state;
         match self {
-            Action::Snd(reg) => {
-                let reg = reg.value(register);
-                register.set_frequency(reg);
-                register.increment_index();
-            }
-            Action::Set(reg, val) => {
-                let reg = reg.register();
-                let val = val.value(register);
-                register.registers.insert(reg, val);
-                register.increment_index();
-            }
-            Action::Add(reg, val) => {
-                let reg = reg.register();
-                let val = val.value(register);
-                register
-                    .registers
-                    .entry(reg)
-                    .and_modify(|v| *v += val)
-                    .or_insert(val);
-                register.increment_index();
-            }
-            Action::Sub(reg, val) => {
-                let reg = reg.register();
-                let val = val.value(register);
-                register
-                    .registers
-                    .entry(reg)
-                    .and_modify(|v| *v -= val)
-                    .or_insert(val);
-                register.increment_index();
-            }
+            Action::Snd(reg) => duet.frequency.push_back(reg.value(registers)),
+            Action::Set(reg, val) => registers[register(reg)] = val.value(registers),
+            Action::Add(reg, val) => registers[register(reg)] += val.value(registers),
+            Action::Sub(reg, val) => registers[register(reg)] -= val.value(registers),
             Action::Mul(reg, val) => {
-                let reg = reg.register();
-                let val = val.value(register);
-                register.count_mul += 1;
-                register.registers.entry(reg).and_modify(|v| *v *= val);
-                register.increment_index();
-            }
-            Action::Mod(reg, val) => {
-                let reg = reg.register();
-                let val = val.value(register);
-                register.registers.entry(reg).and_modify(|v| *v %= val);
-                register.increment_index();
+                duet.count_mul += 1;
+                registers[register(reg)] *= val.value(registers);
             }
+            Action::Mod(reg, val) => registers[register(reg)] %= val.value(registers),
             Action::Rcv(reg) => {
-                let val = reg.value(register);
-                register.set_recovered_frequency(val);
-                register.increment_index();
+                if reg.value(registers) != 0
+                    && let Some(frequency) = duet.frequency.pop_back()
+                {
+                    duet.recovered_frequency.push_back(frequency);
+                    return Flow::Yield(1);
+                }
             }
-            Action::Jgz(reg, jump) => {
-                let val = reg.value(register);
-                let jump = jump.value(register);
-                register.jump_g(val, jump);
+            Action::Jgz(reg, jump) if reg.value(registers) > 0 => {
+                return Flow::Jump(jump.value(registers));
             }
-            Action::Jnz(reg, jump) => {
-                let val = reg.value(register);
-                let jump = jump.value(register);
-                register.jump_n(val, jump);
+            Action::Jnz(reg, jump) if reg.value(registers) != 0 => {
+                return Flow::Jump(jump.value(registers));
             }
+            Action::Jgz(..) | Action::Jnz(..) => (),
         }
+        Flow::NEXT
     }
+}
+
+fn register(value: Value) -> char {
+    value.register().expect("expected char")
+}
+
+impl Action {
     pub fn extract_2nd_digit(&self) -> Option<i64> {
         let value = match self {
             Action::Add(_, v)
@@ -218,10 +118,9 @@ impl From<&str> for ChallengeInput {
 
 impl ChallengeInput {
     fn solution_part_1_day_18(&self) -> i64 {
-        let mut register = Register::new(self.actions.clone());
-        while let Some(action) = register.get_action() {
-            action.apply(&mut register);
-            if let Some(frequency) = register.recovered_frequency.pop_front() {
+        let mut machine = Machine::new(self.actions.clone());
+        while machine.run(u64::MAX) == Status::Yielded {
+            if let Some(frequency) = machine.state.recovered_frequency.pop_front() {
                 return frequency;
             }
         }
@@ -286,29 +185,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_18.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1_day_18();
-    println!("result day_18 part 1: {result_part1}");
-    assert_eq!(result_part1, 4_601);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2_day_18();
-    println!("result day_18 part 2: {result_part2}");
-    assert_eq!(result_part2, 6_858);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1_day_18())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2_day_18())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_18_example.txt");
+        let input = read_file(2017, "day_18_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1_day_18();
@@ -320,7 +219,7 @@ mod tests {
 
     #[test]
     fn text_value_generator() {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_18.txt");
+        let input = read_file(2017, "day_18.txt").unwrap();
         let challenge = ChallengeInput::from(input);
 
         let values = challenge.generate_values_to_sort();
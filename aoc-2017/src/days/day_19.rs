@@ -1,12 +1,15 @@
 //!day_19.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     start: Point,
     map: HashMap<Point, char>,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -22,7 +25,11 @@ impl From<&str> for ChallengeInput {
                 map.insert(p, c);
             }
         }
-        ChallengeInput { start, map }
+        ChallengeInput {
+            start,
+            map,
+            result_part2: None,
+        }
     }
 }
 
@@ -58,28 +65,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_19.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_19 part 1: {result_part1}");
-    assert_eq!(result_part1, "VTWBPYAQFU");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_19 part 2: {result_part2}");
-    assert_eq!(result_part2, 17_358);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_19() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_19_example.txt");
+        let input = read_file(2017, "day_19_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
@@ -1,8 +1,10 @@
 //!day_20.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point3D;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 struct Particle {
@@ -41,7 +43,7 @@ impl Particle {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     particles: Vec<Particle>,
 }
 
@@ -113,29 +115,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_20.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_20 part 1: {result_part1}");
-    assert_eq!(result_part1, 457);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_20 part 2: {result_part2}");
-    assert_eq!(result_part2, 448);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_20_part_1() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_20_example_part_1.txt");
+        let input = read_file(2017, "day_20_example_part_1.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -147,7 +149,7 @@ mod tests {
 
     #[test]
     fn test_example_day_20_part_2() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_20_example_part_2.txt");
+        let input = read_file(2017, "day_20_example_part_2.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part2 = example.solution_part_2();
@@ -1,8 +1,10 @@
 //!day_21.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::{Point, Turns90};
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 #[derive(Clone, Eq, PartialEq, Hash)]
 struct Square {
@@ -138,7 +140,7 @@ impl Square {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     square_map: HashMap<Square, Square>,
 }
 
@@ -226,30 +228,31 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_21.txt");
-    let mut challenge = ChallengeInput::from(input);
-    challenge.fill_missing_patterns();
-
-    let result_part1 = challenge.solution_part_1(5);
-    println!("result day_21 part 1: {result_part1}");
-    assert_eq!(result_part1, 171);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        let mut challenge = Self::from(input);
+        challenge.fill_missing_patterns();
+        Ok(challenge)
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_21 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_498_142);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(5))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_21() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_21_example.txt");
+        let input = read_file(2017, "day_21_example.txt")?;
         let mut example = ChallengeInput::from(input);
         example.fill_missing_patterns();
 
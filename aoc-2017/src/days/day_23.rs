@@ -2,14 +2,15 @@
 
 use super::day_18::*;
 use anyhow::Result;
+use aoc_common::Solution;
+use aoc_common::register_machine::{Machine, Status};
+use std::fmt::Display;
 
 impl ChallengeInput {
     fn solution_part_1_day_23(&self) -> u64 {
-        let mut register = Register::new(self.actions.clone());
-        while let Some(action) = register.get_action() {
-            action.apply(&mut register);
-        }
-        register.count_mul
+        let mut machine = Machine::new(self.actions.clone());
+        while machine.run(u64::MAX) == Status::Yielded {}
+        machine.state.count_mul
     }
     fn solution_part_2_day_23(&self) -> usize {
         // Note: this solution results from re-engineering the register code sequences
@@ -39,29 +40,32 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_23.txt");
-    let challenge = ChallengeInput::from(input);
+// ChallengeInput of day 18 already implements Solution
+pub struct Day23(ChallengeInput);
 
-    let result_part1 = challenge.solution_part_1_day_23();
-    println!("result day_23 part 1: {result_part1}");
-    assert_eq!(result_part1, 3_025);
+impl Solution for Day23 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self(ChallengeInput::from(input)))
+    }
 
-    let result_part2 = challenge.solution_part_2_day_23();
-    println!("result day_23 part 2: {result_part2}");
-    assert_eq!(result_part2, 915);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.0.solution_part_1_day_23())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.0.solution_part_2_day_23())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn text_value_generator() {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_23.txt");
+        let input = read_file(2017, "day_23.txt").unwrap();
         let challenge = ChallengeInput::from(input);
 
         let values = challenge.generate_values_to_check_for_divisor();
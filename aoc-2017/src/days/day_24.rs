@@ -1,7 +1,9 @@
 //!day_24.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct Component {
@@ -34,14 +36,16 @@ impl Component {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     components: HashSet<Component>,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
         ChallengeInput {
             components: value.lines().map(Component::from).collect(),
+            result_part2: None,
         }
     }
 }
@@ -89,28 +93,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_24.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_24 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_511);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_24 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_471);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_24() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_24_example.txt");
+        let input = read_file(2017, "day_24_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
@@ -125,7 +134,7 @@ mod tests {
 
     #[test]
     fn test_unique_components() {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_24.txt");
+        let input = read_file(2017, "day_24.txt").unwrap();
         let challenge = ChallengeInput::from(input);
 
         for (i, a) in challenge.components.iter().enumerate() {
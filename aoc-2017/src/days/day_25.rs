@@ -1,7 +1,9 @@
 //!day_25.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Debug)]
 struct Action {
@@ -28,7 +30,7 @@ impl From<&str> for Action {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     initial_state: char,
     steps: u64,
     machine: HashMap<(char, bool), Action>,
@@ -81,25 +83,27 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2017/day_25.txt");
-    let challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const HAS_PART_2: bool = false;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_25 part 1: {result_part1}");
-    assert_eq!(result_part1, 4_387);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_25() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2017/day_25_example.txt");
+        let input = read_file(2017, "day_25_example.txt")?;
         let example = ChallengeInput::from(input);
         assert_eq!(example.initial_state, 'A');
         assert_eq!(example.steps, 6);
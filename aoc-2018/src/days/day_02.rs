@@ -1,9 +1,11 @@
 //!day_02.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     input: &'a str,
 }
 
@@ -59,36 +61,36 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_02.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_02 part 1: {result_part1}");
-    assert_eq!(result_part1, 5_456);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_02 part 2: {result_part2}");
-    assert_eq!(result_part2, "megsdlpulxvinkatfoyzxcbvq");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_02() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_02_example_01.txt");
+        let input = read_file(2018, "day_02_example_01.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
         println!("result day_02 part 1: {result_part1}");
         assert_eq!(result_part1, 12);
 
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_02_example_02.txt");
+        let input = read_file(2018, "day_02_example_02.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part2 = example.solution_part_2();
@@ -1,8 +1,10 @@
 //!day_04.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use chrono::{DateTime, Timelike, Utc};
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 enum Guard {
@@ -47,14 +49,16 @@ impl From<&str> for LogEntry {
 }
 
 #[derive(Debug)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     log: Vec<LogEntry>,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
         let mut ci = ChallengeInput {
             log: value.lines().map(LogEntry::from).collect(),
+            result_part2: None,
         };
         ci.log.sort_by_key(|a| a.timestamp);
         ci
@@ -117,28 +121,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_04.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_04 part 1: {result_part1}");
-    assert_eq!(result_part1, 26_281);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_04 part 2: {result_part2}");
-    //assert_eq!(result_part2, YYY);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_04() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_04_example.txt");
+        let input = read_file(2018, "day_04_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
@@ -1,6 +1,8 @@
 //!day_05.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
 struct Unit {
@@ -48,7 +50,7 @@ impl Unit {
     }
 }
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     input: &'a str,
 }
 
@@ -105,29 +107,29 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_05.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_05 part 1: {result_part1}");
-    assert_eq!(result_part1, 11_814);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_05 part 2: {result_part2}");
-    assert_eq!(result_part2, 4_282);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_05() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_05_example.txt");
+        let input = read_file(2018, "day_05_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
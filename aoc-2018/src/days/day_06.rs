@@ -1,10 +1,12 @@
 //!day_06.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     targets: Vec<Point>,
 }
 
@@ -83,29 +85,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_06.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_06 part 1: {result_part1}");
-    assert_eq!(result_part1, 3_569);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(10_000);
-    println!("result day_06 part 2: {result_part2}");
-    assert_eq!(result_part2, 48_978);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2(10_000))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_06() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_06_example.txt");
+        let input = read_file(2018, "day_06_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
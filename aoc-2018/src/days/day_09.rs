@@ -1,7 +1,9 @@
 //!day_09.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use regex::Regex;
+use std::fmt::Display;
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 struct Marble {
@@ -42,9 +44,10 @@ impl Marble {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     players: u64,
     last_marble: u64,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -56,6 +59,7 @@ impl From<&str> for ChallengeInput {
         ChallengeInput {
             players,
             last_marble,
+            result_part2: None,
         }
     }
 }
@@ -104,28 +108,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_09.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2(false);
-    println!("result day_09 part 1: {result_part1}");
-    assert_eq!(result_part1, 393_229);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_09 part 2: {result_part2}");
-    assert_eq!(result_part2, 3_273_405_195);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2(false);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_09() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_09_example.txt");
+        let input = read_file(2018, "day_09_example.txt")?;
 
         let solutions = [32, 8_317, 146_373, 2_764, 54_718, 37_305];
         for (line, solution) in input.lines().zip(solutions) {
@@ -1,12 +1,15 @@
 //!day_10.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::{my_point::Point, my_rectangle::Rectangle};
 use regex::Regex;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     positions: Vec<Point>,
     velocities: Vec<Point>,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -27,6 +30,7 @@ impl From<&str> for ChallengeInput {
         ChallengeInput {
             positions,
             velocities,
+            result_part2: None,
         }
     }
 }
@@ -80,40 +84,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_10.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2(10);
-    println!("result day_10 part 1:\n{result_part1}");
-    let solution_1 = "\
-        #####...#........####...#....#..#....#..#####......###...####.\n\
-        #....#..#.......#....#..##...#..#....#..#....#......#...#....#\n\
-        #....#..#.......#.......##...#..#....#..#....#......#...#.....\n\
-        #....#..#.......#.......#.#..#..#....#..#....#......#...#.....\n\
-        #####...#.......#.......#.#..#..######..#####.......#...#.....\n\
-        #....#..#.......#..###..#..#.#..#....#..#...........#...#.....\n\
-        #....#..#.......#....#..#..#.#..#....#..#...........#...#.....\n\
-        #....#..#.......#....#..#...##..#....#..#.......#...#...#.....\n\
-        #....#..#.......#...##..#...##..#....#..#.......#...#...#....#\n\
-        #####...######...###.#..#....#..#....#..#........###.....####.\
-    ";
-    assert_eq!(result_part1, solution_1);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_10 part 2: {result_part2}");
-    assert_eq!(result_part2, 10_476);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2(10);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_10() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_10_example.txt");
+        let input = read_file(2018, "day_10_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2(8);
@@ -1,10 +1,12 @@
 //!day_11.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     serial: i64,
 }
 
@@ -95,28 +97,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_11.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, grid, square_grid) = challenge.solution_part_1();
-    println!("result day_11 part 1: {result_part1}");
-    assert_eq!(result_part1, "33,34");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    // To find the solution start with a good guess for max_expected_size (conservative would be 300).
-    // I use the solution to my input, 14, to reduce run time of calculation to the minimum without
-    // changing the code.
-    let result_part2 = challenge.solution_part_2(grid, square_grid, 14);
-    println!("result day_11 part 2: {result_part2}");
-    assert_eq!(result_part2, "235,118,14");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1().0)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let (_, grid, square_grid) = self.solution_part_1();
+        // To find the solution start with a good guess for max_expected_size (conservative would be 300).
+        // I use the solution to my input, 14, to reduce run time of calculation to the minimum without
+        // changing the code.
+        Ok(self.solution_part_2(grid, square_grid, 14))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_cell_value() {
@@ -132,7 +135,7 @@ mod tests {
 
     #[test]
     fn test_example_day_11() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_11_example.txt");
+        let input = read_file(2018, "day_11_example.txt")?;
 
         let solutions = [("33,45", "90,269,16"), ("21,61", "232,251,12")];
 
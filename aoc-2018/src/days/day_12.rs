@@ -1,9 +1,11 @@
 //!day_12.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::BTreeSet;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     initial_state: BTreeSet<i64>,
     spreading_rules: BTreeSet<u8>,
 }
@@ -103,29 +105,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_12.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_12 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_816);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_12 part 2: {result_part2}");
-    assert_eq!(result_part2, 399_999_999_957);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_12() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_12_example.txt");
+        let input = read_file(2018, "day_12_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,8 +1,10 @@
 //!day_13.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 enum IntersectionAction {
@@ -84,7 +86,7 @@ impl Cart {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     cart_mine: HashMap<Point, char>,
     carts: BTreeMap<Point, Cart>,
 }
@@ -161,36 +163,36 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_13.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_13 part 1: {result_part1}");
-    assert_eq!(result_part1, "83,106");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_13 part 2: {result_part2}");
-    assert_eq!(result_part2, "132,26");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_13() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_13_example_part_1.txt");
+        let input = read_file(2018, "day_13_example_part_1.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
         println!("result day_13 part 1: {result_part1}");
         assert_eq!(result_part1, "7,3");
 
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_13_example_part_2.txt");
+        let input = read_file(2018, "day_13_example_part_2.txt")?;
         let mut example = ChallengeInput::from(input);
         let result_part2 = example.solution_part_2();
         println!("result day_13 part 2: {result_part2}");
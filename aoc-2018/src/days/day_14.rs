@@ -1,6 +1,8 @@
 //!day_14.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 use std::{cell::RefCell, fmt::Write, rc::Rc};
 
 struct Recipe {
@@ -74,7 +76,7 @@ impl Recipe {
         Some(self.id + 1)
     }
 }
-struct ChallengeInput {
+pub struct ChallengeInput {
     num_recipes: u64,
     pattern: String,
 }
@@ -164,29 +166,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_14.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_14 part 1: {result_part1}");
-    assert_eq!(result_part1, "1132413111");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_14 part 2: {result_part2}");
-    assert_eq!(result_part2, 20_340_232);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_14() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_14_example.txt");
+        let input = read_file(2018, "day_14_example.txt")?;
 
         let example_data = [
             ("5158916779", "51589"),
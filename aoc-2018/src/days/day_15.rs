@@ -1,8 +1,10 @@
 //!day_15.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Default, Hash, PartialOrd, Ord)]
 struct Unit {
@@ -35,7 +37,7 @@ impl Unit {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     map: HashSet<Point>,
     initial_units: HashMap<Point, Unit>,
     units: BTreeMap<Point, Unit>,
@@ -275,25 +277,25 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_15.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(false);
-    println!("result day_15 part 1: {result_part1}");
-    assert_eq!(result_part1, 237_996);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_15 part 2: {result_part2}");
-    assert_eq!(result_part2, 69_700);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(false))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_point_ordering() {
@@ -314,7 +316,7 @@ mod tests {
 
     #[test]
     fn test_example_day_15() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_15_example.txt");
+        let input = read_file(2018, "day_15_example.txt")?;
 
         let solutions = [
             (27_730, 4_988),
@@ -1,8 +1,10 @@
 //!day_16.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 type Instruction = (usize, usize, usize, usize);
 
@@ -37,12 +39,14 @@ impl From<&str> for Sample {
 
 impl Sample {
     fn try_opcode(&self, oc: &Opcode) -> bool {
+        let mut reg = self.before.clone();
         oc.execute(
             self.instruction.1,
             self.instruction.2,
             self.instruction.3,
-            self.before.clone(),
-        ) == self.after
+            &mut reg,
+        );
+        reg == self.after
     }
 }
 
@@ -109,78 +113,54 @@ impl From<&str> for Opcode {
     }
 }
 
+impl Display for Opcode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Opcode::Addr => "addr",
+            Opcode::Addi => "addi",
+            Opcode::Mulr => "mulr",
+            Opcode::Muli => "muli",
+            Opcode::Banr => "banr",
+            Opcode::Bani => "bani",
+            Opcode::Borr => "borr",
+            Opcode::Bori => "bori",
+            Opcode::Setr => "setr",
+            Opcode::Seti => "seti",
+            Opcode::Gtir => "gtir",
+            Opcode::Gtri => "gtri",
+            Opcode::Gtrr => "gtrr",
+            Opcode::Eqir => "eqir",
+            Opcode::Eqri => "eqri",
+            Opcode::Eqrr => "eqrr",
+        };
+        write!(f, "{name}")
+    }
+}
+
 impl Opcode {
-    pub fn execute(&self, a: usize, b: usize, c: usize, mut reg: Vec<usize>) -> Vec<usize> {
+    pub fn execute(&self, a: usize, b: usize, c: usize, reg: &mut [usize]) {
         match self {
-            Opcode::Addr => {
-                reg[c] = reg[a] + reg[b];
-                reg
-            }
-            Opcode::Addi => {
-                reg[c] = reg[a] + b;
-                reg
-            }
-            Opcode::Mulr => {
-                reg[c] = reg[a] * reg[b];
-                reg
-            }
-            Opcode::Muli => {
-                reg[c] = reg[a] * b;
-                reg
-            }
-            Opcode::Banr => {
-                reg[c] = reg[a] & reg[b];
-                reg
-            }
-            Opcode::Bani => {
-                reg[c] = reg[a] & b;
-                reg
-            }
-            Opcode::Borr => {
-                reg[c] = reg[a] | reg[b];
-                reg
-            }
-            Opcode::Bori => {
-                reg[c] = reg[a] | b;
-                reg
-            }
-            Opcode::Setr => {
-                reg[c] = reg[a];
-                reg
-            }
-            Opcode::Seti => {
-                reg[c] = a;
-                reg
-            }
-            Opcode::Gtir => {
-                reg[c] = if a > reg[b] { 1 } else { 0 };
-                reg
-            }
-            Opcode::Gtri => {
-                reg[c] = if reg[a] > b { 1 } else { 0 };
-                reg
-            }
-            Opcode::Gtrr => {
-                reg[c] = if reg[a] > reg[b] { 1 } else { 0 };
-                reg
-            }
-            Opcode::Eqir => {
-                reg[c] = if a == reg[b] { 1 } else { 0 };
-                reg
-            }
-            Opcode::Eqri => {
-                reg[c] = if reg[a] == b { 1 } else { 0 };
-                reg
-            }
-            Opcode::Eqrr => {
-                reg[c] = if reg[a] == reg[b] { 1 } else { 0 };
-                reg
-            }
+            Opcode::Addr => reg[c] = reg[a] + reg[b],
+            Opcode::Addi => reg[c] = reg[a] + b,
+            Opcode::Mulr => reg[c] = reg[a] * reg[b],
+            Opcode::Muli => reg[c] = reg[a] * b,
+            Opcode::Banr => reg[c] = reg[a] & reg[b],
+            Opcode::Bani => reg[c] = reg[a] & b,
+            Opcode::Borr => reg[c] = reg[a] | reg[b],
+            Opcode::Bori => reg[c] = reg[a] | b,
+            Opcode::Setr => reg[c] = reg[a],
+            Opcode::Seti => reg[c] = a,
+            Opcode::Gtir => reg[c] = if a > reg[b] { 1 } else { 0 },
+            Opcode::Gtri => reg[c] = if reg[a] > b { 1 } else { 0 },
+            Opcode::Gtrr => reg[c] = if reg[a] > reg[b] { 1 } else { 0 },
+            Opcode::Eqir => reg[c] = if a == reg[b] { 1 } else { 0 },
+            Opcode::Eqri => reg[c] = if reg[a] == b { 1 } else { 0 },
+            Opcode::Eqrr => reg[c] = if reg[a] == reg[b] { 1 } else { 0 },
         }
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     samples: Vec<Sample>,
     instructions: Vec<Instruction>,
 }
@@ -224,7 +204,10 @@ impl ChallengeInput {
         let id_map = self.identify_opcode_ids();
         let mut register = vec![0; 4];
         for (op_id, a, b, c) in self.instructions.iter() {
-            register = id_map.get(op_id).unwrap().execute(*a, *b, *c, register);
+            id_map
+                .get(op_id)
+                .unwrap()
+                .execute(*a, *b, *c, &mut register);
         }
         register[0]
     }
@@ -300,29 +283,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_16.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_16 part 1: {result_part1}");
-    assert_eq!(result_part1, 642);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_16 part 2: {result_part2}");
-    assert_eq!(result_part2, 481);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_16() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_16_example.txt");
+        let input = read_file(2018, "day_16_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
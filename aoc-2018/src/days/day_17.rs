@@ -1,8 +1,10 @@
 //!day_17.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Ground {
@@ -14,13 +16,14 @@ enum Ground {
 }
 
 #[derive(Debug)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     min_y: i64,
     max_y: i64,
     clay: HashSet<Point>,
     buckets: Vec<(Point, Point)>,
     flowing_water: HashSet<Point>,
     settled_water: HashSet<Point>,
+    result_part2: Option<usize>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -64,6 +67,7 @@ impl From<&str> for ChallengeInput {
             buckets: Vec::new(),
             flowing_water: HashSet::new(),
             settled_water: HashSet::new(),
+            result_part2: None,
         }
     }
 }
@@ -263,28 +267,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_17.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_17 part 1: {result_part1}");
-    assert_eq!(result_part1, 27_206);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_17 part 2: {result_part2}");
-    assert_eq!(result_part2, 21_787);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_buckets() {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_17_example.txt");
+        let input = read_file(2018, "day_17_example.txt").unwrap();
         let mut example = ChallengeInput::from(input);
 
         example.identify_buckets();
@@ -299,7 +308,7 @@ mod tests {
 
     #[test]
     fn test_example_day_17() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_17_example.txt");
+        let input = read_file(2018, "day_17_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
@@ -1,11 +1,14 @@
 //!day_18.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     map: HashMap<Point, char>,
+    result_part2: Option<usize>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -17,7 +20,10 @@ impl From<&str> for ChallengeInput {
                 map.insert(pos, c);
             }
         }
-        ChallengeInput { map }
+        ChallengeInput {
+            map,
+            result_part2: None,
+        }
     }
 }
 
@@ -89,28 +95,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_18.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2(1_000_000_000);
-    println!("result day_18 part 1: {result_part1}");
-    assert_eq!(result_part1, 623_583);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_18 part 2: {result_part2}");
-    assert_eq!(result_part2, 107_912);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2(1_000_000_000);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_18_example.txt");
+        let input = read_file(2018, "day_18_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let (result_part1, _) = example.solution_part_1_and_2(10);
@@ -1,17 +1,23 @@
 //!day_20.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use petgraph::graphmap::UnGraphMap;
+use std::fmt::Display;
 use std::str::Chars;
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     input: &'a str,
+    result_part2: Option<usize>,
 }
 
 impl<'a> From<&'a str> for ChallengeInput<'a> {
     fn from(input: &'a str) -> Self {
-        ChallengeInput { input }
+        ChallengeInput {
+            input,
+            result_part2: None,
+        }
     }
 }
 
@@ -67,28 +73,33 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_20.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_20 part 1: {result_part1}");
-    assert_eq!(result_part1, 3_469);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_20 part 2: {result_part2}");
-    assert_eq!(result_part2, 8_780);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_20() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_20_example.txt");
+        let input = read_file(2018, "day_20_example.txt")?;
 
         let solutions = [3, 10, 18, 23, 31];
 
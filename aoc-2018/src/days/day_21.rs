@@ -1,73 +1,98 @@
 //!day_21.rs
 
-use crate::days::day_19::Instruction;
-use anyhow::Result;
+use crate::days::day_16::Opcode;
+use crate::elfcode::{DivisionLoop, ElfcodeMachine, HookAction, Patch, Program, Status};
+use anyhow::{Result, anyhow, bail};
+use aoc_common::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
-struct ChallengeInput {
-    register_bound: usize,
-    instructions: Vec<Instruction>,
+pub struct ChallengeInput {
+    program: Program,
+    result_part2: Option<usize>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
-        let (register_bound, instructions) = value.split_once('\n').unwrap();
-        let (_, register_bound) = register_bound.split_once(' ').unwrap();
         ChallengeInput {
-            register_bound: register_bound.parse().unwrap(),
-            instructions: instructions.lines().map(Instruction::from).collect(),
+            program: Program::from(value),
+            result_part2: None,
         }
     }
 }
 
-// If register[0] == 0, the code loops endless in a cycle of possible solutions.
+// If register[0] == 0, the code loops endless in a cycle of possible solutions, which are
+// compared with register 0 by the only instruction reading register 0.
 // --> Collect all solutions and take the first for part 1 and the last for part 2.
-// To reduce run time, we replace a slow running loop (starting at instruction 18
-// in my puzzle input) with a direct calculation of the required value (see below).
+// To reduce run time, a slow running loop dividing by counting up is replaced by DivisionLoop,
+// if it is found in the program.
+const BUDGET: u64 = 10_000_000_000;
+
 impl ChallengeInput {
-    fn solution_part_1_and_2(&self) -> (usize, usize) {
-        let mut instruction_pointer = 0;
-        let mut register: Vec<usize> = vec![0; 6];
-        let mut first_solution = None::<usize>;
-        let mut last_solution = 0;
-        let mut solutions: HashSet<usize> = HashSet::new();
-        while let Some(instruction) = self.instructions.get(instruction_pointer) {
-            register[self.register_bound] = instruction_pointer;
-            // pre processing for certain instructions (depends upon puzzle input)
-            if instruction_pointer == 18 {
-                // instead of incrementing register 5 one by one we directly set target value derived
-                // from instructions "muli 2 256 2" and "gtrr 2 4 2"
-                register[5] = register[4] / 256;
-            } else if instruction_pointer == 28 {
-                // collect possible solutions to halt program
-                let solution = register[instruction.a];
-                if !solutions.insert(solution) {
-                    return (first_solution.unwrap(), last_solution);
-                }
-                if first_solution.is_none() {
-                    first_solution = Some(solution);
-                }
-                last_solution = solution;
+    /// address and register of value, which is compared with register 0 to halt program
+    fn halting_check(&self) -> Option<(usize, usize)> {
+        let mut checks = self
+            .program
+            .instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, ins)| ins.oc == Opcode::Eqrr && (ins.a == 0) != (ins.b == 0))
+            .map(|(address, ins)| (address, if ins.a == 0 { ins.b } else { ins.a }));
+        let check = checks.next();
+        if checks.next().is_some() {
+            return None;
+        }
+        check
+    }
+    fn solution_part_1_and_2(&self) -> Result<(usize, usize)> {
+        let (address, register) = self
+            .halting_check()
+            .ok_or(anyhow!("no unique check of register 0 found"))?;
+        let mut solutions: Vec<usize> = Vec::new();
+        let mut seen: HashSet<usize> = HashSet::new();
+        let mut machine = ElfcodeMachine::new(&self.program);
+        if let Some(patch) = DivisionLoop::find(&self.program) {
+            patch.install(&mut machine);
+        }
+        // collect possible solutions to halt program
+        machine.add_hook(address, |registers| {
+            let solution = registers[register];
+            if seen.insert(solution) {
+                solutions.push(solution);
+                HookAction::Execute
+            } else {
+                HookAction::Stop
             }
-            register = instruction.execute(register);
-            instruction_pointer = register[self.register_bound] + 1;
+        });
+        match machine.run(BUDGET) {
+            Status::Stopped => (),
+            Status::Halted => bail!("program halted with register 0 == 0"),
+            Status::OutOfBudget => bail!("no cycle of solutions within {BUDGET} instructions"),
+        }
+        drop(machine);
+        match (solutions.first(), solutions.last()) {
+            (Some(first), Some(last)) => Ok((*first, *last)),
+            _ => bail!("no solutions found"),
         }
-        unreachable!()
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_21.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_21 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_797_184);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_21 part 2: {result_part2}");
-    assert_eq!(result_part2, 11_011_493);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2()?;
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 // No examples
@@ -1,10 +1,12 @@
 //!day_22.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     depth: i64,
     target: Point,
     cave: HashMap<Point, (i64, i64, i64)>,
@@ -119,29 +121,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_22.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_22 part 1: {result_part1}");
-    assert_eq!(result_part1, 6_208);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_22 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_039);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_22() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_22_example.txt");
+        let input = read_file(2018, "day_22_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
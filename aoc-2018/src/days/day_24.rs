@@ -1,8 +1,10 @@
 //!day_24.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use regex::Regex;
 use std::collections::{BTreeMap, HashSet};
+use std::fmt::Display;
 
 #[derive(Debug, Clone)]
 struct Units {
@@ -80,7 +82,7 @@ impl Units {
 }
 
 #[derive(Debug, Clone)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     immune_system: Vec<Units>,
     infections: Vec<Units>,
 }
@@ -239,29 +241,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_24.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_24 part 1: {result_part1}");
-    assert_eq!(result_part1, 21_765);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_24 part 2: {result_part2}");
-    assert_eq!(result_part2, 5_522);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_24() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_24_example.txt");
+        let input = read_file(2018, "day_24_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,7 +1,9 @@
 //!day_25.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 struct Point4D {
@@ -32,7 +34,7 @@ impl Point4D {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     points: Vec<Point4D>,
 }
 
@@ -75,25 +77,27 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2018/day_25.txt");
-    let challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const HAS_PART_2: bool = false;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_25 part 1: {result_part1}");
-    assert_eq!(result_part1, 407);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_25() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2018/day_25_example.txt");
+        let input = read_file(2018, "day_25_example.txt")?;
 
         let solutions = [2, 4, 3, 8];
 
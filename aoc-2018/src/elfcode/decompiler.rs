@@ -0,0 +1,621 @@
+//!decompiler.rs
+
+// Decompiler of elfcode into structured pseudo-code. Writes to the bound register are jumps:
+// writes, which only depend on the instruction pointer, are gotos, and `addr ip flag ip`
+// after a comparison into flag is a conditional skip of the next instruction. Comparisons
+// are folded into conditions, if their flag is dead after the skip. Back edges mark loop
+// headers, which become `loop`, `while` or `do ... while`. Forward branches become `if` and
+// `if ... else`. Everything else stays a `goto` to the address of the target.
+
+use super::program::{Mode, modes};
+use super::{Instruction, NUM_REGISTERS, Program, Registers};
+use crate::days::day_16::Opcode;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::ops::Range;
+
+/// Spaces per level of nesting.
+const INDENT: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Register(usize),
+    Value(usize),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Register(r) => write!(f, "r{r}"),
+            Operand::Value(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Condition {
+    lhs: Operand,
+    op: &'static str,
+    rhs: Operand,
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.lhs, self.op, self.rhs)
+    }
+}
+
+impl Condition {
+    fn negate(self) -> Self {
+        let op = match self.op {
+            ">" => "<=",
+            "<=" => ">",
+            "==" => "!=",
+            "!=" => "==",
+            _ => unreachable!(),
+        };
+        Condition { op, ..self }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Statement {
+    Assign(String),
+    /// Targets behind the last instruction halt the program.
+    Goto(usize),
+    /// Jumps to target, if condition is true. Otherwise continues with next statement.
+    Branch(Condition, usize),
+    /// Jump, which depends on other registers than the instruction pointer.
+    Computed(String),
+    /// Instruction, which is part of a statement at an other address.
+    Skip,
+}
+
+fn symbol(oc: Opcode) -> Option<&'static str> {
+    match oc {
+        Opcode::Addr | Opcode::Addi => Some("+"),
+        Opcode::Mulr | Opcode::Muli => Some("*"),
+        Opcode::Banr | Opcode::Bani => Some("&"),
+        Opcode::Borr | Opcode::Bori => Some("|"),
+        Opcode::Gtrr | Opcode::Gtri | Opcode::Gtir => Some(">"),
+        Opcode::Eqrr | Opcode::Eqri | Opcode::Eqir => Some("=="),
+        Opcode::Setr | Opcode::Seti => None,
+    }
+}
+
+fn is_comparison(oc: Opcode) -> bool {
+    matches!(symbol(oc), Some(">" | "=="))
+}
+
+/// Elfcode with bound register replaced by the address of each instruction.
+struct Decoder<'a> {
+    register_bound: usize,
+    instructions: &'a [Instruction],
+}
+
+impl Decoder<'_> {
+    fn operand(&self, address: usize, mode: Mode, value: usize) -> Option<Operand> {
+        match mode {
+            Mode::Register if value == self.register_bound => Some(Operand::Value(address)),
+            Mode::Register => Some(Operand::Register(value)),
+            Mode::Immediate => Some(Operand::Value(value)),
+            Mode::Unused => None,
+        }
+    }
+    fn operands(&self, address: usize) -> (Operand, Option<Operand>) {
+        let ins = self.instructions[address];
+        let (mode_a, mode_b) = modes(ins.oc);
+        (
+            self.operand(address, mode_a, ins.a).unwrap(),
+            self.operand(address, mode_b, ins.b),
+        )
+    }
+    /// registers read by instruction as bit mask
+    fn uses(&self, address: usize) -> u8 {
+        let (a, b) = self.operands(address);
+        [Some(a), b]
+            .into_iter()
+            .flatten()
+            .filter_map(|o| match o {
+                Operand::Register(r) => Some(1 << r),
+                Operand::Value(_) => None,
+            })
+            .fold(0, |mask, bit| mask | bit)
+    }
+    /// register written by instruction as bit mask
+    fn defines(&self, address: usize) -> u8 {
+        let c = self.instructions[address].c;
+        if c == self.register_bound { 0 } else { 1 << c }
+    }
+    fn expression(&self, address: usize) -> String {
+        let oc = self.instructions[address].oc;
+        match (self.operands(address), symbol(oc)) {
+            ((a, Some(b)), Some(op)) => format!("{a} {op} {b}"),
+            ((a, _), _) => a.to_string(),
+        }
+    }
+    fn comparison(&self, address: usize) -> Condition {
+        let oc = self.instructions[address].oc;
+        let (lhs, rhs) = self.operands(address);
+        Condition {
+            lhs,
+            op: symbol(oc).unwrap(),
+            rhs: rhs.unwrap(),
+        }
+    }
+    fn assignment(&self, address: usize) -> String {
+        let ins = self.instructions[address];
+        let c = Operand::Register(ins.c);
+        match (self.operands(address), symbol(ins.oc)) {
+            _ if is_comparison(ins.oc) => format!("{c} = ({})", self.comparison(address)),
+            ((a, Some(b)), Some(op)) if a == c => format!("{c} {op}= {b}"),
+            ((a, Some(b)), Some(op)) if b == c => format!("{c} {op}= {a}"),
+            _ => format!("{c} = {}", self.expression(address)),
+        }
+    }
+    /// Flag register of `addr ip flag ip`, which skips next instruction, if flag is set
+    fn skip_flag(&self, address: usize) -> Option<usize> {
+        let ins = self.instructions[address];
+        if ins.oc != Opcode::Addr || ins.c != self.register_bound {
+            return None;
+        }
+        match (ins.a == self.register_bound, ins.b == self.register_bound) {
+            (true, false) => Some(ins.b),
+            (false, true) => Some(ins.a),
+            _ => None,
+        }
+    }
+    /// Decodes instruction without looking at its neighbours.
+    fn statement(&self, address: usize) -> Statement {
+        let ins = self.instructions[address];
+        if ins.c != self.register_bound {
+            return Statement::Assign(self.assignment(address));
+        }
+        if self.uses(address) == 0 {
+            let mut register: Registers = [0; NUM_REGISTERS];
+            register[self.register_bound] = address;
+            ins.execute(&mut register);
+            return Statement::Goto(register[self.register_bound] + 1);
+        }
+        match (self.operands(address), symbol(ins.oc)) {
+            ((Operand::Value(a), Some(b)), Some("+"))
+            | ((b, Some(Operand::Value(a))), Some("+")) => {
+                Statement::Computed(format!("goto {} + {b}", a + 1))
+            }
+            _ => Statement::Computed(format!("goto {} + 1", self.expression(address))),
+        }
+    }
+}
+
+/// Pseudo-code of an elfcode program.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Decompiled {
+    /// Address ranges of basic blocks.
+    pub basic_blocks: Vec<Range<usize>>,
+    /// Targets of backward jumps.
+    pub loop_headers: Vec<usize>,
+    pub lines: Vec<String>,
+}
+
+impl Display for Decompiled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in self.lines.iter() {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Loop {
+    header: usize,
+    exit: usize,
+    /// `continue` of do-while would skip the condition at the end
+    do_while: bool,
+}
+
+struct Structurer {
+    statements: Vec<Statement>,
+    lines: Vec<String>,
+    loops: Vec<Loop>,
+    /// indent of lines
+    nesting: usize,
+}
+
+impl Structurer {
+    fn len(&self) -> usize {
+        self.statements.len()
+    }
+    /// first statement at or after address, which is not skipped
+    fn resolve(&self, address: usize) -> usize {
+        (address..self.len())
+            .find(|a| self.statements[*a] != Statement::Skip)
+            .unwrap_or(self.len())
+    }
+    /// address of next statement, which is not skipped
+    fn next(&self, address: usize) -> usize {
+        self.resolve(address + 1)
+    }
+    fn target(&self, address: usize) -> Option<usize> {
+        match self.statements[address] {
+            Statement::Goto(target) | Statement::Branch(_, target) => Some(target),
+            _ => None,
+        }
+    }
+    fn line(&mut self, address: Option<usize>, text: impl Display) {
+        let indent = " ".repeat(self.nesting);
+        match address {
+            Some(address) => self.lines.push(format!("{address:>4}: {indent}{text}")),
+            None => self.lines.push(format!("{:>4}  {indent}{text}", "")),
+        }
+    }
+    /// text of jump to target, e.g. `break`
+    fn jump(&self, target: usize) -> String {
+        if target >= self.len() {
+            return "halt".into();
+        }
+        let resolved = self.resolve(target);
+        match self.loops.last() {
+            Some(l) if l.exit == resolved => "break".into(),
+            Some(l) if self.resolve(l.header) == resolved && !l.do_while => "continue".into(),
+            _ => format!("goto {target}"),
+        }
+    }
+    fn emit(&mut self, range: Range<usize>) {
+        let mut address = range.start;
+        while address < range.end {
+            if self.loops.last().map(|l| l.header) != Some(address)
+                && let Some(latch) = (address..range.end)
+                    .rev()
+                    .find(|a| self.target(*a) == Some(address))
+            {
+                address = self.emit_loop(address, latch);
+                continue;
+            }
+            match self.statements[address].clone() {
+                Statement::Assign(text) | Statement::Computed(text) => {
+                    self.line(Some(address), text)
+                }
+                Statement::Goto(target) if target == self.next(address) => (),
+                Statement::Goto(target) => self.line(Some(address), self.jump(target)),
+                Statement::Branch(_, target) if target == self.next(address) => (),
+                Statement::Branch(condition, target) if target > address && target <= range.end => {
+                    address = self.emit_if(address, condition, target, range.end);
+                    continue;
+                }
+                Statement::Branch(condition, target) => {
+                    let text = format!("if {condition} {{ {} }}", self.jump(target));
+                    self.line(Some(address), text);
+                }
+                Statement::Skip => (),
+            }
+            address += 1;
+        }
+    }
+    /// Returns address after if statement.
+    fn emit_if(
+        &mut self,
+        address: usize,
+        condition: Condition,
+        target: usize,
+        end: usize,
+    ) -> usize {
+        let then_start = self.next(address);
+        // goto at end of then block, which jumps over else block
+        let jump_over_else = (then_start..target)
+            .rev()
+            .find(|a| self.statements[*a] != Statement::Skip)
+            .and_then(|last| match self.statements[last] {
+                Statement::Goto(after) if after > target && after <= end => Some((last, after)),
+                _ => None,
+            });
+        let then_statements: Vec<usize> = (then_start..target)
+            .filter(|a| self.statements[*a] != Statement::Skip)
+            .collect();
+        if jump_over_else.is_none()
+            && let [single] = then_statements[..]
+            && let Statement::Goto(jump) = self.statements[single]
+        {
+            let text = format!("if {} {{ {} }}", condition.negate(), self.jump(jump));
+            self.line(Some(address), text);
+            return target;
+        }
+        self.line(Some(address), format!("if {} {{", condition.negate()));
+        self.nesting += INDENT;
+        match jump_over_else {
+            Some((last, after)) => {
+                self.emit(then_start..last);
+                self.nesting -= INDENT;
+                self.line(None, "} else {");
+                self.nesting += INDENT;
+                self.emit(target..after);
+                self.nesting -= INDENT;
+                self.line(None, "}");
+                after
+            }
+            None => {
+                self.emit(then_start..target);
+                self.nesting -= INDENT;
+                self.line(None, "}");
+                target
+            }
+        }
+    }
+    /// Returns address after loop.
+    fn emit_loop(&mut self, header: usize, latch: usize) -> usize {
+        let exit = self.next(latch);
+        let first = self.resolve(header);
+        let comment = format!("// loop {header}..={latch}");
+        match self.statements[latch] {
+            Statement::Branch(condition, _) => {
+                self.line(None, format!("do {{ {comment}"));
+                self.enter(header, exit, true);
+                self.emit(header..latch);
+                self.leave();
+                self.line(Some(latch), format!("}} while {condition}"));
+            }
+            _ => match self.statements[first] {
+                Statement::Branch(condition, target) if target == exit && first < latch => {
+                    self.line(
+                        Some(first),
+                        format!("while {} {{ {comment}", condition.negate()),
+                    );
+                    self.enter(header, exit, false);
+                    self.emit(self.next(first)..latch);
+                    self.leave();
+                    self.line(None, "}");
+                }
+                _ => {
+                    self.line(None, format!("loop {{ {comment}"));
+                    self.enter(header, exit, false);
+                    self.emit(header..latch);
+                    self.leave();
+                    self.line(None, "}");
+                }
+            },
+        }
+        exit
+    }
+    fn enter(&mut self, header: usize, exit: usize, do_while: bool) {
+        self.loops.push(Loop {
+            header,
+            exit,
+            do_while,
+        });
+        self.nesting += INDENT;
+    }
+    fn leave(&mut self) {
+        self.loops.pop();
+        self.nesting -= INDENT;
+    }
+}
+
+/// Decompiles program into structured pseudo-code. Each line starts with the address of its
+/// instruction.
+pub fn decompile(program: &Program) -> Decompiled {
+    let decoder = Decoder {
+        register_bound: program.register_bound,
+        instructions: &program.instructions,
+    };
+    let len = program.instructions.len();
+    let mut statements: Vec<Statement> = (0..len).map(|a| decoder.statement(a)).collect();
+
+    // targets of all possible jumps, before skips are known
+    let mut targets: BTreeSet<usize> = statements
+        .iter()
+        .filter_map(|s| match s {
+            Statement::Goto(target) => Some(*target),
+            _ => None,
+        })
+        .collect();
+    targets.extend(
+        (0..len)
+            .filter(|a| decoder.skip_flag(*a).is_some())
+            .map(|a| a + 2),
+    );
+
+    // skip with flag of preceding comparison
+    for (address, statement) in statements.iter_mut().enumerate().skip(1) {
+        if let Some(flag) = decoder.skip_flag(address)
+            && decoder.defines(address - 1) == 1 << flag
+            && is_comparison(program.instructions[address - 1].oc)
+            && !targets.contains(&address)
+        {
+            let condition = Condition {
+                lhs: Operand::Register(flag),
+                op: "!=",
+                rhs: Operand::Value(0),
+            };
+            *statement = Statement::Branch(condition, address + 2);
+        }
+    }
+
+    let basic_blocks = basic_blocks(&statements);
+
+    // fold comparison into condition, if flag is dead after skip
+    let live_out = liveness(&decoder, &statements);
+    for address in 1..len {
+        if let Statement::Branch(condition, target) = statements[address]
+            && let Operand::Register(flag) = condition.lhs
+            && live_out[address] & (1 << flag) == 0
+        {
+            statements[address] = Statement::Branch(decoder.comparison(address - 1), target);
+            statements[address - 1] = Statement::Skip;
+        }
+    }
+
+    // skip of a goto is a branch to target of goto with negated condition
+    let targets: BTreeSet<usize> = statements
+        .iter()
+        .filter_map(|s| match s {
+            Statement::Goto(target) | Statement::Branch(_, target) => Some(*target),
+            _ => None,
+        })
+        .collect();
+    for address in 0..len.saturating_sub(1) {
+        if let Statement::Branch(condition, target) = statements[address]
+            && target == address + 2
+            && let Statement::Goto(goto) = statements[address + 1]
+            && !targets.contains(&(address + 1))
+        {
+            statements[address] = Statement::Branch(condition.negate(), goto);
+            statements[address + 1] = Statement::Skip;
+        }
+    }
+
+    let loop_headers: BTreeSet<usize> = statements
+        .iter()
+        .enumerate()
+        .filter_map(|(address, s)| match s {
+            Statement::Goto(target) | Statement::Branch(_, target) if *target <= address => {
+                Some(*target)
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut structurer = Structurer {
+        statements,
+        lines: Vec::new(),
+        loops: Vec::new(),
+        nesting: 0,
+    };
+    structurer.emit(0..len);
+    Decompiled {
+        basic_blocks,
+        loop_headers: loop_headers.into_iter().collect(),
+        lines: structurer.lines,
+    }
+}
+
+fn successors(statements: &[Statement], address: usize) -> Option<Vec<usize>> {
+    match statements[address] {
+        Statement::Assign(_) | Statement::Skip => Some(vec![address + 1]),
+        Statement::Goto(target) => Some(vec![target]),
+        Statement::Branch(_, target) => Some(vec![address + 1, target]),
+        Statement::Computed(_) => None,
+    }
+}
+
+fn basic_blocks(statements: &[Statement]) -> Vec<Range<usize>> {
+    let len = statements.len();
+    let mut leaders: BTreeSet<usize> = BTreeSet::from([0]);
+    for (address, statement) in statements.iter().enumerate() {
+        if !matches!(statement, Statement::Assign(_)) {
+            leaders.insert(address + 1);
+            leaders.extend(successors(statements, address).into_iter().flatten());
+        }
+    }
+    let leaders: Vec<usize> = leaders.into_iter().filter(|l| *l < len).collect();
+    leaders
+        .iter()
+        .zip(leaders.iter().skip(1).chain(std::iter::once(&len)))
+        .map(|(start, end)| *start..*end)
+        .collect()
+}
+
+/// Registers, which are live after each instruction, as bit mask. Register 0 holds the result
+/// at halt. All registers are live after computed jumps.
+fn liveness(decoder: &Decoder, statements: &[Statement]) -> Vec<u8> {
+    let all = (1 << NUM_REGISTERS) - 1;
+    let result = 1;
+    let len = statements.len();
+    let mut live_in = vec![0_u8; len];
+    let mut live_out = vec![0_u8; len];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for address in (0..len).rev() {
+            let out = match successors(statements, address) {
+                Some(successors) => successors
+                    .into_iter()
+                    .map(|s| live_in.get(s).copied().unwrap_or(result))
+                    .fold(0, |mask, live| mask | live),
+                None => all,
+            };
+            let inp = decoder.uses(address) | (out & !decoder.defines(address));
+            if out != live_out[address] || inp != live_in[address] {
+                live_out[address] = out;
+                live_in[address] = inp;
+                changed = true;
+            }
+        }
+    }
+    live_out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // same structure as day 21, but with other constants
+    const PROGRAM: &str = "#ip 3
+seti 123 0 4
+bani 4 456 4
+eqri 4 72 4
+addr 4 3 3
+seti 0 0 3
+seti 0 2 4
+bori 4 65536 1
+seti 1234567 1 4
+bani 1 255 5
+addr 4 5 4
+bani 4 16777215 4
+muli 4 65899 4
+bani 4 16777215 4
+gtir 256 1 5
+addr 5 3 3
+addi 3 1 3
+seti 27 1 3
+seti 0 5 5
+addi 5 1 2
+muli 2 256 2
+gtrr 2 1 2
+addr 2 3 3
+addi 3 1 3
+seti 25 2 3
+addi 5 1 5
+seti 17 8 3
+setr 5 2 1
+seti 7 4 3
+eqrr 4 0 5
+addr 5 3 3
+seti 5 3 3";
+
+    #[test]
+    fn test_decompile() {
+        let decompiled = decompile(&Program::from(PROGRAM));
+        println!("{decompiled}");
+        assert_eq!(decompiled.loop_headers, [1, 6, 8, 18]);
+        assert_eq!(decompiled.basic_blocks[..3], [0..1, 1..4, 4..5]);
+        let expected = [
+            "   0: r4 = 123",
+            "      do { // loop 1..=3",
+            "   1:     r4 &= 456",
+            "   2:     r4 = (r4 == 72)",
+            "   3: } while r4 == 0",
+            "   5: r4 = 0",
+            "      do { // loop 6..=29",
+            "   6:     r1 = r4 | 65536",
+            "   7:     r4 = 1234567",
+            "          loop { // loop 8..=27",
+            "   8:         r5 = r1 & 255",
+            "   9:         r4 += r5",
+            "  10:         r4 &= 16777215",
+            "  11:         r4 *= 65899",
+            "  12:         r4 &= 16777215",
+            "  14:         if 256 > r1 { break }",
+            "  17:         r5 = 0",
+            "              loop { // loop 18..=25",
+            "  18:             r2 = r5 + 1",
+            "  19:             r2 *= 256",
+            "  21:             if r2 > r1 { break }",
+            "  24:             r5 += 1",
+            "              }",
+            "  26:         r1 = r5",
+            "          }",
+            "  29: } while r4 != r0",
+        ];
+        assert_eq!(decompiled.lines, expected);
+    }
+}
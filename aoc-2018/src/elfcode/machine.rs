@@ -0,0 +1,192 @@
+//!machine.rs
+
+// Elfcode virtual machine with the instruction pointer bound to a register. Hooks run before
+// the instruction at their address, e.g. to observe registers or to replace a slow loop (see
+// patch.rs). Executed instructions are counted per address to find such loops.
+
+use super::{Instruction, Program};
+use std::fmt::Display;
+
+/// Number of registers of elfcode.
+pub const NUM_REGISTERS: usize = 6;
+
+pub type Registers = [usize; NUM_REGISTERS];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookAction {
+    /// execute instruction at address of hook
+    Execute,
+    /// continue at address without executing instruction at address of hook
+    Jump(usize),
+    /// return from run() before instruction at address of hook
+    Stop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// instruction pointer left program
+    Halted,
+    /// stopped by hook
+    Stopped,
+    OutOfBudget,
+}
+
+type Hook<'a> = Box<dyn FnMut(&mut Registers) -> HookAction + 'a>;
+
+pub struct ElfcodeMachine<'a> {
+    program: &'a Program,
+    pub registers: Registers,
+    pub ip: usize,
+    /// executed instructions; a jump of a hook counts as one instruction
+    pub steps: u64,
+    /// hooks of each address
+    hooks: Vec<Vec<Hook<'a>>>,
+    /// executed instructions of each address
+    counts: Vec<u64>,
+}
+
+impl<'a> ElfcodeMachine<'a> {
+    pub fn new(program: &'a Program) -> Self {
+        let len = program.instructions.len();
+        ElfcodeMachine {
+            program,
+            registers: [0; NUM_REGISTERS],
+            ip: 0,
+            steps: 0,
+            hooks: (0..len).map(|_| Vec::new()).collect(),
+            counts: vec![0; len],
+        }
+    }
+    /// Adds hook, which runs before instruction at address. Hooks of the same address run in
+    /// order of adding, until one of them does not return Execute.
+    pub fn add_hook(
+        &mut self,
+        address: usize,
+        hook: impl FnMut(&mut Registers) -> HookAction + 'a,
+    ) {
+        self.hooks[address].push(Box::new(hook));
+    }
+    fn run_hooks(&mut self) -> HookAction {
+        for hook in self.hooks[self.ip].iter_mut() {
+            match hook(&mut self.registers) {
+                HookAction::Execute => (),
+                action => return action,
+            }
+        }
+        HookAction::Execute
+    }
+    /// Runs at most budget instructions.
+    pub fn run(&mut self, budget: u64) -> Status {
+        let register_bound = self.program.register_bound;
+        let end = self.steps.saturating_add(budget);
+        while let Some(instruction) = self.program.instructions.get(self.ip) {
+            if self.steps == end {
+                return Status::OutOfBudget;
+            }
+            self.registers[register_bound] = self.ip;
+            if !self.hooks[self.ip].is_empty() {
+                match self.run_hooks() {
+                    HookAction::Execute => (),
+                    HookAction::Jump(address) => {
+                        self.ip = address;
+                        self.steps += 1;
+                        continue;
+                    }
+                    HookAction::Stop => return Status::Stopped,
+                }
+            }
+            instruction.execute(&mut self.registers);
+            self.counts[self.ip] += 1;
+            self.steps += 1;
+            self.ip = self.registers[register_bound] + 1;
+        }
+        Status::Halted
+    }
+    /// Executed instructions per address, hottest address first.
+    pub fn profile(&self) -> Profile {
+        let mut hot: Vec<(usize, u64, Instruction)> = self
+            .counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(address, count)| (address, *count, self.program.instructions[address]))
+            .collect();
+        hot.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        Profile {
+            steps: self.steps,
+            hot,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub steps: u64,
+    /// address, number of executions and instruction
+    pub hot: Vec<(usize, u64, Instruction)>,
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "steps: {}", self.steps)?;
+        for (address, count, instruction) in self.hot.iter() {
+            let share = *count as f64 * 100.0 / self.steps.max(1) as f64;
+            writeln!(f, "{address:>4}: {count:>12} {share:>5.1}%  {instruction}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // counts r0 and r3 up to r2 = 3
+    const PROGRAM: &str = "#ip 4
+seti 3 0 2
+addi 0 1 0
+addi 3 1 3
+eqrr 3 2 5
+addr 4 5 4
+seti 0 0 4";
+
+    #[test]
+    fn test_elfcode_machine() {
+        let program = Program::from(PROGRAM);
+        let mut machine = ElfcodeMachine::new(&program);
+        assert_eq!(machine.run(10), Status::OutOfBudget);
+        assert_eq!(machine.run(100), Status::Halted);
+        assert_eq!(machine.registers[0], 3);
+        let profile = machine.profile();
+        assert_eq!(profile.steps, 15);
+        assert_eq!(profile.hot[0], (1, 3, program.instructions[1]));
+        assert_eq!(profile.hot.last().unwrap().0, 0);
+        assert!(
+            profile
+                .to_string()
+                .contains("   1:            3  20.0%  addi 0 1 0")
+        );
+
+        // jump over loop after setting result
+        let mut seen = Vec::new();
+        let mut machine = ElfcodeMachine::new(&program);
+        machine.add_hook(1, |registers| {
+            seen.push(registers[0]);
+            HookAction::Execute
+        });
+        machine.add_hook(1, |registers| {
+            registers[0] = registers[2];
+            HookAction::Jump(6)
+        });
+        assert_eq!(machine.run(100), Status::Halted);
+        assert_eq!(machine.registers[0], 3);
+        assert_eq!(machine.steps, 2);
+        drop(machine);
+        assert_eq!(seen, [0]);
+
+        let mut machine = ElfcodeMachine::new(&program);
+        machine.add_hook(3, |_| HookAction::Stop);
+        assert_eq!(machine.run(100), Status::Stopped);
+        assert_eq!((machine.ip, machine.registers[0]), (3, 1));
+    }
+}
@@ -0,0 +1,13 @@
+//!mod.rs
+
+// Tools for elfcode programs of 2018 (days 19 and 21).
+
+mod decompiler;
+mod machine;
+mod patch;
+mod program;
+
+pub use decompiler::*;
+pub use machine::*;
+pub use patch::*;
+pub use program::*;
@@ -0,0 +1,342 @@
+//!patch.rs
+
+// Replacements of slow loops of elfcode programs by direct calculations. A patch is only
+// installed, if its loop is found in the program with consistent registers and jump targets.
+// Therefore addresses and registers of the loops are taken from each input instead of being
+// hard coded. Use `aoc elfcode decompile` and `aoc elfcode profile` to find such loops.
+
+use super::{ElfcodeMachine, HookAction, Instruction, NUM_REGISTERS, Program};
+use crate::days::day_16::Opcode;
+use std::collections::HashSet;
+
+pub trait Patch: Sized {
+    /// Finds loop of patch in program. Returns None, if program does not contain it.
+    fn find(program: &Program) -> Option<Self>;
+
+    /// Adds hook, which replaces the loop.
+    fn install(&self, machine: &mut ElfcodeMachine);
+}
+
+/// Instructions, which only use immediate values for jumps and operations on registers, which
+/// are all distinct.
+struct Matcher {
+    register_bound: usize,
+    address: usize,
+}
+
+impl Matcher {
+    fn distinct(&self, registers: &[usize]) -> bool {
+        let mut seen: HashSet<usize> = HashSet::from([self.register_bound]);
+        registers
+            .iter()
+            .all(|r| *r < NUM_REGISTERS && seen.insert(*r))
+    }
+    /// operands a and b in any order
+    fn pair(ins: &Instruction, x: usize, y: usize) -> bool {
+        (ins.a, ins.b) == (x, y) || (ins.a, ins.b) == (y, x)
+    }
+    /// `seti`, which jumps to instruction with offset from start of loop
+    fn goto(&self, ins: &Instruction, offset: usize) -> bool {
+        ins.oc == Opcode::Seti && ins.c == self.register_bound && ins.a + 1 == self.address + offset
+    }
+    /// `addr ip flag ip`, which skips next instruction, if flag is set
+    fn skip(&self, ins: &Instruction, flag: usize) -> bool {
+        ins.oc == Opcode::Addr
+            && ins.c == self.register_bound
+            && Self::pair(ins, flag, self.register_bound)
+    }
+    /// `addi ip 1 ip`, which skips next instruction
+    fn skip_next(&self, ins: &Instruction) -> bool {
+        ins.oc == Opcode::Addi
+            && (ins.a, ins.b, ins.c) == (self.register_bound, 1, self.register_bound)
+    }
+    fn increment(ins: &Instruction, register: usize) -> bool {
+        ins.oc == Opcode::Addi && (ins.a, ins.b, ins.c) == (register, 1, register)
+    }
+}
+
+/// Sum of all divisors of n (day 19):
+///
+/// ```text
+/// a = 1
+/// do {
+///     b = 1
+///     do {
+///         if a * b == n { sum += a }
+///         b += 1
+///     } while b <= n
+///     a += 1
+/// } while a <= n
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivisorSum {
+    pub address: usize,
+    a: usize,
+    b: usize,
+    flag: usize,
+    n: usize,
+    sum: usize,
+}
+
+const DIVISOR_SUM_LEN: usize = 15;
+
+pub fn sum_of_divisors(n: usize) -> usize {
+    (1..=n.isqrt())
+        .filter(|d| n.is_multiple_of(*d))
+        .map(|d| if d * d == n { d } else { d + n / d })
+        .sum()
+}
+
+impl Patch for DivisorSum {
+    fn find(program: &Program) -> Option<Self> {
+        program
+            .instructions
+            .windows(DIVISOR_SUM_LEN)
+            .enumerate()
+            .find_map(|(address, window)| {
+                let m = Matcher {
+                    register_bound: program.register_bound,
+                    address,
+                };
+                let [
+                    init_a,
+                    init_b,
+                    mul,
+                    eq,
+                    skip_add,
+                    skip_over_add,
+                    add,
+                    inc_b,
+                    gt_b,
+                    skip_b,
+                    loop_b,
+                    inc_a,
+                    gt_a,
+                    skip_a,
+                    loop_a,
+                ] = window
+                else {
+                    return None;
+                };
+                let (a, b, flag, sum) = (init_a.c, init_b.c, mul.c, add.c);
+                let n = if eq.a == flag { eq.b } else { eq.a };
+                let valid = m.distinct(&[a, b, flag, n, sum])
+                    && (init_a.oc, init_a.a) == (Opcode::Seti, 1)
+                    && (init_b.oc, init_b.a) == (Opcode::Seti, 1)
+                    && mul.oc == Opcode::Mulr
+                    && Matcher::pair(mul, a, b)
+                    && eq.oc == Opcode::Eqrr
+                    && Matcher::pair(eq, flag, n)
+                    && eq.c == flag
+                    && m.skip(skip_add, flag)
+                    && m.skip_next(skip_over_add)
+                    && add.oc == Opcode::Addr
+                    && Matcher::pair(add, a, sum)
+                    && Matcher::increment(inc_b, b)
+                    && (gt_b.oc, gt_b.a, gt_b.b, gt_b.c) == (Opcode::Gtrr, b, n, flag)
+                    && m.skip(skip_b, flag)
+                    && m.goto(loop_b, 2)
+                    && Matcher::increment(inc_a, a)
+                    && (gt_a.oc, gt_a.a, gt_a.b, gt_a.c) == (Opcode::Gtrr, a, n, flag)
+                    && m.skip(skip_a, flag)
+                    && m.goto(loop_a, 1);
+                valid.then_some(DivisorSum {
+                    address,
+                    a,
+                    b,
+                    flag,
+                    n,
+                    sum,
+                })
+            })
+    }
+
+    fn install(&self, machine: &mut ElfcodeMachine) {
+        let patch = *self;
+        machine.add_hook(self.address, move |registers| {
+            let n = registers[patch.n];
+            registers[patch.sum] += sum_of_divisors(n);
+            // registers after both loops
+            registers[patch.a] = n.max(1) + 1;
+            registers[patch.b] = n.max(1) + 1;
+            registers[patch.flag] = 1;
+            HookAction::Jump(patch.address + DIVISOR_SUM_LEN)
+        });
+    }
+}
+
+/// Division by counting the quotient up (day 21):
+///
+/// ```text
+/// quotient = 0
+/// loop {
+///     if (quotient + 1) * divisor > n { break }
+///     quotient += 1
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivisionLoop {
+    pub address: usize,
+    quotient: usize,
+    divisor: usize,
+    n: usize,
+}
+
+const DIVISION_LOOP_LEN: usize = 9;
+
+impl Patch for DivisionLoop {
+    fn find(program: &Program) -> Option<Self> {
+        program
+            .instructions
+            .windows(DIVISION_LOOP_LEN)
+            .enumerate()
+            .find_map(|(address, window)| {
+                let m = Matcher {
+                    register_bound: program.register_bound,
+                    address,
+                };
+                let [
+                    init,
+                    inc,
+                    mul,
+                    gt,
+                    skip_exit,
+                    skip_over_exit,
+                    exit,
+                    inc_q,
+                    loop_q,
+                ] = window
+                else {
+                    return None;
+                };
+                let (quotient, product, n) = (init.c, inc.c, gt.b);
+                let valid = m.distinct(&[quotient, product, n])
+                    && (init.oc, init.a) == (Opcode::Seti, 0)
+                    && (inc.oc, inc.a, inc.b) == (Opcode::Addi, quotient, 1)
+                    && (mul.oc, mul.a, mul.c) == (Opcode::Muli, product, product)
+                    && mul.b > 0
+                    && (gt.oc, gt.a, gt.c) == (Opcode::Gtrr, product, product)
+                    && m.skip(skip_exit, product)
+                    && m.skip_next(skip_over_exit)
+                    && m.goto(exit, DIVISION_LOOP_LEN)
+                    && Matcher::increment(inc_q, quotient)
+                    && m.goto(loop_q, 1);
+                valid.then_some(DivisionLoop {
+                    address: address + 1,
+                    quotient,
+                    divisor: mul.b,
+                    n,
+                })
+            })
+    }
+
+    fn install(&self, machine: &mut ElfcodeMachine) {
+        let patch = *self;
+        // loop ends after one iteration with final quotient
+        machine.add_hook(self.address, move |registers| {
+            registers[patch.quotient] = registers[patch.n] / patch.divisor;
+            HookAction::Execute
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elfcode::Status;
+
+    // same structure as day 19, but with other constants
+    const DIVISOR_SUM: &str = "#ip 5
+addi 5 16 5
+seti 1 8 2
+seti 1 0 4
+mulr 2 4 3
+eqrr 3 1 3
+addr 3 5 5
+addi 5 1 5
+addr 2 0 0
+addi 4 1 4
+gtrr 4 1 3
+addr 5 3 5
+seti 2 0 5
+addi 2 1 2
+gtrr 2 1 3
+addr 3 5 5
+seti 1 4 5
+mulr 5 5 5
+addi 1 2 1
+mulr 1 1 1
+mulr 5 1 1
+muli 1 11 1
+addi 3 3 3
+mulr 3 5 3
+addi 3 5 3
+addr 1 3 1
+addr 5 0 5
+seti 0 0 5
+setr 5 3 3
+mulr 3 5 3
+addr 5 3 3
+mulr 5 3 3
+muli 3 14 3
+mulr 3 5 3
+addr 1 3 1
+seti 0 1 0
+seti 0 0 5";
+
+    fn run(program: &Program, register_0: usize, patch: Option<DivisorSum>) -> usize {
+        let mut machine = ElfcodeMachine::new(program);
+        machine.registers[0] = register_0;
+        if let Some(patch) = patch {
+            patch.install(&mut machine);
+        }
+        assert_eq!(machine.run(100_000_000), Status::Halted);
+        machine.registers[0]
+    }
+
+    #[test]
+    fn test_divisor_sum() {
+        let program = Program::from(DIVISOR_SUM);
+        let patch = DivisorSum::find(&program);
+        assert_eq!(patch.map(|p| p.address), Some(1));
+        // patched program returns same result as original program
+        let result = run(&program, 0, None);
+        assert_eq!(result, sum_of_divisors(4 * 19 * 11 + 3 * 22 + 5));
+        assert_eq!(run(&program, 0, patch), result);
+        assert_eq!(sum_of_divisors(12), 28);
+        assert_eq!(sum_of_divisors(16), 31);
+
+        // other register of n does not match
+        let program = Program::from(DIVISOR_SUM.replace("gtrr 2 1 3", "gtrr 2 4 3").as_str());
+        assert_eq!(DivisorSum::find(&program), None);
+    }
+
+    #[test]
+    fn test_division_loop() {
+        let program = Program::from(
+            "#ip 3
+seti 1000 0 1
+seti 0 5 5
+addi 5 1 2
+muli 2 256 2
+gtrr 2 1 2
+addr 2 3 3
+addi 3 1 3
+seti 9 2 3
+addi 5 1 5
+seti 1 8 3
+setr 5 2 1",
+        );
+        let patch = DivisionLoop::find(&program).unwrap();
+        assert_eq!(patch.address, 2);
+        for patch in [None, Some(patch)] {
+            let mut machine = ElfcodeMachine::new(&program);
+            if let Some(patch) = patch {
+                patch.install(&mut machine);
+            }
+            assert_eq!(machine.run(1_000), Status::Halted);
+            assert_eq!(machine.registers[1], 1000 / 256);
+            assert_eq!(machine.registers[2], 1);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//!program.rs
+
+// Elfcode program of days 19 and 21: instructions with opcodes of day 16 and the register,
+// which is bound to the instruction pointer.
+
+use super::NUM_REGISTERS;
+use crate::days::day_16::{ALL_OPCODES, Opcode};
+use anyhow::{Result, anyhow, bail};
+use std::fmt::Display;
+
+/// Mode of operands a and b.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Mode {
+    Register,
+    Immediate,
+    Unused,
+}
+
+pub(super) fn modes(oc: Opcode) -> (Mode, Mode) {
+    match oc {
+        Opcode::Addr | Opcode::Mulr | Opcode::Banr | Opcode::Borr | Opcode::Gtrr | Opcode::Eqrr => {
+            (Mode::Register, Mode::Register)
+        }
+        Opcode::Addi | Opcode::Muli | Opcode::Bani | Opcode::Bori | Opcode::Gtri | Opcode::Eqri => {
+            (Mode::Register, Mode::Immediate)
+        }
+        Opcode::Gtir | Opcode::Eqir => (Mode::Immediate, Mode::Register),
+        Opcode::Setr => (Mode::Register, Mode::Unused),
+        Opcode::Seti => (Mode::Immediate, Mode::Unused),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub oc: Opcode,
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+impl From<&str> for Instruction {
+    fn from(value: &str) -> Self {
+        Instruction::parse(value).unwrap()
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {} {}", self.oc, self.a, self.b, self.c)
+    }
+}
+
+impl Instruction {
+    /// Parses instruction and checks, that all registers are smaller than NUM_REGISTERS.
+    pub fn parse(value: &str) -> Result<Self> {
+        let mut input_iter = value.split_whitespace();
+        let name = input_iter
+            .next()
+            .ok_or(anyhow!("missing opcode in '{value}'"))?;
+        let oc = ALL_OPCODES
+            .into_iter()
+            .find(|oc| oc.to_string() == name)
+            .ok_or(anyhow!("unknown opcode '{name}' in '{value}'"))?;
+        let mut operands = [0; 3];
+        for (operand, name) in operands.iter_mut().zip(["a", "b", "c"]) {
+            let number = input_iter
+                .next()
+                .ok_or(anyhow!("missing operand {name} in '{value}'"))?;
+            *operand = number
+                .parse()
+                .map_err(|_| anyhow!("invalid operand {name} '{number}' in '{value}'"))?;
+        }
+        if input_iter.next().is_some() {
+            bail!("more than three operands in '{value}'");
+        }
+        let [a, b, c] = operands;
+        let (mode_a, mode_b) = modes(oc);
+        for (mode, register, name) in [(mode_a, a, "a"), (mode_b, b, "b"), (Mode::Register, c, "c")]
+        {
+            if mode == Mode::Register && register >= NUM_REGISTERS {
+                bail!("register {name} '{register}' out of range in '{value}'");
+            }
+        }
+        Ok(Instruction { oc, a, b, c })
+    }
+    pub fn execute(&self, reg: &mut [usize]) {
+        self.oc.execute(self.a, self.b, self.c, reg)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    /// register, which is bound to the instruction pointer by `#ip`
+    pub register_bound: usize,
+    pub instructions: Vec<Instruction>,
+}
+
+impl From<&str> for Program {
+    fn from(value: &str) -> Self {
+        Program::parse(value).unwrap()
+    }
+}
+
+impl Program {
+    /// Parses program with `#ip` in first line. Other than From this returns an error for
+    /// malformed input instead of panicking.
+    pub fn parse(value: &str) -> Result<Self> {
+        let (register_bound, instructions) = value
+            .split_once('\n')
+            .ok_or(anyhow!("program without instructions"))?;
+        let register_bound = register_bound
+            .strip_prefix("#ip ")
+            .ok_or(anyhow!("missing #ip in first line '{register_bound}'"))?;
+        let register_bound: usize = register_bound
+            .trim()
+            .parse()
+            .map_err(|_| anyhow!("invalid #ip register '{register_bound}'"))?;
+        if register_bound >= NUM_REGISTERS {
+            bail!("#ip register '{register_bound}' out of range");
+        }
+        let instructions = instructions
+            .lines()
+            .enumerate()
+            .map(|(line, ins)| {
+                Instruction::parse(ins).map_err(|err| anyhow!("line {}: {err}", line + 2))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Program {
+            register_bound,
+            instructions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Program::parse("#ip 1\nseti 5 0 1\naddr 1 2 3").is_ok());
+        for (program, error) in [
+            ("#ip 6\nseti 5 0 1", "#ip register '6' out of range"),
+            ("#ip 1\nseti 5 0", "line 2: missing operand c in 'seti 5 0'"),
+            (
+                "#ip 1\nseti x 0 1",
+                "line 2: invalid operand a 'x' in 'seti x 0 1'",
+            ),
+            (
+                "#ip 1\nmov 5 0 1",
+                "line 2: unknown opcode 'mov' in 'mov 5 0 1'",
+            ),
+            (
+                "#ip 1\nseti 5 0 1\naddr 6 2 3",
+                "line 3: register a '6' out of range in 'addr 6 2 3'",
+            ),
+            (
+                "#ip 1\nseti 5 0 8",
+                "line 2: register c '8' out of range in 'seti 5 0 8'",
+            ),
+        ] {
+            assert_eq!(Program::parse(program).unwrap_err().to_string(), error);
+        }
+    }
+}
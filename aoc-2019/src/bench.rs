@@ -0,0 +1,36 @@
+//!bench.rs
+
+// Variants of solutions, which are registered only to compare implementations in benchmarks.
+
+use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
+use std::time::Duration;
+
+/// Solutions, whose Intcode computers can switch to sparse memory.
+pub trait UseSparseMemory {
+    fn use_sparse_memory(&mut self);
+}
+
+/// Runs a solution with sparse memory. Registered as variant "sparse_memory" of days 09, 13
+/// and 23 to compare paged memory with sparse memory in benchmarks.
+pub struct SparseMemory<S>(S);
+
+impl<S: Solution + UseSparseMemory> Solution for SparseMemory<S> {
+    const HAS_PART_2: bool = S::HAS_PART_2;
+    const EXPECTED_RUNTIME: Duration = S::EXPECTED_RUNTIME;
+
+    fn parse(input: &'static str) -> Result<Self> {
+        let mut solution = S::parse(input)?;
+        solution.use_sparse_memory();
+        Ok(SparseMemory(solution))
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.0.part_1()
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.0.part_2()
+    }
+}
@@ -0,0 +1,15 @@
+// ratatui tui to watch or play the arcade game of day 13
+
+use aoc_2019::{intcode::IntCodeComputer, visualizer::*};
+use aoc_common::read_input;
+use color_eyre::eyre::eyre;
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let input = read_input(2019, 13).map_err(|err| eyre!("{err}"))?;
+    let code = IntCodeComputer::from(input);
+    let terminal = ratatui::init();
+    let result = run(terminal, ArcadeVisualization::from(code));
+    ratatui::restore();
+    result
+}
@@ -0,0 +1,15 @@
+// ratatui tui to watch the repair droid of day 15 exploring the ship
+
+use aoc_2019::{intcode::IntCodeComputer, visualizer::*};
+use aoc_common::read_input;
+use color_eyre::eyre::eyre;
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let input = read_input(2019, 15).map_err(|err| eyre!("{err}"))?;
+    let code = IntCodeComputer::from(input);
+    let terminal = ratatui::init();
+    let result = run(terminal, DroidVisualization::from(code));
+    ratatui::restore();
+    result
+}
@@ -1,12 +1,14 @@
 // ratatui tui to solve day 25
 
-use aoc_2019::day_25_tui::App;
+use aoc_2019::day_25_tui::{App, SESSION_FILE};
 
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
+    // optional argument: file to record commands in and to replay them from
+    let session_file = std::env::args().nth(1).unwrap_or(SESSION_FILE.into());
     let terminal = ratatui::init();
-    let result = App::new().run(terminal).await;
+    let result = App::new(session_file.into()).run(terminal).await;
     ratatui::restore();
     result
 }
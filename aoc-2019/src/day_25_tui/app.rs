@@ -1,6 +1,9 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, path::PathBuf};
 
-use super::{AppEvent, Event, EventHandler, IntCodeHandler, RoomCrawler, ShipRoom, ui};
+use super::{
+    AppEvent, CRAWLER_SLOT, Event, EventHandler, IntCodeHandler, RoomCrawler, SAVE_SLOTS,
+    SESSION_FILE, ShipMap, ShipRoom, ui,
+};
 use color_eyre::eyre::bail;
 use crossterm::event::KeyEventKind;
 use ratatui::{
@@ -57,6 +60,14 @@ impl ActiveArea {
     }
 }
 
+/// Map state of a save slot.
+#[derive(Debug, Clone)]
+pub struct SavedMap {
+    pub ship_map: ShipMap,
+    pub visited_rooms: HashSet<String>,
+    pub ship_room: Option<ShipRoom>,
+}
+
 /// Application.
 #[derive(Debug)]
 pub struct App {
@@ -76,6 +87,10 @@ pub struct App {
     pub ship_room: Option<ShipRoom>,
     /// visited rooms
     pub visited_rooms: HashSet<String>,
+    /// map of visited rooms
+    pub ship_map: ShipMap,
+    /// door of last move, which links last room with next room on map
+    pub last_door: Option<String>,
     /// list state for items of room
     pub state_items_of_room: ListState,
     /// collected items
@@ -84,6 +99,14 @@ pub struct App {
     pub state_collected_items: ListState,
     /// flag for check of inventory
     pub flag_check_inventory: bool,
+    /// flag to take collected items from inventory after loading or replaying a game
+    pub flag_sync_inventory: bool,
+    /// int code halted, only loading a game or replaying the session continues
+    pub int_code_halted: bool,
+    /// selected save slot
+    pub save_slot: usize,
+    /// map, visited rooms and current room of each save slot
+    pub saved_maps: Vec<Option<SavedMap>>,
     /// room crawler, which tries every door and collects every item he finds
     pub room_crawler: RoomCrawler,
     /// if true, room crawler sleeps after each generated event for a short time for visibility
@@ -92,7 +115,14 @@ pub struct App {
 
 impl Default for App {
     fn default() -> Self {
-        let (int_code_handler, int_code_task) = IntCodeHandler::new();
+        Self::new(SESSION_FILE.into())
+    }
+}
+
+impl App {
+    /// Constructs a new instance of [`App`], which records all commands in session file.
+    pub fn new(session_file: PathBuf) -> Self {
+        let (int_code_handler, int_code_task) = IntCodeHandler::new(session_file);
         Self {
             running: true,
             events: EventHandler::new(int_code_task),
@@ -102,21 +132,20 @@ impl Default for App {
             last_raw_message: String::new(),
             ship_room: None,
             visited_rooms: HashSet::new(),
+            ship_map: ShipMap::default(),
+            last_door: None,
             state_items_of_room: ListState::default(),
             collected_items: Vec::new(),
             state_collected_items: ListState::default(),
             flag_check_inventory: false,
+            flag_sync_inventory: false,
+            int_code_halted: false,
+            save_slot: 0,
+            saved_maps: vec![None; SAVE_SLOTS],
             room_crawler: RoomCrawler::default(),
             sleepy_room_crawler: true,
         }
     }
-}
-
-impl App {
-    /// Constructs a new instance of [`App`].
-    pub fn new() -> Self {
-        Self::default()
-    }
 
     /// Run the application's main loop.
     pub async fn run(mut self, mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
@@ -153,24 +182,18 @@ impl App {
                         self.state_items_of_room.select_first();
                     }
                     self.visited_rooms.insert(ship_room.name.clone());
+                    self.ship_map
+                        .enter_room(self.last_door.take().as_deref(), &ship_room);
+                    self.room_crawler.enter_room(&ship_room);
                     self.ship_room = Some(ship_room);
                     self.last_text_message.clear();
-                    self.room_crawler.enter_room(
-                        &mut self.events,
-                        &self.ship_room,
-                        &self.collected_items,
-                        &mut self.state_collected_items,
-                    );
                 }
                 AppEvent::TextMessage(text) => {
-                    self.room_crawler.collect_message(
-                        &text,
-                        &mut self.events,
-                        &self.ship_room,
-                        &self.collected_items,
-                        &mut self.state_collected_items,
-                    );
+                    self.room_crawler.collect_message(&text);
                     self.last_text_message = text;
+                    if self.flag_sync_inventory {
+                        self.sync_inventory();
+                    }
                     if self.flag_check_inventory {
                         self.check_inventory()?;
                     }
@@ -178,22 +201,50 @@ impl App {
                 AppEvent::RawMessage(raw) => {
                     self.last_raw_message = raw;
                 }
-                AppEvent::NoneAscii(_non_ascii) => {
-                    todo!("non ascii event not detected, yet.")
+                AppEvent::NoneAscii(value) => {
+                    self.last_text_message =
+                        format!("Int Computer returned non ascii value {value}");
                 }
                 AppEvent::IntCodeHalt => {
-                    self.room_crawler.active = false;
+                    self.room_crawler.stop();
+                    self.int_code_halted = true;
+                }
+                AppEvent::IntCodeOutput(output) => {
+                    self.room_crawler.forward_output(output);
+                }
+                AppEvent::CrawlerCommand(command) => {
+                    self.room_crawler.execute(
+                        &command,
+                        &mut self.events,
+                        &self.ship_room,
+                        &self.collected_items,
+                        &mut self.state_items_of_room,
+                        &mut self.state_collected_items,
+                    );
+                }
+                AppEvent::CrawlerSave => {
+                    self.save_crawler_game()?;
+                }
+                AppEvent::CrawlerLoad => {
+                    self.load_crawler_game()?;
+                }
+                AppEvent::CrawlerFinished(message) => {
+                    self.room_crawler.finish(&message);
                 }
                 AppEvent::Up => {
+                    self.last_door = Some("north".into());
                     let _ = self.int_code_handler.move_up();
                 }
                 AppEvent::Down => {
+                    self.last_door = Some("south".into());
                     let _ = self.int_code_handler.move_down();
                 }
                 AppEvent::Right => {
+                    self.last_door = Some("east".into());
                     let _ = self.int_code_handler.move_right();
                 }
                 AppEvent::Left => {
+                    self.last_door = Some("west".into());
                     let _ = self.int_code_handler.move_left();
                 }
                 AppEvent::PreviousRoomItem => {
@@ -217,6 +268,15 @@ impl App {
                 AppEvent::CheckInventory => {
                     let _ = self.int_code_handler.send_inventory_request();
                 }
+                AppEvent::SaveGame => {
+                    self.save_game()?;
+                }
+                AppEvent::LoadGame => {
+                    self.load_game()?;
+                }
+                AppEvent::ReplaySession => {
+                    self.replay_session()?;
+                }
                 AppEvent::Quit => self.quit(),
             },
         }
@@ -266,15 +326,24 @@ impl App {
             KeyCode::Char('i') if !self.room_crawler.active => {
                 self.events.send(AppEvent::CheckInventory);
             }
-            KeyCode::Enter if let ActiveArea::Crawler = self.active_area => {
-                self.room_crawler.toggle_status(
-                    &mut self.events,
-                    &self.ship_room,
-                    &self.collected_items,
-                    &mut self.state_collected_items,
-                    self.sleepy_room_crawler,
-                )
+            KeyCode::Char(slot @ '1'..='9') if !self.room_crawler.active => {
+                let slot = slot as usize - '1' as usize;
+                if slot < SAVE_SLOTS {
+                    self.save_slot = slot;
+                }
+            }
+            KeyCode::Char('s') if !self.room_crawler.active => {
+                self.events.send(AppEvent::SaveGame);
+            }
+            KeyCode::Char('l') if !self.room_crawler.active => {
+                self.events.send(AppEvent::LoadGame);
             }
+            KeyCode::Char('r') if !self.room_crawler.active => {
+                self.events.send(AppEvent::ReplaySession);
+            }
+            KeyCode::Enter if let ActiveArea::Crawler = self.active_area => self
+                .room_crawler
+                .toggle_status(&self.events, &self.ship_room, self.sleepy_room_crawler),
             KeyCode::PageUp if let ActiveArea::Crawler = self.active_area => {
                 self.room_crawler.scroll = self.room_crawler.scroll.saturating_sub(1);
                 self.room_crawler.scroll_state = self
@@ -338,6 +407,7 @@ impl App {
             self.state_items_of_room.select_previous();
             let room_item = self.ship_room.as_mut().unwrap().items.remove(selected);
             self.int_code_handler.take_room_item(&room_item)?;
+            self.ship_map.update_room(self.ship_room.as_ref().unwrap());
             self.collected_items.push(room_item.clone());
             self.collected_items.sort();
             let selected_collected_item = self
@@ -365,6 +435,7 @@ impl App {
                 .items
                 .push(collected_item.clone());
             self.ship_room.as_mut().unwrap().items.sort();
+            self.ship_map.update_room(self.ship_room.as_ref().unwrap());
             let selected_room_item = self
                 .ship_room
                 .as_ref()
@@ -401,9 +472,106 @@ impl App {
         Ok(())
     }
 
-    /// room crawler
-    pub fn run_room_crawler(&mut self) -> color_eyre::Result<()> {
-        todo!()
+    /// take collected items from inventory
+    pub fn sync_inventory(&mut self) {
+        self.collected_items = self
+            .last_text_message
+            .strip_prefix("Items in your inventory:\n")
+            .map(|inventory| {
+                inventory
+                    .lines()
+                    .filter_map(|i| i.strip_prefix("- "))
+                    .map(|i| i.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.collected_items.sort();
+        if self.collected_items.is_empty() {
+            self.state_collected_items.select(None);
+        } else {
+            self.state_collected_items.select_first();
+        }
+        self.flag_sync_inventory = false;
+    }
+
+    /// save and load games
+    pub fn save_game(&mut self) -> color_eyre::Result<()> {
+        if self.int_code_halted {
+            self.last_text_message = "Int Computer halted. Load a game or replay session.".into();
+            return Ok(());
+        }
+        self.int_code_handler.save_game(self.save_slot)?;
+        self.saved_maps[self.save_slot] = Some(SavedMap {
+            ship_map: self.ship_map.clone(),
+            visited_rooms: self.visited_rooms.clone(),
+            ship_room: self.ship_room.clone(),
+        });
+        self.last_text_message = format!("Saved game in slot {}.", self.save_slot + 1);
+        Ok(())
+    }
+
+    pub fn load_game(&mut self) -> color_eyre::Result<()> {
+        if self.int_code_handler.load_game(self.save_slot)? {
+            if let Some(saved_map) = self.saved_maps[self.save_slot].clone() {
+                self.ship_map = saved_map.ship_map;
+                self.visited_rooms = saved_map.visited_rooms;
+                self.ship_room = saved_map.ship_room;
+            }
+            self.restart_game()?;
+        } else {
+            self.last_text_message = format!("Slot {} is empty.", self.save_slot + 1);
+        }
+        Ok(())
+    }
+
+    pub fn replay_session(&mut self) -> color_eyre::Result<()> {
+        match self.int_code_handler.replay_session() {
+            Ok(_) => {
+                // only the room of the last replayed command is reported, map starts from there
+                self.ship_map = ShipMap::default();
+                self.visited_rooms.clear();
+                self.ship_room = None;
+                self.restart_game()?
+            }
+            Err(err) => {
+                self.last_text_message = format!(
+                    "Cannot replay {}: {err}",
+                    self.int_code_handler.session_file().display()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // game of room crawler does not change map, the loaded prompt resets room of checkpoint
+    fn save_crawler_game(&mut self) -> color_eyre::Result<()> {
+        self.int_code_handler.save_game(CRAWLER_SLOT)?;
+        self.room_crawler.saved_items = self.collected_items.clone();
+        Ok(())
+    }
+
+    fn load_crawler_game(&mut self) -> color_eyre::Result<()> {
+        if self.int_code_handler.load_game(CRAWLER_SLOT)? {
+            self.last_door = None;
+            self.collected_items = self.room_crawler.saved_items.clone();
+            if self.collected_items.is_empty() {
+                self.state_collected_items.select(None);
+            } else {
+                self.state_collected_items.select_first();
+            }
+        } else {
+            self.room_crawler.finish("Crawler did not save a game.");
+        }
+        Ok(())
+    }
+
+    // droid continues from loaded state, which may differ in collected items
+    fn restart_game(&mut self) -> color_eyre::Result<()> {
+        self.room_crawler.stop();
+        self.int_code_halted = false;
+        self.last_door = None;
+        self.flag_sync_inventory = true;
+        self.int_code_handler.send_inventory_request()
     }
 
     /// Set running to false to quit the application.
@@ -1,4 +1,5 @@
 use super::{IntCodeTask, ShipRoom};
+use crate::intcode::AsciiOutput;
 use color_eyre::eyre::OptionExt;
 use futures::{FutureExt, StreamExt};
 use ratatui::crossterm::event::Event as CrosstermEvent;
@@ -40,8 +41,18 @@ pub enum AppEvent {
     ShipRoom(ShipRoom),
     /// received non ascii value
     NoneAscii(i64),
-    /// received non ascii value
+    /// int code computer halted
     IntCodeHalt,
+    /// output of int code computer, which is forwarded to room crawler
+    IntCodeOutput(AsciiOutput),
+    /// command of room crawler
+    CrawlerCommand(String),
+    /// room crawler saves game at security checkpoint
+    CrawlerSave,
+    /// room crawler loads game saved at security checkpoint
+    CrawlerLoad,
+    /// room crawler finished or stopped with message
+    CrawlerFinished(String),
     /// move up
     Up,
     /// move down
@@ -64,6 +75,12 @@ pub enum AppEvent {
     DropCollectedItem,
     /// check collected items against inventory of int code
     CheckInventory,
+    /// save game in selected slot
+    SaveGame,
+    /// load game from selected slot
+    LoadGame,
+    /// replay commands of session file
+    ReplaySession,
     /// Quit the application.
     Quit,
 }
@@ -113,6 +130,11 @@ impl EventHandler {
         // reference to it
         let _ = self.sender.send(Event::App(app_event));
     }
+
+    /// Returns a sender, which queues events from other threads.
+    pub fn sender(&self) -> mpsc::UnboundedSender<Event> {
+        self.sender.clone()
+    }
 }
 
 /// A thread that handles reading crossterm events and emitting tick events on a regular schedule.
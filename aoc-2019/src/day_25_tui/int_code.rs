@@ -1,87 +1,203 @@
 // int code handling in day 25
 
 use super::{AppEvent, Event};
-use crate::days::day_05::{IntCodeComputer, IntOut};
-use std::thread;
-use std::time::Duration;
-use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender, error::TryRecvError};
+pub use crate::days::day_25::ShipRoom;
+use crate::intcode::{AsciiIntcode, AsciiOutput, HangDetection, IntCodeComputer, Snapshot};
+use aoc_common::read_input;
+use color_eyre::{Report, eyre::eyre};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// Default file, which records every command sent to the droid.
+pub const SESSION_FILE: &str = "day_25_session.txt";
+/// Number of save slots.
+pub const SAVE_SLOTS: usize = 4;
+/// Hidden save slot of room crawler behind the save slots of the user.
+pub const CRAWLER_SLOT: usize = SAVE_SLOTS;
+
+/// Messages to the thread running int code.
+#[derive(Debug)]
+enum DroidCommand {
+    /// command line for droid
+    Command(String),
+    /// save snapshot of droid in slot
+    Save(usize),
+    /// restore snapshot of droid from slot
+    Load(usize),
+    /// restart droid and run all commands
+    Replay(Vec<String>),
+}
+
+/// Sends command line to droid and reads its answer.
+fn send_line(droid: &mut AsciiIntcode, command: &str) -> Result<AsciiOutput, Report> {
+    droid.send_line(command)?;
+    Ok(droid.read_until_prompt()?)
+}
 
 #[derive(Debug)]
 pub struct IntCodeHandler {
-    in_sender: UnboundedSender<i64>,
+    in_sender: UnboundedSender<DroidCommand>,
+    /// commands sent since start or last replay
+    session: Vec<String>,
+    session_file: PathBuf,
+    /// session of each save slot
+    save_slots: Vec<Option<Vec<String>>>,
 }
 
 impl IntCodeHandler {
-    pub fn new() -> (Self, IntCodeTask) {
-        let (in_sender, in_receiver) = mpsc::unbounded_channel();
+    pub fn new(session_file: PathBuf) -> (Self, IntCodeTask) {
+        let (in_sender, mut in_receiver) = mpsc::unbounded_channel::<DroidCommand>();
         let (out_sender, out_receiver) = mpsc::unbounded_channel();
-        let err_sender = out_sender.clone();
 
-        thread::spawn(move || {
-            let input = include_str!("../../../../aoc_input/aoc-2019/day_25.txt");
+        // int code runs until it waits for the next command, therefore it gets its own thread
+        tokio::task::spawn_blocking(move || {
+            let input = match read_input(2019, 25) {
+                Ok(input) => input,
+                Err(err) => {
+                    let _ = out_sender.send(Err(eyre!("{err}")));
+                    return;
+                }
+            };
             let mut code = IntCodeComputer::from(input);
-            if let Err(err) =
-                code.run_int_code_with_mpsc(in_receiver, out_sender, None, Duration::from_millis(1))
-            {
-                let _ = err_sender.send(Err(err));
+            // droid answers every command within far less instructions
+            code.set_hang_detection(HangDetection {
+                budget: Some(10_000_000),
+                detect_cycles: false,
+            });
+            let mut droid = AsciiIntcode::from(code);
+            let start = droid.snapshot();
+            // snapshots are taken at prompts; the prompt is sent again after loading
+            let mut save_slots: Vec<Option<(Snapshot, String)>> = vec![None; SAVE_SLOTS + 1];
+            let mut last_prompt = String::new();
+            let mut output = droid.read_until_prompt().map_err(Report::from);
+            loop {
+                let halted = !matches!(output, Ok(AsciiOutput::Prompt(_)));
+                if let Ok(AsciiOutput::Prompt(prompt)) = &output {
+                    last_prompt = prompt.clone();
+                }
+                if out_sender.send(output).is_err() {
+                    return;
+                }
+                // after halt or error only loading and replay continue the game
+                output = loop {
+                    match in_receiver.blocking_recv() {
+                        Some(DroidCommand::Command(command)) if !halted => {
+                            break send_line(&mut droid, &command);
+                        }
+                        Some(DroidCommand::Command(_)) => (),
+                        Some(DroidCommand::Save(slot)) => {
+                            if !halted {
+                                save_slots[slot] = Some((droid.snapshot(), last_prompt.clone()));
+                            }
+                        }
+                        Some(DroidCommand::Load(slot)) => {
+                            if let Some((snapshot, prompt)) = &save_slots[slot] {
+                                droid.restore(snapshot);
+                                break Ok(AsciiOutput::Prompt(prompt.clone()));
+                            }
+                        }
+                        Some(DroidCommand::Replay(commands)) => {
+                            droid.restore(&start);
+                            let mut output = droid.read_until_prompt().map_err(Report::from);
+                            for command in commands.iter() {
+                                if !matches!(output, Ok(AsciiOutput::Prompt(_))) {
+                                    break;
+                                }
+                                output = send_line(&mut droid, command);
+                            }
+                            break output;
+                        }
+                        None => return,
+                    }
+                };
             }
         });
-        (Self { in_sender }, IntCodeTask { out_receiver })
+        let handler = Self {
+            in_sender,
+            session: Vec::new(),
+            session_file,
+            save_slots: vec![None; SAVE_SLOTS + 1],
+        };
+        (handler, IntCodeTask { out_receiver })
     }
-    pub fn move_up(&self) -> color_eyre::Result<()> {
-        for ch in "north\n".chars() {
-            self.in_sender.send(ch as i64)?;
-        }
+    fn send(&self, message: DroidCommand) -> color_eyre::Result<()> {
+        self.in_sender.send(message)?;
         Ok(())
     }
-    pub fn move_down(&self) -> color_eyre::Result<()> {
-        for ch in "south\n".chars() {
-            self.in_sender.send(ch as i64)?;
-        }
-        Ok(())
+    /// sends command and records it in session file
+    fn send_command(&mut self, command: String) -> color_eyre::Result<()> {
+        self.session.push(command.clone());
+        self.write_session()?;
+        self.send(DroidCommand::Command(command))
     }
-    pub fn move_right(&self) -> color_eyre::Result<()> {
-        for ch in "east\n".chars() {
-            self.in_sender.send(ch as i64)?;
-        }
+    fn write_session(&self) -> color_eyre::Result<()> {
+        let mut text = self.session.join("\n");
+        text.push('\n');
+        fs::write(&self.session_file, text)?;
         Ok(())
     }
-    pub fn move_left(&self) -> color_eyre::Result<()> {
-        for ch in "west\n".chars() {
-            self.in_sender.send(ch as i64)?;
-        }
-        Ok(())
+    pub fn session(&self) -> &[String] {
+        &self.session
     }
-    pub fn take_room_item(&self, item: &str) -> color_eyre::Result<()> {
-        for ch in "take ".chars() {
-            self.in_sender.send(ch as i64)?;
-        }
-        for ch in item.chars() {
-            self.in_sender.send(ch as i64)?;
-        }
-        self.in_sender.send('\n' as i64)?;
-        Ok(())
+    pub fn session_file(&self) -> &Path {
+        &self.session_file
     }
-    pub fn drop_collected_item(&self, item: &str) -> color_eyre::Result<()> {
-        for ch in "drop ".chars() {
-            self.in_sender.send(ch as i64)?;
-        }
-        for ch in item.chars() {
-            self.in_sender.send(ch as i64)?;
-        }
-        self.in_sender.send('\n' as i64)?;
-        Ok(())
+    pub fn is_slot_used(&self, slot: usize) -> bool {
+        self.save_slots[slot].is_some()
+    }
+    /// saves droid in slot. Do not save a halted droid, it cannot be loaded.
+    pub fn save_game(&mut self, slot: usize) -> color_eyre::Result<()> {
+        self.save_slots[slot] = Some(self.session.clone());
+        self.send(DroidCommand::Save(slot))
+    }
+    /// loads droid from slot and restores session of slot. Returns false, if slot is empty.
+    pub fn load_game(&mut self, slot: usize) -> color_eyre::Result<bool> {
+        let Some(session) = self.save_slots[slot].clone() else {
+            return Ok(false);
+        };
+        self.session = session;
+        self.write_session()?;
+        self.send(DroidCommand::Load(slot))?;
+        Ok(true)
+    }
+    /// restarts droid and replays all commands of session file. Returns number of commands.
+    pub fn replay_session(&mut self) -> color_eyre::Result<usize> {
+        let text = fs::read_to_string(&self.session_file)?;
+        self.session = text
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty())
+            .map(|l| l.to_string())
+            .collect();
+        self.send(DroidCommand::Replay(self.session.clone()))?;
+        Ok(self.session.len())
+    }
+    pub fn move_up(&mut self) -> color_eyre::Result<()> {
+        self.send_command("north".into())
+    }
+    pub fn move_down(&mut self) -> color_eyre::Result<()> {
+        self.send_command("south".into())
+    }
+    pub fn move_right(&mut self) -> color_eyre::Result<()> {
+        self.send_command("east".into())
+    }
+    pub fn move_left(&mut self) -> color_eyre::Result<()> {
+        self.send_command("west".into())
+    }
+    pub fn take_room_item(&mut self, item: &str) -> color_eyre::Result<()> {
+        self.send_command(format!("take {item}"))
+    }
+    pub fn drop_collected_item(&mut self, item: &str) -> color_eyre::Result<()> {
+        self.send_command(format!("drop {item}"))
     }
     pub fn send_inventory_request(&mut self) -> color_eyre::Result<()> {
-        for ch in "inv\n".chars() {
-            self.in_sender.send(ch as i64)?;
-        }
-        Ok(())
+        self.send_command("inv".into())
     }
 }
 
 pub struct IntCodeTask {
-    out_receiver: UnboundedReceiver<Result<(i64, IntOut), String>>,
+    out_receiver: UnboundedReceiver<Result<AsciiOutput, Report>>,
 }
 
 impl IntCodeTask {
@@ -89,43 +205,39 @@ impl IntCodeTask {
         &mut self,
         sender: mpsc::UnboundedSender<Event>,
     ) -> color_eyre::Result<()> {
-        let mut message = String::new();
-        loop {
-            match self.out_receiver.try_recv() {
-                Ok(Ok((_id, IntOut::Out(value)))) => {
-                    if value > 255 {
-                        let _ = sender.send(Event::App(AppEvent::NoneAscii(value)));
-                    } else {
-                        let ch = (value as u8) as char;
-                        message.push(ch);
-                        if message.ends_with("Command?") {
-                            // send raw string
-                            let _ = sender.send(Event::App(AppEvent::RawMessage(message.clone())));
-                            // handle == Pressure-Sensitive Floor ==
-                            if message.trim().starts_with("== Pressure-Sensitive Floor ==")
-                                && let Some(pos) = message.find("== Security Checkpoint ==")
-                            {
-                                // robot did not pass == Pressure-Sensitive Floor ==
-                                // and was ejected back to == Security Checkpoint ==
-                                let (ps, sc) = message.split_at(pos);
-                                let _ = sender.send(Event::App(AppEvent::ShipRoom(
-                                    ShipRoom::try_from(ps).unwrap(),
-                                )));
-                                message = sc.to_string();
-                            }
-                            match ShipRoom::try_from(message.as_str()) {
-                                Ok(ship_room) => {
-                                    let _ = sender.send(Event::App(AppEvent::ShipRoom(ship_room)));
-                                }
-                                Err(err) => {
-                                    let _ = sender.send(Event::App(AppEvent::TextMessage(err)));
-                                }
-                            }
-                            message.clear();
+        while let Some(output) = self.out_receiver.recv().await {
+            // room crawler gets output after all other events of output, but before halt
+            let crawler_output = output.as_ref().ok().cloned();
+            let halted = matches!(output, Ok(AsciiOutput::Halted(_)) | Err(_));
+            match output {
+                Ok(AsciiOutput::Prompt(mut message)) => {
+                    // send raw string
+                    let _ = sender.send(Event::App(AppEvent::RawMessage(message.clone())));
+                    // handle == Pressure-Sensitive Floor ==
+                    if message.trim().starts_with("== Pressure-Sensitive Floor ==")
+                        && let Some(pos) = message.find("== Security Checkpoint ==")
+                    {
+                        // robot did not pass == Pressure-Sensitive Floor ==
+                        // and was ejected back to == Security Checkpoint ==
+                        let (ps, sc) = message.split_at(pos);
+                        let _ = sender.send(Event::App(AppEvent::ShipRoom(
+                            ShipRoom::try_from(ps).unwrap(),
+                        )));
+                        message = sc.to_string();
+                    }
+                    match ShipRoom::try_from(message.as_str()) {
+                        Ok(ship_room) => {
+                            let _ = sender.send(Event::App(AppEvent::ShipRoom(ship_room)));
+                        }
+                        Err(err) => {
+                            let _ = sender.send(Event::App(AppEvent::TextMessage(err)));
                         }
                     }
                 }
-                Ok(Ok((_, IntOut::Halt))) => {
+                Ok(AsciiOutput::Value(value)) => {
+                    let _ = sender.send(Event::App(AppEvent::NoneAscii(value)));
+                }
+                Ok(AsciiOutput::Halted(message)) => {
                     if message.trim().ends_with("main airlock.\"") {
                         // reached end
                         let _ = sender.send(Event::App(AppEvent::ShipRoom(
@@ -145,69 +257,22 @@ impl IntCodeTask {
                             "unexpected halt of Int Computer.".into(),
                         )));
                     }
-                    let _ = sender.send(Event::App(AppEvent::IntCodeHalt));
                 }
-                Ok(Ok((_, IntOut::None))) => unreachable!(),
-                Ok(Err(err)) => {
+                // read_until_prompt() does not split lines
+                Ok(AsciiOutput::Line(_)) => unreachable!(),
+                Err(err) => {
                     let _ = sender.send(Event::App(AppEvent::TextMessage(format!(
                         "Int Computer returned error:\n{err}\nGame ends. You lost."
                     ))));
                 }
-                Err(TryRecvError::Empty) => {
-                    thread::sleep(Duration::from_millis(1));
-                }
-                Err(TryRecvError::Disconnected) => return Ok(()),
             }
-        }
-    }
-}
-
-#[derive(Debug, Default, Clone)]
-pub struct ShipRoom {
-    pub name: String,
-    pub description: String,
-    pub doors: Vec<String>,
-    pub items: Vec<String>,
-    pub message: String,
-}
-
-impl TryFrom<&str> for ShipRoom {
-    type Error = String;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.trim().starts_with("==") {
-            let mut ship_room = ShipRoom::default();
-            for block in value.trim().split("\n\n") {
-                if block.starts_with("==") {
-                    let (name, description) = block.split_once('\n').unwrap();
-                    ship_room.name = name.into();
-                    ship_room.description = description.into();
-                } else if let Some(doors) = block.strip_prefix("Doors here lead:\n") {
-                    ship_room.doors = doors
-                        .lines()
-                        .filter_map(|d| d.strip_prefix("- "))
-                        .map(|d| d.to_string())
-                        .collect();
-                } else if let Some(items) = block.strip_prefix("Items here:\n") {
-                    ship_room.items = items
-                        .lines()
-                        .filter_map(|d| d.strip_prefix("- "))
-                        .map(|d| d.to_string())
-                        .collect();
-                } else if block != "Command?" {
-                    ship_room.message = block.to_string();
-                }
+            if let Some(crawler_output) = crawler_output {
+                let _ = sender.send(Event::App(AppEvent::IntCodeOutput(crawler_output)));
+            }
+            if halted {
+                let _ = sender.send(Event::App(AppEvent::IntCodeHalt));
             }
-            Ok(ship_room)
-        } else {
-            let no_ship_room_message = value.trim().strip_suffix("Command?").unwrap().trim();
-            Err(no_ship_room_message.to_string())
         }
-    }
-}
-
-impl ShipRoom {
-    pub fn get_name(&self) -> Option<&str> {
-        self.name.strip_prefix("==")?.strip_suffix("==")
+        Ok(())
     }
 }
@@ -4,10 +4,12 @@ mod app;
 mod event;
 mod int_code;
 mod room_crawler;
+mod ship_map;
 mod ui;
 
 pub use app::*;
 pub use event::*;
 pub use int_code::*;
 pub use room_crawler::*;
+pub use ship_map::*;
 pub use ui::*;
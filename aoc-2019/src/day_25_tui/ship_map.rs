@@ -0,0 +1,265 @@
+// map of discovered rooms, which are laid out on a grid by the directions of their doors
+
+use petgraph::{
+    graph::{DiGraph, NodeIndex},
+    visit::EdgeRef,
+};
+use ratatui::{
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use super::ShipRoom;
+use crate::days::day_25::{SECURITY_CHECKPOINT, reverse_door};
+
+/// columns and rows of one grid cell
+const CELL_WIDTH: i32 = 7;
+const CELL_HEIGHT: i32 = 2;
+
+#[derive(Debug, Default, Clone)]
+pub struct ShipMap {
+    /// rooms with doors as edges like the ship graph of the room crawler
+    ship_graph: DiGraph<String, String>,
+    nodes: HashMap<String, NodeIndex>,
+    /// last seen state of rooms
+    rooms: HashMap<String, ShipRoom>,
+    current: Option<NodeIndex>,
+}
+
+fn direction(door: &str) -> (i32, i32) {
+    match door {
+        "north" => (0, -1),
+        "south" => (0, 1),
+        "east" => (1, 0),
+        "west" => (-1, 0),
+        _ => unreachable!(),
+    }
+}
+
+impl ShipMap {
+    /// enter room through door of current room; None if droid did not use a door
+    pub fn enter_room(&mut self, door: Option<&str>, ship_room: &ShipRoom) {
+        let node = match self.nodes.get(&ship_room.name) {
+            Some(node) => *node,
+            None => {
+                let node = self.ship_graph.add_node(ship_room.name.clone());
+                self.nodes.insert(ship_room.name.clone(), node);
+                node
+            }
+        };
+        if let Some(door) = door
+            && let Some(current) = self.current
+            && current != node
+            && self.ship_graph.find_edge(current, node).is_none()
+        {
+            self.ship_graph.add_edge(current, node, door.to_string());
+            self.ship_graph
+                .add_edge(node, current, reverse_door(door).to_string());
+        }
+        self.current = Some(node);
+        self.update_room(ship_room);
+    }
+    /// update items of room after taking or dropping an item
+    pub fn update_room(&mut self, ship_room: &ShipRoom) {
+        self.rooms.insert(ship_room.name.clone(), ship_room.clone());
+    }
+    /// grid position of each room. Rooms, whose position is already used, are moved further
+    /// in direction of door.
+    pub fn layout(&self) -> HashMap<NodeIndex, (i32, i32)> {
+        let mut positions: HashMap<NodeIndex, (i32, i32)> = HashMap::new();
+        let mut used: HashMap<(i32, i32), NodeIndex> = HashMap::new();
+        for start in self.ship_graph.node_indices() {
+            if positions.contains_key(&start) {
+                continue;
+            }
+            // unconnected rooms (e.g. after loading a game) are placed below the others
+            let row = used.keys().map(|(_, y)| y + 2).max().unwrap_or(0);
+            positions.insert(start, (0, row));
+            used.insert((0, row), start);
+            let mut queue = VecDeque::from([start]);
+            while let Some(node) = queue.pop_front() {
+                let (x, y) = positions[&node];
+                for edge in self.ship_graph.edges(node) {
+                    if positions.contains_key(&edge.target()) {
+                        continue;
+                    }
+                    let (dx, dy) = direction(edge.weight());
+                    let mut position = (x + dx, y + dy);
+                    while used.contains_key(&position) {
+                        position = (position.0 + dx, position.1 + dy);
+                    }
+                    positions.insert(edge.target(), position);
+                    used.insert(position, edge.target());
+                    queue.push_back(edge.target());
+                }
+            }
+        }
+        positions
+    }
+    /// initials of room name, e.g. "HB" for "== Hull Breach ==", or first three letters of
+    /// one word names, e.g. "Kit" for "== Kitchen =="
+    pub fn label(name: &str) -> String {
+        let name = name.trim_matches(['=', ' ']);
+        if name.contains(' ') {
+            name.split_whitespace()
+                .filter_map(|w| w.chars().next())
+                .take(3)
+                .collect()
+        } else {
+            name.chars().take(3).collect()
+        }
+    }
+    fn room_style(&self, node: NodeIndex) -> Style {
+        let name = &self.ship_graph[node];
+        let style = if name == SECURITY_CHECKPOINT {
+            Style::new().fg(Color::Red)
+        } else if self.rooms.get(name).is_some_and(|r| !r.items.is_empty()) {
+            Style::new().fg(Color::Green)
+        } else {
+            Style::new().fg(Color::Cyan)
+        };
+        if Some(node) == self.current {
+            style.bold().reversed()
+        } else {
+            style
+        }
+    }
+    /// map as lines of text followed by a legend of room labels
+    pub fn render(&self) -> Vec<Line<'static>> {
+        let positions = self.layout();
+        if positions.is_empty() {
+            return Vec::new();
+        }
+        let min_x = positions.values().map(|(x, _)| *x).min().unwrap();
+        let min_y = positions.values().map(|(_, y)| *y).min().unwrap();
+        let max_x = positions.values().map(|(x, _)| *x).max().unwrap();
+        let max_y = positions.values().map(|(_, y)| *y).max().unwrap();
+        // one column and row of margin for unexplored doors
+        let width = ((max_x - min_x + 1) * CELL_WIDTH + 2) as usize;
+        let height = ((max_y - min_y + 1) * CELL_HEIGHT + 1) as usize;
+        let mut canvas = vec![vec![(' ', Style::new()); width]; height];
+        let mut put = |column: i32, row: i32, text: &str, style: Style| {
+            for (offset, ch) in text.chars().enumerate() {
+                let column = column as usize + offset;
+                if let Some(cell) = canvas
+                    .get_mut(row as usize)
+                    .and_then(|line| line.get_mut(column))
+                {
+                    *cell = (ch, style);
+                }
+            }
+        };
+        let unexplored = Style::new().fg(Color::Magenta).bold();
+        let mut legend: BTreeMap<String, String> = BTreeMap::new();
+        for (node, (x, y)) in positions.iter() {
+            // label "[Kit]" starts one column right of cell and one row below cell start
+            let column = (x - min_x) * CELL_WIDTH + 1;
+            let row = (y - min_y) * CELL_HEIGHT + 1;
+            let name = &self.ship_graph[*node];
+            let label = Self::label(name);
+            put(column, row, &format!("[{label}]"), self.room_style(*node));
+            legend.insert(label.clone(), name.trim_matches(['=', ' ']).to_string());
+            for edge in self.ship_graph.edges(*node) {
+                let (tx, ty) = positions[&edge.target()];
+                match edge.weight().as_str() {
+                    "east" if ty == *y => {
+                        let length = (tx - x) * CELL_WIDTH - label.len() as i32 - 2;
+                        let connector = "─".repeat(length.max(0) as usize);
+                        put(
+                            column + label.len() as i32 + 2,
+                            row,
+                            &connector,
+                            Style::new(),
+                        );
+                    }
+                    "south" if tx == *x => {
+                        for r in row + 1..row + (ty - y) * CELL_HEIGHT {
+                            put(column + 1, r, "│", Style::new());
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            // doors without known room behind them
+            if let Some(room) = self.rooms.get(name) {
+                for door in room.doors.iter() {
+                    if self.ship_graph.edges(*node).any(|e| e.weight() == door) {
+                        continue;
+                    }
+                    match door.as_str() {
+                        "north" => put(column + 1, row - 1, "?", unexplored),
+                        "south" => put(column + 1, row + 1, "?", unexplored),
+                        "east" => put(column + label.len() as i32 + 2, row, "?", unexplored),
+                        "west" => put(column - 1, row, "?", unexplored),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        }
+        let mut lines: Vec<Line> = canvas
+            .into_iter()
+            .map(|line| {
+                Line::from(
+                    line.into_iter()
+                        .map(|(ch, style)| Span::styled(ch.to_string(), style))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.extend(
+            legend
+                .into_iter()
+                .map(|(label, name)| Line::from(format!("{label}: {name}"))),
+        );
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn room(name: &str, doors: &[&str], items: &[&str]) -> ShipRoom {
+        ShipRoom {
+            name: format!("== {name} =="),
+            doors: doors.iter().map(|d| d.to_string()).collect(),
+            items: items.iter().map(|i| i.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_ship_map() {
+        let mut ship_map = ShipMap::default();
+        let hull_breach = room("Hull Breach", &["north", "east", "west"], &[]);
+        ship_map.enter_room(None, &hull_breach);
+        ship_map.enter_room(
+            Some("north"),
+            &room("Kitchen", &["south", "east"], &["mug"]),
+        );
+        ship_map.enter_room(Some("east"), &room("Hallway", &["west", "south"], &[]));
+        // stables closes the loop back to hull breach
+        ship_map.enter_room(Some("south"), &room("Stables", &["north", "west"], &[]));
+        ship_map.enter_room(Some("west"), &hull_breach);
+        assert_eq!(ship_map.ship_graph.node_count(), 4);
+        assert_eq!(ship_map.ship_graph.edge_count(), 8);
+        let positions: HashMap<String, (i32, i32)> = ship_map
+            .layout()
+            .into_iter()
+            .map(|(node, position)| (ShipMap::label(&ship_map.ship_graph[node]), position))
+            .collect();
+        assert_eq!(positions["HB"], (0, 0));
+        assert_eq!(positions["Kit"], (0, -1));
+        assert_eq!(positions["Hal"], (1, -1));
+        assert_eq!(positions["Sta"], (1, 0));
+
+        let text: Vec<String> = ship_map.render().iter().map(|l| l.to_string()).collect();
+        assert_eq!(text[1], " [Kit]──[Hal]   ");
+        assert_eq!(text[2], "  │      │      ");
+        // west door of hull breach is unexplored
+        assert_eq!(text[3], "?[HB]───[Sta]   ");
+        assert_eq!(text[6], "HB: Hull Breach");
+    }
+}
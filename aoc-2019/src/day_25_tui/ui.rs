@@ -10,7 +10,7 @@ use ratatui::{
     },
 };
 
-use super::App;
+use super::{App, SAVE_SLOTS};
 
 pub fn ui(frame: &mut Frame, app: &mut App) {
     // vertical layout
@@ -18,9 +18,9 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Percentage(5),
-            Constraint::Percentage(43),
-            Constraint::Percentage(43),
-            Constraint::Percentage(9),
+            Constraint::Percentage(42),
+            Constraint::Percentage(42),
+            Constraint::Percentage(11),
         ])
         .split(frame.area());
 
@@ -51,10 +51,29 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         "Switch active area with Pos1/Home and End/Ende."
     };
 
+    let slots: String = (0..SAVE_SLOTS)
+        .map(|slot| {
+            let used = if app.int_code_handler.is_slot_used(slot) {
+                "💾"
+            } else {
+                "__"
+            };
+            if slot == app.save_slot {
+                format!("[{} {used}]", slot + 1)
+            } else {
+                format!(" {} {used} ", slot + 1)
+            }
+        })
+        .collect();
+
     let text = vec![
         Line::from(first_line),
         Line::from(app.active_area.navigation_text(app.room_crawler.active)),
         Line::from("Compare collected items with inventory from int code with: i."),
+        Line::from(format!(
+            "Select slot {slots} with 1-{SAVE_SLOTS}, save with s, load with l, replay {} with r.",
+            app.int_code_handler.session_file().display()
+        )),
         Line::from("Press `Esc`, `Ctrl-C` or `q` to stop running."),
     ];
 
@@ -88,10 +107,15 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
 
     let main_layout_bottom = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+        .constraints(vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+            Constraint::Percentage(30),
+        ])
         .split(vertical[2]);
     let area_raw_message = main_layout_bottom[0];
-    let area_crawler_messages = main_layout_bottom[1];
+    let area_ship_map = main_layout_bottom[1];
+    let area_crawler_messages = main_layout_bottom[2];
 
     // ship room
     if let Some(ship_room) = app.ship_room.as_ref() {
@@ -216,6 +240,27 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         .wrap(Wrap { trim: true });
     frame.render_widget(visited_rooms, area_visited_rooms);
 
+    // ship map
+    let block = Block::bordered()
+        .title(" Ship map ")
+        .title_bottom(
+            Line::from(vec![
+                " current ".bold().reversed(),
+                " items ".fg(Color::Green),
+                " security checkpoint ".fg(Color::Red),
+                " ? unexplored door ".fg(Color::Magenta),
+            ])
+            .centered(),
+        )
+        .title_alignment(Alignment::Left)
+        .border_type(BorderType::Rounded);
+    let ship_map = Paragraph::new(app.ship_map.render())
+        .block(block)
+        .left_aligned()
+        .fg(Color::Cyan)
+        .bg(Color::Black);
+    frame.render_widget(ship_map, area_ship_map);
+
     // collected items
     if app.collected_items.is_empty() {
         let block = Block::bordered()
@@ -1,8 +1,10 @@
 //!day_03.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::{my_line::LineSegment, my_point::Point};
 use std::collections::HashMap;
+use std::fmt::Display;
 
 struct Wire {
     wire: Vec<LineSegment>,
@@ -63,9 +65,10 @@ impl Wire {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     wire_1: Wire,
     wire_2: Wire,
+    result_part2: Option<i64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -74,6 +77,7 @@ impl From<&str> for ChallengeInput {
         ChallengeInput {
             wire_1: Wire::from(wire_1),
             wire_2: Wire::from(wire_2),
+            result_part2: None,
         }
     }
 }
@@ -89,28 +93,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_03.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solutions_part_1_and_2();
-    println!("result day_03 part 1: {result_part1}");
-    assert_eq!(result_part1, 293);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_03 part 2: {result_part2}");
-    assert_eq!(result_part2, 27_306);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solutions_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_03() -> Result<()> {
-        let multi_input = include_str!("../../../../aoc_input/aoc-2019/day_03_example.txt");
+        let multi_input = read_file(2019, "day_03_example.txt")?;
         let solutions_part_1_and_2 = [(6, 30), (159, 610), (135, 410)];
 
         for (input, (solution_part1, solution_part2)) in
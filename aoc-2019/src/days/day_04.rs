@@ -1,8 +1,10 @@
 //!day_04.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     lower_bound: u64,
     upper_bound: u64,
 }
@@ -65,29 +67,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_04.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_04 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_890);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_04 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_277);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_04() -> Result<()> {
-        let single_examples = include_str!("../../../../aoc_input/aoc-2019/day_04_example.txt");
+        let single_examples = read_file(2019, "day_04_example.txt")?;
 
         let (solution_part_1, solution_part_2) = (6, 3);
 
@@ -1,467 +1,176 @@
 //!day_05.rs
 
+use crate::intcode::IntCodeComputer;
 use anyhow::Result;
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::thread;
-use std::time::Duration;
-use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, error::TryRecvError};
+use aoc_common::Solution;
+use std::fmt::Display;
 
-pub enum IntOut {
-    None,
-    Out(i64),
-    Halt,
+pub struct ChallengeInput {
+    code: IntCodeComputer,
 }
 
-#[derive(Debug, Clone)]
-pub struct IntCodeComputer {
-    numbers: HashMap<i64, i64>,
-    index: i64,
-    relative_base: i64,
-    id: i64,
-}
-
-impl From<&str> for IntCodeComputer {
+impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
-        IntCodeComputer {
-            numbers: value
-                .split(',')
-                .enumerate()
-                .filter_map(|(i, n)| n.parse().ok().map(|d| (i as i64, d)))
-                .collect(),
-            index: 0,
-            relative_base: 0,
-            id: 0,
+        ChallengeInput {
+            code: IntCodeComputer::from(value),
         }
     }
 }
 
-impl IntCodeComputer {
-    fn solution_part_1(&self) -> i64 {
-        let mut test_data = self.clone();
+impl ChallengeInput {
+    fn solution_part_1(&self) -> Result<i64> {
+        let mut test_data = self.code.clone();
         let inputs = [1];
-        test_data.run_until_finished(&inputs)
+        Ok(test_data.run_until_finished(&inputs)?)
     }
-    fn solution_part_2(&self) -> i64 {
-        let mut test_data = self.clone();
+    fn solution_part_2(&self) -> Result<i64> {
+        let mut test_data = self.code.clone();
         let inputs = [5];
-        test_data.run_until_finished(&inputs)
-    }
-    pub fn clone_n_times(&self, n: usize) -> Vec<Self> {
-        let mut clones: Vec<Self> = Vec::with_capacity(n);
-        for _ in 0..n {
-            clones.push(self.clone());
-        }
-        clones
-    }
-    pub fn run_until_finished(&mut self, inputs: &[i64]) -> i64 {
-        let mut first_call = true;
-        let mut last_out: Option<i64> = None;
-        loop {
-            let out = if first_call {
-                first_call = false;
-                self.run_int_code(inputs)
-            } else {
-                self.run_int_code(&[])
-            };
-            match out {
-                Ok(Some(out)) => {
-                    // handle last out.
-                    if let Some(lo) = last_out
-                        && lo != 0
-                    {
-                        // some error occurred
-                        panic!(
-                            "Some test error '{lo}' occurred before index '{}'.",
-                            self.index
-                        );
-                    }
-                    last_out = Some(out);
-                }
-                Ok(None) => {
-                    // finished, handle last out
-                    if let Some(out) = last_out {
-                        return out;
-                    }
-                    panic!("no output generated");
-                }
-                Err(err) => panic!(
-                    "Some computation error '{err}' occurred at index '{}'.",
-                    self.index
-                ),
-            }
-        }
-    }
-    pub fn run_int_code(&mut self, inputs: &[i64]) -> Result<Option<i64>, String> {
-        // this is run like a state machine
-        // it returns None if finished or Some(out), if some out command has been executed
-        let mut input_index = 0;
-
-        while let Some(ext_op_code) = self.numbers.get(&self.index) {
-            let op_code = ext_op_code % 100;
-            let parameter_modes = ext_op_code / 100;
-
-            let input = if op_code == 3 {
-                *inputs
-                    .get(input_index)
-                    .ok_or(format!("invalid input index '{input_index}'"))?
-            } else {
-                0
-            };
-            match self.execute_opcode(op_code, parameter_modes, input)? {
-                IntOut::None => {
-                    if op_code == 3 {
-                        // increment input_index
-                        input_index += 1;
-                    }
-                }
-                IntOut::Out(out) => return Ok(Some(out)),
-                IntOut::Halt => break,
-            }
-        }
-        Ok(None)
-    }
-
-    fn execute_opcode(
-        &mut self,
-        op_code: i64,
-        parameter_modes: i64,
-        input: i64,
-    ) -> Result<IntOut, String> {
-        match op_code {
-            1 => {
-                // addition
-                let parameters = self.get_n_parameters(parameter_modes, 3, true)?;
-                self.set(
-                    parameters[2],
-                    parameters[0]
-                        .checked_add(parameters[1])
-                        .ok_or(String::from("checked_add overflow"))?,
-                )?;
-                self.index += 4;
-            }
-            2 => {
-                // multiplication
-                let parameters = self.get_n_parameters(parameter_modes, 3, true)?;
-                self.set(
-                    parameters[2],
-                    parameters[0]
-                        .checked_mul(parameters[1])
-                        .ok_or(String::from("checked_mul overflow"))?,
-                )?;
-                self.index += 4;
-            }
-            3 => {
-                // write input to position
-                let parameters = self.get_n_parameters(parameter_modes, 1, true)?;
-                self.set(parameters[0], input)?;
-                self.index += 2;
-            }
-            4 => {
-                // return val to out
-                let parameters = self.get_n_parameters(parameter_modes, 1, false)?;
-                let out = parameters[0];
-                self.index += 2;
-                return Ok(IntOut::Out(out));
-            }
-            5 => {
-                // jump if true
-                let parameters = self.get_n_parameters(parameter_modes, 2, false)?;
-                self.index = if parameters[0] != 0 {
-                    parameters[1]
-                } else {
-                    self.index + 3
-                }
-            }
-            6 => {
-                // jump if false
-                let parameters = self.get_n_parameters(parameter_modes, 2, false)?;
-                self.index = if parameters[0] == 0 {
-                    parameters[1]
-                } else {
-                    self.index + 3
-                }
-            }
-            7 => {
-                // less than
-                let parameters = self.get_n_parameters(parameter_modes, 3, true)?;
-                let store_val = (parameters[0] < parameters[1]) as i64;
-                self.set(parameters[2], store_val)?;
-                self.index += 4;
-            }
-            8 => {
-                // equals
-                let parameters = self.get_n_parameters(parameter_modes, 3, true)?;
-                let store_val = (parameters[0] == parameters[1]) as i64;
-                self.set(parameters[2], store_val)?;
-                self.index += 4;
-            }
-            9 => {
-                // change relative base
-                let parameters = self.get_n_parameters(parameter_modes, 1, false)?;
-                self.relative_base += parameters[0];
-                self.index += 2;
-            }
-            99 => {
-                // immediately halt
-                return Ok(IntOut::Halt);
-            }
-            _ => panic!("unknown op code"),
-        }
-        Ok(IntOut::None)
+        Ok(test_data.run_until_finished(&inputs)?)
     }
+}
 
-    fn get(&mut self, key: i64) -> Result<i64, String> {
-        if key < 0 {
-            return Err("negative index of memory".into());
-        }
-        // insert 0, if entry does not exist yet
-        Ok(*self.numbers.entry(key).or_insert(0))
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
     }
 
-    fn set(&mut self, key: i64, value: i64) -> Result<i64, String> {
-        if key < 0 {
-            return Err("negative index of memory".into());
-        }
-        // insert 0, if entry does not exist yet
-        Ok(*self
-            .numbers
-            .entry(key)
-            .and_modify(|v| *v = value)
-            .or_insert(value))
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.solution_part_1()
     }
 
-    fn get_n_parameters(
-        &mut self,
-        mut parameter_modes: i64,
-        num_parameters: usize,
-        last_is_write: bool,
-    ) -> Result<Vec<i64>, String> {
-        let mut parameters = vec![0; num_parameters];
-        for (p_index, parameter) in parameters
-            .iter_mut()
-            .enumerate()
-            .map(|(i, p)| (i as i64, p))
-        {
-            let parameter_mode = parameter_modes % 10;
-            parameter_modes /= 10;
-            *parameter = match parameter_mode {
-                0 => {
-                    // position
-                    let pos = self.get(self.index + p_index + 1)?;
-                    if pos < 0 {
-                        return Err(format!("Negative position index '{pos}'"));
-                    }
-                    // if writing position, just return writing position
-                    if p_index == (num_parameters as i64) - 1 && last_is_write {
-                        pos
-                    } else {
-                        self.get(pos)?
-                    }
-                }
-                1 => {
-                    // immediate
-                    // not valid for writing to position
-                    if p_index == (num_parameters as i64) - 1 && last_is_write {
-                        return Err(
-                            "immediate mode not valid for writing parameter to memory".into()
-                        );
-                    }
-                    self.get(self.index + p_index + 1)?
-                }
-                2 => {
-                    // relative mode
-                    let pos = self.get(self.index + p_index + 1)? + self.relative_base;
-                    if pos < 0 {
-                        return Err(format!("Negative position index '{pos}'"));
-                    }
-                    // if writing position, just return writing position
-                    if p_index == (num_parameters as i64) - 1 && last_is_write {
-                        pos
-                    } else {
-                        self.get(pos)?
-                    }
-                }
-                _ => return Err("not supported parameter mode".into()),
-            };
-        }
-        Ok(parameters)
-    }
-    pub fn set_address(&mut self, add: i64, val: i64) {
-        self.numbers
-            .entry(add)
-            .and_modify(|v| *v = val)
-            .or_insert(val);
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.solution_part_2()
     }
-    pub fn run_int_code_with_mpsc(
-        &mut self,
-        mut in_receiver: UnboundedReceiver<i64>,
-        out_sender: UnboundedSender<Result<(i64, IntOut), String>>,
-        default_if_empty: Option<i64>,
-        sleep_if_empty: Duration,
-    ) -> Result<(), String> {
-        let loop_length = 100_000;
-        let mut op_code_queue: VecDeque<(i64, i64)> = VecDeque::with_capacity(loop_length + 1);
-        let mut endless_loop_detector: HashSet<VecDeque<(i64, i64)>> =
-            HashSet::with_capacity(loop_length + 1);
-        while let Some(ext_op_code) = self.numbers.get(&self.index) {
-            let op_code = ext_op_code % 100;
-            let parameter_modes = ext_op_code / 100;
-
-            let input = if op_code == 3 {
-                match in_receiver.try_recv() {
-                    Ok(input) => {
-                        op_code_queue.clear();
-                        endless_loop_detector.clear();
-                        input
-                    }
-                    Err(TryRecvError::Empty) => {
-                        // wait a short time
-                        thread::sleep(sleep_if_empty);
-                        if let Some(default) = default_if_empty {
-                            default
-                        } else {
-                            // cycle while loop to wait for input
-                            continue;
-                        }
-                    }
-                    Err(TryRecvError::Disconnected) => return Ok(()),
-                }
-            } else {
-                0
-            };
-            if default_if_empty.is_none() {
-                op_code_queue.push_front((*ext_op_code, self.index));
-                if op_code_queue.len() > loop_length {
-                    op_code_queue.pop_back();
-                    if !endless_loop_detector.insert(op_code_queue.clone()) {
-                        let err = String::from("detected endless loop");
-                        out_sender
-                            .send(Err(err.clone()))
-                            .map_err(|err| format!("{err}"))?;
-                        return Err(err);
-                    }
-                }
-            }
-            match self.execute_opcode(op_code, parameter_modes, input) {
-                Ok(IntOut::None) => (),
-                Ok(IntOut::Out(out)) => {
-                    out_sender
-                        .send(Ok((self.id, IntOut::Out(out))))
-                        .map_err(|err| format!("{err}"))?;
-                }
-                Ok(IntOut::Halt) => {
-                    out_sender
-                        .send(Ok((self.id, IntOut::Halt)))
-                        .map_err(|err| format!("{err}"))?;
-                    break;
-                }
-                Err(err) => {
-                    out_sender
-                        .send(Err(err.clone()))
-                        .map_err(|err| format!("{err}"))?;
-                    return Err(err);
-                }
-            }
-        }
-        Ok(())
-    }
-    pub fn set_id(&mut self, id: i64) {
-        self.id = id;
-    }
-}
-
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_05.txt");
-    let challenge = IntCodeComputer::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_05 part 1: {result_part1}");
-    assert_eq!(result_part1, 7_692_125);
-
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_05 part 2: {result_part2}");
-    assert_eq!(result_part2, 14_340_395);
-
-    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::intcode::{IntcodeError, assemble};
+    use aoc_common::read_file;
 
     // there is no example for day 05,which represents the challenge
     // instead there are some int code snippets we can use for testing
 
     #[test]
     fn test_day_05_small_examples() -> Result<()> {
-        let small_examples =
-            include_str!("../../../../aoc_input/aoc-2019/day_05_small_examples.txt");
+        let small_examples = read_file(2019, "day_05_small_examples.txt")?;
         let int_code_computers: Vec<IntCodeComputer> =
             small_examples.lines().map(IntCodeComputer::from).collect();
         // input == 8 ? with position mode
         let mut example = int_code_computers[0].clone();
-        let result = example.run_until_finished(&[8]);
+        let result = example.run_until_finished(&[8])?;
         assert_eq!(result, 1);
         let mut example = int_code_computers[0].clone();
-        let result = example.run_until_finished(&[7]);
+        let result = example.run_until_finished(&[7])?;
         assert_eq!(result, 0);
 
         // input == 8 ? with immediate mode
         let mut example = int_code_computers[1].clone();
-        let result = example.run_until_finished(&[8]);
+        let result = example.run_until_finished(&[8])?;
         assert_eq!(result, 1);
         let mut example = int_code_computers[1].clone();
-        let result = example.run_until_finished(&[7]);
+        let result = example.run_until_finished(&[7])?;
         assert_eq!(result, 0);
 
         // input less than 8 ? with position mode
         let mut example = int_code_computers[2].clone();
-        let result = example.run_until_finished(&[7]);
+        let result = example.run_until_finished(&[7])?;
         assert_eq!(result, 1);
         let mut example = int_code_computers[2].clone();
-        let result = example.run_until_finished(&[8]);
+        let result = example.run_until_finished(&[8])?;
         assert_eq!(result, 0);
 
         // input less than 8 ? with immediate mode
         let mut example = int_code_computers[3].clone();
-        let result = example.run_until_finished(&[7]);
+        let result = example.run_until_finished(&[7])?;
         assert_eq!(result, 1);
         let mut example = int_code_computers[3].clone();
-        let result = example.run_until_finished(&[8]);
+        let result = example.run_until_finished(&[8])?;
         assert_eq!(result, 0);
 
         // jump: input == 0 ? with position mode
         let mut example = int_code_computers[4].clone();
-        let result = example.run_until_finished(&[7]);
+        let result = example.run_until_finished(&[7])?;
         assert_eq!(result, 1);
         let mut example = int_code_computers[4].clone();
-        let result = example.run_until_finished(&[0]);
+        let result = example.run_until_finished(&[0])?;
         assert_eq!(result, 0);
 
         // jump: input == 0 ? with immediate mode
         let mut example = int_code_computers[5].clone();
-        let result = example.run_until_finished(&[7]);
+        let result = example.run_until_finished(&[7])?;
         assert_eq!(result, 1);
         let mut example = int_code_computers[5].clone();
-        let result = example.run_until_finished(&[0]);
+        let result = example.run_until_finished(&[0])?;
         assert_eq!(result, 0);
 
         Ok(())
     }
 
+    #[test]
+    fn test_day_05_assembled_examples() -> Result<()> {
+        // same checks as small examples, but without private example file
+        let equal_8 = assemble("in [x]\neq [x], #8, [x]\nout [x]\nhlt\nx: data 0")?;
+        let less_8 = assemble("in [x]\nlt [x], #8, [x]\nout [x]\nhlt\nx: data 0")?;
+        let is_not_zero = assemble(
+            "
+                    in [x]
+                    jf [x], #zero
+                    out #1
+                    hlt
+            zero:   out #0
+                    hlt
+            x:      data 0
+            ",
+        )?;
+        for (assembly, input, expected) in [
+            (&equal_8, 8, 1),
+            (&equal_8, 7, 0),
+            (&less_8, 7, 1),
+            (&less_8, 8, 0),
+            (&is_not_zero, 7, 1),
+            (&is_not_zero, 0, 0),
+        ] {
+            let mut example = IntCodeComputer::from(assembly.to_string().as_str());
+            assert_eq!(example.run_until_finished(&[input])?, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_05_diagnostic_errors() -> Result<()> {
+        let failed_test = assemble("out #3\nout #42\nhlt")?;
+        let mut example = IntCodeComputer::from(failed_test.to_string().as_str());
+        assert!(matches!(
+            example.run_until_finished(&[]),
+            Err(IntcodeError::DiagnosticFailure { value: 3, .. })
+        ));
+        let no_output = assemble("in [x]\nhlt\nx: data 0")?;
+        let mut example = IntCodeComputer::from(no_output.to_string().as_str());
+        assert!(matches!(
+            example.run_until_finished(&[1]),
+            Err(IntcodeError::NoOutput { .. })
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_day_05_large_example() -> Result<()> {
         // input is below, equal or greater 8?
-        let int_code = include_str!("../../../../aoc_input/aoc-2019/day_05_large_example.txt");
+        let int_code = read_file(2019, "day_05_large_example.txt")?;
         let mut example = IntCodeComputer::from(int_code);
-        let result = example.run_until_finished(&[7]);
+        let result = example.run_until_finished(&[7])?;
         assert_eq!(result, 999);
         let mut example = IntCodeComputer::from(int_code);
-        let result = example.run_until_finished(&[8]);
+        let result = example.run_until_finished(&[8])?;
         assert_eq!(result, 1000);
         let mut example = IntCodeComputer::from(int_code);
-        let result = example.run_until_finished(&[9]);
+        let result = example.run_until_finished(&[9])?;
         assert_eq!(result, 1001);
 
         Ok(())
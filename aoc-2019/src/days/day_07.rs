@@ -1,10 +1,12 @@
 //!day_07.rs
 
-use super::day_05::IntCodeComputer;
-use anyhow::Result;
+use crate::intcode::IntCodeComputer;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_algo_collection::RangeCombinations;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     int_code_computer: IntCodeComputer,
 }
 
@@ -17,7 +19,7 @@ impl From<&str> for ChallengeInput {
 }
 
 impl ChallengeInput {
-    fn solution_part_1(&self) -> i64 {
+    fn solution_part_1(&self) -> Result<i64> {
         self.calc_amplifier_output()
     }
     fn solution_part_2(&self) -> i64 {
@@ -26,20 +28,20 @@ impl ChallengeInput {
             Err(err) => panic!("Some error '{err}' occurred"),
         }
     }
-    fn calc_amplifier_output(&self) -> i64 {
+    fn calc_amplifier_output(&self) -> Result<i64> {
         let mut max = 0;
         for phases in RangeCombinations::new(0, 4) {
             let mut amplifiers = self.int_code_computer.clone_n_times(phases.len());
             let mut input = 0;
             for (amp, phase) in amplifiers.iter_mut().zip(phases.iter()) {
-                let out = amp.run_until_finished(&[*phase, input]);
+                let out = amp.run_until_finished(&[*phase, input])?;
                 input = out;
             }
             max = max.max(input);
         }
-        max
+        Ok(max)
     }
-    fn calc_amplifier_output_with_feed_back(&self) -> Result<i64, String> {
+    fn calc_amplifier_output_with_feed_back(&self) -> Result<i64> {
         let mut max = 0;
         for phases in RangeCombinations::new(5, 9) {
             let mut amplifiers = self.int_code_computer.clone_n_times(phases.len());
@@ -74,42 +76,42 @@ impl ChallengeInput {
                 }
                 first = false;
             }
-            max = max.max(last.ok_or(String::from("did not receive any output"))?);
+            max = max.max(last.ok_or(anyhow!("did not receive any output"))?);
         }
         Ok(max)
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_07.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_07 part 1: {result_part1}");
-    assert_eq!(result_part1, 45_730);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_07 part 2: {result_part2}");
-    assert_eq!(result_part2, 5_406_484);
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.solution_part_1()
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_07() -> Result<()> {
-        let multi_input = include_str!("../../../../aoc_input/aoc-2019/day_07_example.txt");
+        let multi_input = read_file(2019, "day_07_example.txt")?;
         let solutions = [43_210, 54_321, 65_210, 139629729, 18216];
 
         for (index, (input, solution)) in multi_input.split("\n\n").zip(solutions).enumerate() {
             let example = ChallengeInput::from(input);
 
             if index < 3 {
-                let result_part1 = example.solution_part_1();
+                let result_part1 = example.solution_part_1()?;
                 println!("result day_07 part 1: {result_part1}");
                 assert_eq!(result_part1, solution);
             } else {
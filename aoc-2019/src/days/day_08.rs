@@ -1,9 +1,11 @@
 //!day_08.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
-struct ChallengeInput<const X: usize, const Y: usize> {
+pub struct ChallengeInput<const X: usize, const Y: usize> {
     image: Vec<MyMap2D<u64, X, Y>>,
 }
 
@@ -61,31 +63,29 @@ impl<const X: usize, const Y: usize> ChallengeInput<X, Y> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_08.txt");
-    let challenge = ChallengeInput::<25, 6>::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_08 part 1: {result_part1}");
-    assert_eq!(result_part1, 2_016);
-
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_08 part 2:\n{result_part2}");
+impl<const X: usize, const Y: usize> Solution for ChallengeInput<X, Y> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let solution = include_str!("../../../../aoc_input/aoc-2019/day_08_expected_part_2.txt");
-    assert_eq!(result_part2, solution);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_08() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_08_example.txt");
+        let input = read_file(2019, "day_08_example.txt")?;
         let (first, second) = input.split_once("\n\n").unwrap();
 
         let example = ChallengeInput::<3, 2>::from(first);
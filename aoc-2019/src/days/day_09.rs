@@ -1,9 +1,12 @@
 //!day_09.rs
 
-use super::day_05::IntCodeComputer;
+use crate::bench::UseSparseMemory;
+use crate::intcode::IntCodeComputer;
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     code: IntCodeComputer,
 }
 
@@ -16,43 +19,48 @@ impl From<&str> for ChallengeInput {
 }
 
 impl ChallengeInput {
-    fn solution_part_1(&self) -> i64 {
+    fn solution_part_1(&self) -> Result<i64> {
         let mut test_run = self.code.clone();
-        test_run.run_until_finished(&[1])
+        Ok(test_run.run_until_finished(&[1])?)
     }
-    fn solution_part_2(&self) -> i64 {
+    fn solution_part_2(&self) -> Result<i64> {
         let mut test_run = self.code.clone();
-        test_run.run_until_finished(&[2])
+        Ok(test_run.run_until_finished(&[2])?)
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_09.txt");
-    let challenge = ChallengeInput::from(input);
+impl UseSparseMemory for ChallengeInput {
+    fn use_sparse_memory(&mut self) {
+        self.code.use_sparse_memory();
+    }
+}
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_09 part 1: {result_part1}");
-    assert_eq!(result_part1, 3_638_931_938);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_09 part 2: {result_part2}");
-    assert_eq!(result_part2, 86_025);
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.solution_part_1()
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.solution_part_2()
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     // day 09 does not provide examples for the challenge. Instead it does provide new
     // int code snippets to test
 
     #[test]
     fn test_day_05_small_examples() {
-        let small_examples =
-            include_str!("../../../../aoc_input/aoc-2019/day_09_small_examples.txt");
+        let small_examples = read_file(2019, "day_09_small_examples.txt").unwrap();
         let int_code_computers: Vec<IntCodeComputer> =
             small_examples.lines().map(IntCodeComputer::from).collect();
         let mut int_code_computer = int_code_computers[0].clone();
@@ -1,14 +1,17 @@
 //!day_10.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::{
     my_line::LineSegment,
     my_point::{Cylindrical, Point},
 };
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     asteroids: HashSet<Point>,
+    result_part2: Option<i64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -21,7 +24,10 @@ impl From<&str> for ChallengeInput {
                 }
             }
         }
-        ChallengeInput { asteroids }
+        ChallengeInput {
+            asteroids,
+            result_part2: None,
+        }
     }
 }
 
@@ -86,28 +92,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_10.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_10 part 1: {result_part1}");
-    assert_eq!(result_part1, 253);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_10 part 2: {result_part2}");
-    assert_eq!(result_part2, 815);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_10() -> Result<()> {
-        let multi_input = include_str!("../../../../aoc_input/aoc-2019/day_10_example.txt");
+        let multi_input = read_file(2019, "day_10_example.txt")?;
         let solutions = [8, 33, 35, 41, 210];
 
         for (input, solution) in multi_input.split("\n\n").zip(solutions) {
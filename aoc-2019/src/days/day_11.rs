@@ -1,12 +1,14 @@
 //!day_11.rs
 
-use super::day_05::IntCodeComputer;
-use anyhow::Result;
+use crate::intcode::IntCodeComputer;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::HashMap;
+use std::fmt::Display;
 use std::fmt::Write;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     code: IntCodeComputer,
 }
 
@@ -54,14 +56,14 @@ impl ChallengeInput {
         }
         out
     }
-    fn execute_paint_job(&self, painted: &mut HashMap<Point, i64>) -> Result<(), String> {
+    fn execute_paint_job(&self, painted: &mut HashMap<Point, i64>) -> Result<()> {
         let mut paint_bot = self.code.clone();
         let mut current = Point::new(0, 0);
         let mut current_direction = '^';
         while let Some(color) = paint_bot.run_int_code(&[*painted.get(&current).unwrap_or(&0)])? {
             let direction = paint_bot
                 .run_int_code(&[])?
-                .ok_or(String::from("did not output direction"))?;
+                .ok_or(anyhow!("did not output direction"))?;
             painted
                 .entry(current)
                 .and_modify(|v| *v = color)
@@ -90,31 +92,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_11.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_11 part 1: {result_part1}");
-    assert_eq!(result_part1, 2_339);
-
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_11 part 2:\n{result_part2}");
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let solution = include_str!("../../../../aoc_input/aoc-2019/day_11_expected_part_2.txt");
-    assert_eq!(result_part2, solution);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     // no usable example provided in part 1
     /*use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_11() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_11_example.txt");
+        let input = read_file(2019, "day_11_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,9 +1,11 @@
 //!day_12.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_algo_collection::gcd;
 use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 // changes in one axis are independent from changes in other axis.
 // therefore we can handle each axis separately, which is especially
@@ -45,7 +47,7 @@ impl MoonAxis {
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     x: MoonAxis,
     y: MoonAxis,
     z: MoonAxis,
@@ -130,31 +132,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_12.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1(1_000);
-    println!("result day_12 part 1: {result_part1}");
-    assert_eq!(result_part1, 12_351);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    // reset challenge
-    let mut challenge = ChallengeInput::from(input);
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_12 part 2: {result_part2}");
-    assert_eq!(result_part2, 380_635_029_877_596);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.clone().solution_part_1(1_000))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_12() -> Result<()> {
-        let multi_input = include_str!("../../../../aoc_input/aoc-2019/day_12_example.txt");
+        let multi_input = read_file(2019, "day_12_example.txt")?;
         let solutions = [(179, 2_772), (1_940, 4_686_774_924)];
         for (input, (solution_1, solution_2)) in multi_input.split("\n\n").zip(solutions) {
             let mut example = ChallengeInput::from(input);
@@ -1,147 +1,180 @@
 //!day_13.rs
 
-use super::day_05::IntCodeComputer;
-use anyhow::Result;
+use crate::bench::UseSparseMemory;
+use crate::intcode::{IntCodeComputer, RunState};
+use anyhow::{Result, bail};
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
-use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
 
-struct ChallengeInput {
+/// Instructions executed per call of run_cooperative().
+const BUDGET: usize = 100_000;
+
+// tile ids
+pub const EMPTY: i64 = 0;
+pub const WALL: i64 = 1;
+pub const BLOCK: i64 = 2;
+pub const PADDLE: i64 = 3;
+pub const BALL: i64 = 4;
+
+/// Arcade cabinet, which runs game frame by frame. A frame ends, when game waits for joystick.
+#[derive(Clone)]
+pub struct Arcade {
     code: IntCodeComputer,
-    score: i64,
-    arcade: HashMap<Point, i64>,
-    ball: Point,
-    paddle: Point,
+    pub score: i64,
+    /// walls and blocks; empty tiles are removed
+    pub tiles: HashMap<Point, i64>,
+    pub ball: Point,
+    pub paddle: Point,
 }
 
-impl From<&str> for ChallengeInput {
-    fn from(value: &str) -> Self {
-        ChallengeInput {
-            code: IntCodeComputer::from(value),
+impl From<IntCodeComputer> for Arcade {
+    fn from(code: IntCodeComputer) -> Self {
+        Arcade {
+            code,
             score: 0,
-            arcade: HashMap::new(),
+            tiles: HashMap::new(),
             ball: (0, 0).into(),
             paddle: (0, 0).into(),
         }
     }
 }
 
-impl ChallengeInput {
-    fn solution_part_1(&mut self) -> usize {
-        if let Err(err) = self.generate_arcade() {
-            panic!("{err}");
-        }
-        self.arcade.values().filter(|c| **c == 2).count()
+impl Arcade {
+    /// Arcade with quarters inserted, which allows to play the game.
+    pub fn free_play(mut code: IntCodeComputer) -> Self {
+        code.set_address(0, 2);
+        Arcade::from(code)
     }
-    fn solution_part_2(&mut self) -> i64 {
-        if let Err(err) = self.run_game() {
-            panic!("{err}");
+    /// Runs one frame. Joystick is -1 (left), 0 (neutral) or 1 (right); None before first
+    /// frame. Returns false, if game halted.
+    pub fn frame(&mut self, joystick: Option<i64>) -> Result<bool> {
+        let mut input: VecDeque<i64> = joystick.into_iter().collect();
+        let mut triple: Vec<i64> = Vec::with_capacity(3);
+        loop {
+            match self.code.run_cooperative(&mut input, BUDGET)? {
+                RunState::Output(value) => {
+                    triple.push(value);
+                    if let [x, y, id] = triple[..] {
+                        self.draw(x, y, id);
+                        triple.clear();
+                    }
+                }
+                RunState::BlockedOnInput => return Ok(true),
+                RunState::Halted => return Ok(false),
+                RunState::Yielded => (),
+            }
         }
-        self.score
     }
-    fn generate_arcade(&mut self) -> Result<(), String> {
-        let mut arcade_printer = self.code.clone();
-        while let Some(x) = arcade_printer.run_int_code(&[])? {
-            let y = arcade_printer.run_int_code(&[])?.expect("expected y");
-            let id = arcade_printer.run_int_code(&[])?.expect("expected id");
-            let point = (x, y).into();
-            if id == 3 {
-                self.paddle = point;
-            } else if id == 4 {
-                self.ball = point;
-            } else {
-                self.arcade.insert(point, id);
+    /// Joystick, which keeps paddle below ball.
+    pub fn autopilot(&self) -> i64 {
+        (self.ball.x - self.paddle.x).signum()
+    }
+    pub fn blocks(&self) -> usize {
+        self.tiles.values().filter(|t| **t == BLOCK).count()
+    }
+    fn draw(&mut self, x: i64, y: i64, id: i64) {
+        if x == -1 && y == 0 {
+            self.score = id;
+            return;
+        }
+        let point = (x, y).into();
+        match id {
+            PADDLE => self.paddle = point,
+            BALL => self.ball = point,
+            EMPTY => {
+                self.tiles.remove(&point);
+            }
+            _ => {
+                self.tiles.insert(point, id);
             }
         }
-        Ok(())
     }
-    fn run_game(&mut self) -> Result<(), String> {
-        self.code.set_address(0, 2);
-        let mut first_score = false;
-        let mut last_score = false;
-        let mut input = 0;
-        while let Some(x) = self.code.run_int_code(&[input])? {
-            let y = self.code.run_int_code(&[input])?.expect("expected y");
-            let id = self.code.run_int_code(&[input])?.expect("expected id");
-
-            if x == -1 && y == 0 {
-                self.score = id;
-                first_score = true;
-                if last_score {
-                    return Ok(());
-                }
-            } else {
-                let point = (x, y).into();
-                if id == 3 {
-                    self.paddle = point;
-                } else if id == 4 {
-                    self.ball = point;
-                } else {
-                    self.arcade
-                        .entry(point)
-                        .and_modify(|v| *v = id)
-                        .or_insert(id);
-                }
-                if first_score && id == 4 {
-                    // arcade game is now running; we only calculate new input, if we received a new ball position
-                    match self.paddle.x.cmp(&self.ball.x) {
-                        Ordering::Greater => {
-                            input = -1;
-                            self.paddle.x -= 1;
-                        }
-                        Ordering::Equal => {
-                            input = 0;
-                        }
-                        Ordering::Less => {
-                            input = 1;
-                            self.paddle.x += 1;
-                        }
-                    }
-                    if !self.arcade.values().any(|v| *v == 2) {
-                        // all blocks are destroyed, wait for one more score
-                        last_score = true;
-                    }
-                }
-            }
+}
+
+pub struct ChallengeInput {
+    code: IntCodeComputer,
+}
+
+impl From<&str> for ChallengeInput {
+    fn from(value: &str) -> Self {
+        ChallengeInput {
+            code: IntCodeComputer::from(value),
         }
-        Ok(())
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_13.txt");
-    let mut challenge = ChallengeInput::from(input);
+impl ChallengeInput {
+    fn solution_part_1(&self) -> Result<usize> {
+        let mut arcade = Arcade::from(self.code.clone());
+        // without quarters game draws screen and halts
+        if arcade.frame(None)? {
+            bail!("arcade waits for joystick without quarters");
+        }
+        Ok(arcade.blocks())
+    }
+    fn solution_part_2(&self) -> Result<i64> {
+        let mut arcade = Arcade::free_play(self.code.clone());
+        let mut joystick = None;
+        // game halts after last block is destroyed
+        while arcade.frame(joystick)? {
+            joystick = Some(arcade.autopilot());
+        }
+        if arcade.blocks() > 0 {
+            bail!("lost game with {} blocks left", arcade.blocks());
+        }
+        Ok(arcade.score)
+    }
+}
+
+impl UseSparseMemory for ChallengeInput {
+    fn use_sparse_memory(&mut self) {
+        self.code.use_sparse_memory();
+    }
+}
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_13 part 1: {result_part1}");
-    assert_eq!(result_part1, 207);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_13 part 2: {result_part2}");
-    assert_eq!(result_part2, 10_247);
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.solution_part_1()
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.solution_part_2()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
 
-    // no example to use for this challenge
-    /*use super::*;
+    // no example to use for this challenge, therefore a tiny game: draws wall, block, paddle
+    // and ball, waits for joystick, moves ball, destroys block and halts
+    const GAME: &str = "1,0,0,200,104,0,104,0,104,1,104,1,104,0,104,2,104,1,104,2,104,3,\
+        104,0,104,1,104,4,104,-1,104,0,104,0,3,100,104,0,104,1,104,0,104,1,104,1,104,4,\
+        104,1,104,0,104,0,104,-1,104,0,104,50,99";
 
     #[test]
-    fn test_example_day_13() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_13_example.txt");
-        let example = ChallengeInput::from(input);
-
-        let result_part1 = example.solution_part_1();
-        println!("result day_13 part 1: {result_part1}");
-        //assert_eq!(result_part1, XXX);
-
-        let result_part2 = example.solution_part_2();
-        println!("result day_13 part 2: {result_part2}");
-        //assert_eq!(result_part2, YYY);
+    fn test_arcade() -> Result<()> {
+        let mut arcade = Arcade::free_play(IntCodeComputer::from(GAME));
+        assert!(arcade.frame(None)?);
+        assert_eq!(arcade.blocks(), 1);
+        assert_eq!(arcade.tiles[&(0, 0).into()], WALL);
+        assert_eq!(arcade.paddle, (1, 2).into());
+        assert_eq!(arcade.ball, (0, 1).into());
+        assert_eq!(arcade.autopilot(), -1);
+        assert!(!arcade.frame(Some(arcade.autopilot()))?);
+        assert_eq!(arcade.blocks(), 0);
+        assert_eq!(arcade.score, 50);
 
+        let example = ChallengeInput::from(GAME);
+        // without quarters game waits for joystick, too
+        assert!(example.solution_part_1().is_err());
+        assert_eq!(example.solution_part_2()?, 50);
         Ok(())
-    }*/
+    }
 }
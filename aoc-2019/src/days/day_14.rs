@@ -1,12 +1,14 @@
 //!day_14.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use petgraph::{
     Direction,
     graph::{DiGraph, NodeIndex},
     visit::EdgeRef,
 };
 use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct Chemical {
@@ -51,7 +53,7 @@ impl Chemical {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     chemicals: DiGraph<Chemical, i64>,
     ore: NodeIndex,
     fuel: NodeIndex,
@@ -166,29 +168,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_14.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_14 part 1: {result_part1}");
-    assert_eq!(result_part1, 202_617);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_14 part 2: {result_part2}");
-    assert_eq!(result_part2, 7_863_863);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_14() -> Result<()> {
-        let multi_input = include_str!("../../../../aoc_input/aoc-2019/day_14_example.txt");
+        let multi_input = read_file(2019, "day_14_example.txt")?;
         let solutions = [
             (31, 0),
             (165, 0),
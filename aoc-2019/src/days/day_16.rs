@@ -1,6 +1,8 @@
 //!day_16.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 struct RepeatingPattern {
     repeat: usize,
@@ -36,7 +38,7 @@ impl Iterator for RepeatingPattern {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     numbers: Vec<i64>,
 }
 
@@ -116,26 +118,25 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_16.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_16 part 1: {result_part1}");
-    assert_eq!(result_part1, 70_856_418);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let mut challenge = ChallengeInput::from(input);
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_16 part 2: {result_part2}");
-    assert_eq!(result_part2, 87_766_336);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_repeating_pattern_iter() {
@@ -159,7 +160,7 @@ mod tests {
 
     #[test]
     fn test_example_day_16() -> Result<()> {
-        let multi_input = include_str!("../../../../aoc_input/aoc-2019/day_16_example.txt");
+        let multi_input = read_file(2019, "day_16_example.txt")?;
         let solutions = [
             24_176_176, 73_745_418, 52_432_133, 84_462_026, 78_725_270, 53_553_731,
         ];
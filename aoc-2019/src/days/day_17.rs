@@ -1,11 +1,13 @@
 //!day_17.rs
 
-use super::day_05::IntCodeComputer;
-use anyhow::Result;
+use crate::intcode::{AsciiIntcode, AsciiOutput, IntCodeComputer};
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::{Point, Turns90};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     code: IntCodeComputer,
     map: HashMap<Point, char>,
     bot: Point,
@@ -30,56 +32,41 @@ impl ChallengeInput {
         }
         self.calc_alignment_parameters()
     }
-    fn solution_part_2(&mut self) -> i64 {
+    fn solution_part_2(&mut self) -> Result<i64> {
         // enable robot
         self.code.set_address(0, 2);
+        let mut robot = AsciiIntcode::from(self.code.clone());
         // get movement functions
-        let mut int_code_input_string = self.get_movement_routine_and_functions();
+        let mut int_code_input = self.get_movement_routine_and_functions();
         // we do not want enable video feed; set to y if you want continuous printing of map
-        int_code_input_string.push("n\n".into());
-        // generate input
-        let mut inputs: Vec<Vec<i64>> = int_code_input_string
-            .iter()
-            .map(|i| i.chars().map(|c| (c as u8) as i64).collect())
-            .collect();
-        // we start with no input
-        inputs.insert(0, vec![]);
-        let mut index = 0;
-        while let Some(out) = self
-            .code
-            .run_int_code(inputs.get(index).unwrap_or(&vec![]))
-            .unwrap()
-        {
-            if out > 255 {
+        int_code_input.push("n".into());
+        for line in int_code_input.iter() {
+            // wait for prompt (uncomment for print of map and more debug information)
+            let _prompt = robot.read_until_prompt()?;
+            //println!("{_prompt:?}");
+            robot.send_line(line)?;
+        }
+        loop {
+            match robot.read_line()? {
                 // no ascii output -> final result!
-                return out;
-            } else {
-                let ch = (out as u8) as char;
-                // uncomment for print of map and more debug information
-                //print!("{ch}");
-                if ch == ':' || ch == '?' {
-                    // received prompt for input
-                    index += 1;
-                }
+                AsciiOutput::Value(dust) => return Ok(dust),
+                AsciiOutput::Halted(text) => return Err(anyhow!("robot halted:\n{text}")),
+                _ => (),
             }
         }
-        0
     }
-    fn parse_map(&mut self) -> Result<(), String> {
-        let mut parser = self.code.clone();
-        let mut current = Point::new(0, 0);
-        while let Some(ascii) = parser.run_int_code(&[])? {
-            let ch = (ascii as u8) as char;
+    fn parse_map(&mut self) -> Result<()> {
+        let mut parser = AsciiIntcode::from(self.code.clone());
+        // positive y points down!
+        let mut y = 0;
+        while let AsciiOutput::Line(line) = parser.read_line()? {
             // uncomment for print of map
-            //print!("{ch}");
-            if ch == '\n' {
-                current.x = 0;
-                current.y += 1;
-            } else {
+            //println!("{line}");
+            for (x, ch) in line.chars().enumerate() {
+                let current = Point::new(x as i64, y);
                 self.map.insert(current, ch);
                 if ['^', '<', '>', 'v'].contains(&ch) {
                     self.bot = current;
-                    // positive y points down!
                     self.direction = match ch {
                         '^' => (0, -1).into(),
                         'v' => (0, 1).into(),
@@ -88,8 +75,8 @@ impl ChallengeInput {
                         _ => unreachable!(),
                     }
                 }
-                current.x += 1;
             }
+            y += 1;
         }
         Ok(())
     }
@@ -219,27 +206,26 @@ impl ChallengeInput {
         }
         // collect all int code input sequences in separate Strings
         let mut int_code_input: Vec<String> = Vec::new();
-        int_code_input.push(movement_routine.join(",") + "\n");
+        int_code_input.push(movement_routine.join(","));
         for movement_function in movement_functions {
-            int_code_input.push(movement_function.join(",") + "\n");
+            int_code_input.push(movement_function.join(","));
         }
         int_code_input
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_17.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_17 part 1: {result_part1}");
-    assert_eq!(result_part1, 2080);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_17 part 2: {result_part2}");
-    assert_eq!(result_part2, 742_673);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.solution_part_2()
+    }
 }
 
 #[cfg(test)]
@@ -249,7 +235,7 @@ mod tests {
 
     #[test]
     fn test_example_day_17() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_17_example.txt");
+        let input = read_file(2019, "day_17_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,6 +1,7 @@
 //!day_18.rs
 
 use anyhow::Result;
+use aoc_common::{LONG_RUNTIME, Solution};
 use my_lib::{
     my_compass::Compass,
     my_map_point::MapPoint,
@@ -9,6 +10,8 @@ use my_lib::{
 use petgraph::{Direction, graphmap::UnGraphMap};
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::fmt::Display;
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct DistanceWalker {
@@ -125,7 +128,7 @@ impl DistanceWalker {
     }
 }
 
-struct ChallengeInput<const X: usize, const Y: usize> {
+pub struct ChallengeInput<const X: usize, const Y: usize> {
     map: MyMap2D<char, X, Y>,
     at_pos: MapPoint<X, Y>,
     graph: UnGraphMap<char, usize>,
@@ -219,29 +222,31 @@ impl<const X: usize, const Y: usize> ChallengeInput<X, Y> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_18.txt");
-    let mut challenge = ChallengeInput::<81, 81>::from(input);
+impl<const X: usize, const Y: usize> Solution for ChallengeInput<X, Y> {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
 
-    let result_part1 = challenge.solution_part_1(true);
-    println!("result day_18 part 1: {result_part1}");
-    assert_eq!(result_part1, 4_620);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(true);
-    println!("result day_18 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_564);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1(true))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2(true))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_1_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_18_example_1.txt");
+        let input = read_file(2019, "day_18_example_1.txt")?;
         let mut example = ChallengeInput::<24, 5>::from(input);
 
         let result_part1 = example.solution_part_1(false);
@@ -253,7 +258,7 @@ mod tests {
 
     #[test]
     fn test_example_2_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_18_example_2.txt");
+        let input = read_file(2019, "day_18_example_2.txt")?;
         let mut example = ChallengeInput::<24, 5>::from(input);
 
         let result_part1 = example.solution_part_1(false);
@@ -266,7 +271,7 @@ mod tests {
     /* this example takes very long to execute. Therefore we exclude it from testing
     #[test]
     fn test_example_3_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_18_example_3.txt");
+        let input = read_file(2019, "day_18_example_3.txt")?;
         let mut example = ChallengeInput::<1724, 9>::from(input);
 
         let result_part1 = example.solution_part_1(false);
@@ -278,7 +283,7 @@ mod tests {
 
     #[test]
     fn test_example_4_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_18_example_4.txt");
+        let input = read_file(2019, "day_18_example_4.txt")?;
         let mut example = ChallengeInput::<24, 6>::from(input);
 
         let result_part1 = example.solution_part_1(false);
@@ -290,7 +295,7 @@ mod tests {
 
     #[test]
     fn test_example_5_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_18_example_5.txt");
+        let input = read_file(2019, "day_18_example_5.txt")?;
         let mut example = ChallengeInput::<7, 7>::from(input);
 
         let result_part2 = example.solution_part_2(true);
@@ -302,7 +307,7 @@ mod tests {
 
     #[test]
     fn test_example_6_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_18_example_6.txt");
+        let input = read_file(2019, "day_18_example_6.txt")?;
         let mut example = ChallengeInput::<15, 7>::from(input);
 
         let result_part2 = example.solution_part_2(false);
@@ -314,7 +319,7 @@ mod tests {
 
     #[test]
     fn test_example_7_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_18_example_7.txt");
+        let input = read_file(2019, "day_18_example_7.txt")?;
         let mut example = ChallengeInput::<13, 7>::from(input);
 
         let result_part2 = example.solution_part_2(false);
@@ -328,7 +333,7 @@ mod tests {
     We need this filter for the challenge to execute in a reasonable time, therefore we disable this example.
     #[test]
     fn test_example_8_day_18() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_18_example_8.txt");
+        let input = read_file(2019, "day_18_example_8.txt")?;
         let mut example = ChallengeInput::<13, 9>::from(input);
 
         let result_part2 = example.solution_part_2(false);
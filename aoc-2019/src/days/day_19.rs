@@ -1,18 +1,22 @@
 //!day_19.rs
 
-use super::day_05::IntCodeComputer;
-use anyhow::Result;
+use crate::intcode::IntCodeComputer;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::{my_line::Line, my_point::Point};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     code: IntCodeComputer,
+    result_part2: Option<i64>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
         ChallengeInput {
             code: IntCodeComputer::from(value),
+            result_part2: None,
         }
     }
 }
@@ -89,28 +93,29 @@ impl ChallengeInput {
     }
     fn pos_is_pulled(&self, pos: Point) -> Result<bool> {
         let mut parse_beam = self.code.clone();
-        if let Some(is_pulled) = parse_beam
-            .run_int_code(&[pos.x, pos.y])
-            .map_err(|err| anyhow::anyhow!("{err}"))?
-        {
+        if let Some(is_pulled) = parse_beam.run_int_code(&[pos.x, pos.y])? {
             return Ok(is_pulled == 1);
         }
         Ok(false)
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_19.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2()?;
-    println!("result day_19 part 1: {result_part1}");
-    assert_eq!(result_part1, 116);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_19 part 2: {result_part2}");
-    assert_eq!(result_part2, 10_311_666);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2()?;
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
@@ -120,7 +125,7 @@ mod tests {
 
     #[test]
     fn test_example_day_19() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_19_example.txt");
+        let input = read_file(2019, "day_19_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
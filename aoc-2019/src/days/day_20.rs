@@ -1,6 +1,7 @@
 //!day_20.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use petgraph::{
     algo::dijkstra,
@@ -8,8 +9,9 @@ use petgraph::{
 };
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
 
-struct ChallengeInput<const X: usize, const Y: usize> {
+pub struct ChallengeInput<const X: usize, const Y: usize> {
     map: MyMap2D<char, X, Y>,
     graph: UnGraph<MapPoint<X, Y>, usize>,
     node_map: HashMap<MapPoint<X, Y>, NodeIndex>,
@@ -240,29 +242,29 @@ impl Ord for LevelWalker {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_20.txt");
-    let mut challenge = ChallengeInput::<107, 109>::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_20 part 1: {result_part1}");
-    //assert_eq!(result_part1, XXX);
+impl<const X: usize, const Y: usize> Solution for ChallengeInput<X, Y> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_20 part 2: {result_part2}");
-    //assert_eq!(result_part2, YYY);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_1_day_20() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_20_example_1.txt");
+        let input = read_file(2019, "day_20_example_1.txt")?;
         let mut example = ChallengeInput::<21, 19>::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -278,7 +280,7 @@ mod tests {
 
     #[test]
     fn test_example_2_day_20() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_20_example_2.txt");
+        let input = read_file(2019, "day_20_example_2.txt")?;
         let mut example = ChallengeInput::<35, 37>::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -290,7 +292,7 @@ mod tests {
 
     #[test]
     fn test_example_3_day_20() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_20_example_3.txt");
+        let input = read_file(2019, "day_20_example_3.txt")?;
         let mut example = ChallengeInput::<45, 37>::from(input);
 
         let result_part2 = example.solution_part_2();
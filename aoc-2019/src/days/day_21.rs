@@ -1,9 +1,11 @@
 //!day_21.rs
 
-use super::day_05::IntCodeComputer;
-use anyhow::Result;
+use crate::intcode::{AsciiIntcode, AsciiOutput, IntCodeComputer};
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     code: IntCodeComputer,
 }
 
@@ -30,39 +32,36 @@ impl ChallengeInput {
         self.jump_droid(spring_script)
     }
     fn jump_droid(&self, spring_script: &str) -> Result<i64> {
-        let mut jump_droid = self.code.clone();
-        let mut input: Vec<i64> = Vec::new();
-        while let Some(ascii) = jump_droid
-            .run_int_code(&input)
-            .map_err(|err| anyhow::anyhow!("{err}"))?
-        {
-            if ascii > 255 {
-                return Ok(ascii);
-            }
-            let ch = (ascii as u8) as char;
-            if ch == ':' {
-                input = spring_script.chars().map(|c| c as i64).collect();
+        let mut jump_droid = AsciiIntcode::from(self.code.clone());
+        // wait for prompt "Input instructions:"
+        jump_droid.read_until_prompt()?;
+        for line in spring_script.lines() {
+            jump_droid.send_line(line)?;
+        }
+        loop {
+            match jump_droid.read_line()? {
+                AsciiOutput::Value(hull_damage) => return Ok(hull_damage),
+                AsciiOutput::Halted(text) => return Err(anyhow!("droid did not survive:\n{text}")),
+                // uncomment for debug
+                //AsciiOutput::Line(line) => println!("{line}"),
+                _ => (),
             }
-            // uncomment for debug
-            //print!("{ch}");
         }
-        Ok(0)
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_21.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1()?;
-    println!("result day_21 part 1: {result_part1}");
-    assert_eq!(result_part1, 19_348_840);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2()?;
-    println!("result day_21 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_141_857_182);
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.solution_part_1()
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.solution_part_2()
+    }
 }
 
 #[cfg(test)]
@@ -72,7 +71,7 @@ mod tests {
 
     #[test]
     fn test_example_day_21() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_21_example.txt");
+        let input = read_file(2019, "day_21_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,11 +1,13 @@
 //!day_22.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_algo_collection::egcd_i128;
 use num_bigint::BigUint;
 use num_traits::{FromPrimitive, ToPrimitive};
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::fmt::Display;
 use std::rc::Rc;
 
 enum Technique {
@@ -229,7 +231,7 @@ impl Iterator for IterDeck {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     techniques: Vec<Technique>,
 }
 
@@ -325,25 +327,25 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_22.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_22 part 1: {result_part1}");
-    assert_eq!(result_part1, 3_036);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_22 part 2: {result_part2}");
-    assert_eq!(result_part2, 70_618_172_909_245);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_deal_into_new_stack() {
@@ -416,7 +418,7 @@ mod tests {
 
     #[test]
     fn test_example_day_22() -> Result<()> {
-        let multi_input = include_str!("../../../../aoc_input/aoc-2019/day_22_example.txt");
+        let multi_input = read_file(2019, "day_22_example.txt")?;
         for example in multi_input.split("\n\n") {
             let (input, solution) = example.split_once("\nResult: ").unwrap();
             let solution: Vec<i128> = solution
@@ -437,7 +439,7 @@ mod tests {
 
     #[test]
     fn test_part_two_with_values_from_part_1() {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_22.txt");
+        let input = read_file(2019, "day_22.txt").unwrap();
         let challenge = ChallengeInput::from(input);
 
         // position of card 2019 after on shuffle of 10_007 cards
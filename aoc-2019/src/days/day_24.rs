@@ -1,10 +1,12 @@
 //!day_24.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     bugs: MyMap2D<char, 5, 5>,
 }
 
@@ -147,29 +149,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2019/day_24.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_24 part 1: {result_part1}");
-    assert_eq!(result_part1, 28_903_899);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(200);
-    println!("result day_24 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_896);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2(200))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_24() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2019/day_24_example.txt");
+        let input = read_file(2019, "day_24_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -0,0 +1,462 @@
+//!day_25.rs
+
+// Headless solver of the text adventure. The droid crawls through the ship and collects all
+// safe items, moves to the security checkpoint and tries combinations of items on the
+// pressure-sensitive floor, until it is heavy enough to pass. Each combination starts from a
+// save game at the checkpoint. The room crawler of the day 25 tui runs the same engine.
+
+use crate::intcode::{AsciiIntcode, AsciiOutput, HangDetection, IntCodeComputer, Snapshot};
+use anyhow::{Result, anyhow, bail};
+use aoc_common::Solution;
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub const SECURITY_CHECKPOINT: &str = "== Security Checkpoint ==";
+pub const PRESSURE_SENSITIVE_FLOOR: &str = "== Pressure-Sensitive Floor ==";
+/// Picking up one of these items results either in an unexpected halt of int computer, in an
+/// infinite loop (item "infinite loop") or in prevention of moving around (item "giant
+/// electromagnet").
+pub const BAD_ITEMS: [&str; 5] = [
+    "escape pod",
+    "infinite loop",
+    "giant electromagnet",
+    "molten lava",
+    "photons",
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct ShipRoom {
+    pub name: String,
+    pub description: String,
+    pub doors: Vec<String>,
+    pub items: Vec<String>,
+    pub message: String,
+}
+
+impl TryFrom<&str> for ShipRoom {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.trim().starts_with("==") {
+            let mut ship_room = ShipRoom::default();
+            for block in value.trim().split("\n\n") {
+                if block.starts_with("==") {
+                    let (name, description) = block.split_once('\n').unwrap();
+                    ship_room.name = name.into();
+                    ship_room.description = description.into();
+                } else if let Some(doors) = block.strip_prefix("Doors here lead:\n") {
+                    ship_room.doors = doors
+                        .lines()
+                        .filter_map(|d| d.strip_prefix("- "))
+                        .map(|d| d.to_string())
+                        .collect();
+                } else if let Some(items) = block.strip_prefix("Items here:\n") {
+                    ship_room.items = items
+                        .lines()
+                        .filter_map(|d| d.strip_prefix("- "))
+                        .map(|d| d.to_string())
+                        .collect();
+                } else if block != "Command?" {
+                    ship_room.message = block.to_string();
+                }
+            }
+            Ok(ship_room)
+        } else {
+            let no_ship_room_message = value.trim().strip_suffix("Command?").unwrap().trim();
+            Err(no_ship_room_message.to_string())
+        }
+    }
+}
+
+impl ShipRoom {
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.strip_prefix("==")?.strip_suffix("==")
+    }
+    /// Parses all rooms of output. Being ejected from the pressure-sensitive floor results in
+    /// two rooms.
+    pub fn parse_all(text: &str) -> Vec<ShipRoom> {
+        let mut starts: Vec<usize> = text.match_indices("== ").map(|(i, _)| i).collect();
+        // room names are enclosed in "== " and " =="
+        starts.retain(|i| *i == 0 || text[..*i].ends_with('\n'));
+        starts
+            .iter()
+            .enumerate()
+            .filter_map(|(index, start)| {
+                let end = starts.get(index + 1).copied().unwrap_or(text.len());
+                ShipRoom::try_from(&text[*start..end]).ok()
+            })
+            .collect()
+    }
+}
+
+pub fn reverse_door(door: &str) -> &'static str {
+    match door {
+        "north" => "south",
+        "south" => "north",
+        "east" => "west",
+        "west" => "east",
+        _ => unreachable!(),
+    }
+}
+
+/// Text adventure driven by the crawler engine.
+pub trait TextAdventure {
+    type Save;
+    /// Sends command (None at start of game) and returns output up to next prompt or halt.
+    fn send(&mut self, command: Option<&str>) -> Result<AsciiOutput>;
+    fn save(&self) -> Self::Save;
+    fn load(&mut self, save: &Self::Save);
+}
+
+impl TextAdventure for AsciiIntcode {
+    type Save = Snapshot;
+    fn send(&mut self, command: Option<&str>) -> Result<AsciiOutput> {
+        if let Some(command) = command {
+            self.send_line(command)?;
+        }
+        Ok(self.read_until_prompt()?)
+    }
+    fn save(&self) -> Snapshot {
+        self.snapshot()
+    }
+    fn load(&mut self, save: &Snapshot) {
+        self.restore(save);
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CrawlerState {
+    #[default]
+    Crawl,
+    MoveToSecurityCheckpoint,
+    OpenSecurityDoor,
+    Finished,
+}
+
+pub struct CrawlerEngine<G: TextAdventure> {
+    game: G,
+    pub state: CrawlerState,
+    pub room: ShipRoom,
+    pub ship_graph: DiGraph<String, String>,
+    seen_rooms: HashMap<String, NodeIndex>,
+    pub collected_items: Vec<String>,
+    // doors from start to current room
+    path: Vec<String>,
+    path_to_sc: Option<Vec<String>>,
+    security_door: Option<String>,
+}
+
+impl<G: TextAdventure> CrawlerEngine<G> {
+    pub fn new(game: G) -> Self {
+        CrawlerEngine {
+            game,
+            state: CrawlerState::default(),
+            room: ShipRoom::default(),
+            ship_graph: DiGraph::new(),
+            seen_rooms: HashMap::new(),
+            collected_items: Vec::new(),
+            path: Vec::new(),
+            path_to_sc: None,
+            security_door: None,
+        }
+    }
+    /// Runs all crawler states and returns password for main airlock.
+    pub fn run(&mut self) -> Result<String> {
+        let rooms = self.prompt(None)?;
+        let room = rooms
+            .last()
+            .cloned()
+            .ok_or(anyhow!("game does not start in a room"))?;
+        self.run_from(room)
+    }
+    /// Runs all crawler states in a running game, starting in its current room.
+    pub fn run_from(&mut self, room: ShipRoom) -> Result<String> {
+        self.room = room;
+        let start = self.ship_graph.add_node(self.room.name.clone());
+        self.seen_rooms.insert(self.room.name.clone(), start);
+        self.crawl(start, None)?;
+
+        self.state = CrawlerState::MoveToSecurityCheckpoint;
+        let path_to_sc = self
+            .path_to_sc
+            .take()
+            .ok_or(anyhow!("security checkpoint not found"))?;
+        for door in path_to_sc.iter() {
+            self.walk(door)?;
+        }
+
+        self.state = CrawlerState::OpenSecurityDoor;
+        let password = self.open_security_door()?;
+        self.state = CrawlerState::Finished;
+        Ok(password)
+    }
+    // depth first search through all rooms, which collects all items except bad items
+    fn crawl(&mut self, node: NodeIndex, entry_door: Option<&str>) -> Result<()> {
+        let room = self.room.clone();
+        for item in room.items.iter() {
+            if !BAD_ITEMS.contains(&item.as_str()) {
+                self.prompt(Some(&format!("take {item}")))?;
+                self.collected_items.push(item.clone());
+            }
+        }
+        if room.name == SECURITY_CHECKPOINT {
+            self.path_to_sc = Some(self.path.clone());
+        }
+        for door in room.doors.iter() {
+            if entry_door.is_some_and(|entry| reverse_door(entry) == door) {
+                continue;
+            }
+            let rooms = self.walk(door)?;
+            if rooms.len() > 1 && rooms[0].name == PRESSURE_SENSITIVE_FLOOR {
+                // door of security checkpoint leads to pressure-sensitive floor
+                self.security_door = Some(door.clone());
+                continue;
+            }
+            let next_room = self.room.name.clone();
+            if self.seen_rooms.contains_key(&next_room) {
+                // entered visited room through another door
+                self.walk(reverse_door(door))?;
+                continue;
+            }
+            let next_node = self.ship_graph.add_node(next_room.clone());
+            self.seen_rooms.insert(next_room, next_node);
+            self.ship_graph.add_edge(node, next_node, door.clone());
+            self.path.push(door.clone());
+            self.crawl(next_node, Some(door))?;
+            self.path.pop();
+            self.walk(reverse_door(door))?;
+        }
+        Ok(())
+    }
+    // tries all combinations of collected items
+    fn open_security_door(&mut self) -> Result<String> {
+        let security_door = self
+            .security_door
+            .clone()
+            .ok_or(anyhow!("security door not found"))?;
+        let items = self.collected_items.clone();
+        let checkpoint = self.game.save();
+        for combination in 1..1_u64 << items.len() {
+            self.game.load(&checkpoint);
+            for (index, item) in items.iter().enumerate() {
+                if combination & (1 << index) == 0 {
+                    self.prompt(Some(&format!("drop {item}")))?;
+                }
+            }
+            if let AsciiOutput::Halted(text) = self.game.send(Some(&security_door))? {
+                self.collected_items = items
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| combination & (1 << index) != 0)
+                    .map(|(_, item)| item.clone())
+                    .collect();
+                return password(&text);
+            }
+        }
+        bail!("no combination of items opens security door")
+    }
+    fn walk(&mut self, door: &str) -> Result<Vec<ShipRoom>> {
+        let rooms = self.prompt(Some(door))?;
+        self.room = rooms
+            .last()
+            .cloned()
+            .ok_or(anyhow!("door {door} of {} leads nowhere", self.room.name))?;
+        Ok(rooms)
+    }
+    fn prompt(&mut self, command: Option<&str>) -> Result<Vec<ShipRoom>> {
+        match self.game.send(command)? {
+            AsciiOutput::Prompt(text) => Ok(ShipRoom::parse_all(&text)),
+            AsciiOutput::Halted(text) => Err(anyhow!(
+                "game halted after command {command:?}:\n{}",
+                text.trim()
+            )),
+            output => Err(anyhow!("unexpected output {output:?}")),
+        }
+    }
+}
+
+fn password(text: &str) -> Result<String> {
+    let (_, code) = text
+        .split_once("typing ")
+        .ok_or(anyhow!("no password in:\n{}", text.trim()))?;
+    Ok(code.chars().take_while(|c| c.is_ascii_digit()).collect())
+}
+
+pub struct ChallengeInput {
+    code: IntCodeComputer,
+}
+
+impl From<&str> for ChallengeInput {
+    fn from(value: &str) -> Self {
+        let mut code = IntCodeComputer::from(value);
+        // droid answers every command within far less instructions
+        code.set_hang_detection(HangDetection {
+            budget: Some(10_000_000),
+            detect_cycles: false,
+        });
+        ChallengeInput { code }
+    }
+}
+
+impl ChallengeInput {
+    fn solution_part_1(&self) -> Result<String> {
+        let mut engine = CrawlerEngine::new(AsciiIntcode::from(self.code.clone()));
+        engine.run()
+    }
+}
+
+impl Solution for ChallengeInput {
+    const HAS_PART_2: bool = false;
+
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.solution_part_1()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    // small ship, which answers like the int code of day 25
+    #[derive(Clone)]
+    struct MockShip {
+        room: usize,
+        items: Vec<Vec<String>>,
+        inventory: Vec<String>,
+    }
+
+    // name, doors (door, target room), items
+    type MockRoom = (
+        &'static str,
+        &'static [(&'static str, usize)],
+        &'static [&'static str],
+    );
+
+    const ROOMS: [MockRoom; 6] = [
+        ("Hull Breach", &[("north", 1), ("east", 3)], &[]),
+        ("Kitchen", &[("south", 0), ("east", 2)], &["mug"]),
+        ("Hallway", &[("west", 1)], &["infinite loop", "coin"]),
+        (
+            "Security Checkpoint",
+            &[("north", 4), ("south", 5), ("west", 0)],
+            &[],
+        ),
+        ("Pressure-Sensitive Floor", &[("south", 3)], &[]),
+        ("Storage", &[("north", 3)], &["spool"]),
+    ];
+
+    impl MockShip {
+        fn new() -> Self {
+            MockShip {
+                room: 0,
+                items: ROOMS
+                    .iter()
+                    .map(|(_, _, items)| items.iter().map(|i| i.to_string()).collect())
+                    .collect(),
+                inventory: Vec::new(),
+            }
+        }
+        fn describe(&self, room: usize) -> String {
+            let (name, doors, _) = ROOMS[room];
+            let mut text =
+                format!("\n\n\n== {name} ==\nA room of the mock ship.\n\nDoors here lead:\n");
+            for (door, _) in doors.iter() {
+                text += &format!("- {door}\n");
+            }
+            if !self.items[room].is_empty() {
+                text += "\nItems here:\n";
+                for item in self.items[room].iter() {
+                    text += &format!("- {item}\n");
+                }
+            }
+            text
+        }
+        fn weight(&self) -> u32 {
+            self.inventory
+                .iter()
+                .map(|i| match i.as_str() {
+                    "mug" => 1,
+                    "coin" => 2,
+                    _ => 4,
+                })
+                .sum()
+        }
+    }
+
+    impl TextAdventure for MockShip {
+        type Save = MockShip;
+        fn send(&mut self, command: Option<&str>) -> Result<AsciiOutput> {
+            let Some(command) = command else {
+                return Ok(AsciiOutput::Prompt(
+                    self.describe(self.room) + "\nCommand?\n",
+                ));
+            };
+            let text = if let Some(item) = command.strip_prefix("take ") {
+                assert!(!BAD_ITEMS.contains(&item), "took bad item {item}");
+                let position = self.items[self.room]
+                    .iter()
+                    .position(|i| i == item)
+                    .unwrap();
+                self.inventory.push(self.items[self.room].remove(position));
+                format!("\nYou take the {item}.\n")
+            } else if let Some(item) = command.strip_prefix("drop ") {
+                let position = self.inventory.iter().position(|i| i == item).unwrap();
+                self.items[self.room].push(self.inventory.remove(position));
+                format!("\nYou drop the {item}.\n")
+            } else {
+                let (_, doors, _) = ROOMS[self.room];
+                let (_, target) = doors.iter().find(|(d, _)| *d == command).unwrap();
+                if *target == 4 {
+                    let floor = self.describe(4);
+                    if self.weight() == 6 {
+                        return Ok(AsciiOutput::Halted(format!(
+                            "{floor}\nA loud, robotic voice says \"Analysis complete! You may proceed.\" and you enter the cockpit.\n\"Oh, hello! You should be able to get in by typing 2424 on the keypad at the main airlock.\"\n"
+                        )));
+                    }
+                    format!(
+                        "{floor}\nA loud, robotic voice says \"Alert! Droids on this ship are lighter than the detected value!\" and you are ejected back to the checkpoint.\n{}",
+                        self.describe(3)
+                    )
+                } else {
+                    self.room = *target;
+                    self.describe(self.room)
+                }
+            };
+            Ok(AsciiOutput::Prompt(text + "\nCommand?\n"))
+        }
+        fn save(&self) -> MockShip {
+            self.clone()
+        }
+        fn load(&mut self, save: &MockShip) {
+            *self = save.clone();
+        }
+    }
+
+    #[test]
+    fn test_crawler_engine() -> Result<()> {
+        let mut engine = CrawlerEngine::new(MockShip::new());
+        assert_eq!(engine.run()?, "2424");
+        assert_eq!(engine.state, CrawlerState::Finished);
+        assert_eq!(engine.ship_graph.node_count(), 5);
+        assert_eq!(engine.collected_items, ["coin", "spool"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_rooms() {
+        let text = MockShip::new().send(Some("east")).unwrap();
+        let AsciiOutput::Prompt(text) = text else {
+            panic!("expected prompt")
+        };
+        let rooms = ShipRoom::parse_all(&text);
+        assert_eq!(rooms.len(), 1);
+        assert_eq!(rooms[0].get_name(), Some(" Security Checkpoint "));
+        assert_eq!(rooms[0].doors, ["north", "south", "west"]);
+    }
+}
@@ -0,0 +1,134 @@
+//!ascii.rs
+
+// Line based text interface for ASCII capable Intcode programs (days 17, 21 and 25).
+
+use super::{IntCodeComputer, IntcodeError, RunState, Snapshot};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+/// Instructions executed per call of run_cooperative().
+const BUDGET: usize = 100_000;
+
+/// Output of an ASCII program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsciiOutput {
+    /// Line of text without trailing newline.
+    Line(String),
+    /// Text received before program waits for input.
+    Prompt(String),
+    /// Output above 255, e.g. the final result of days 17 and 21.
+    Value(i64),
+    /// Program halted. Contains text received since last read.
+    Halted(String),
+}
+
+/// Input line contains a character, which is not ASCII.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonAsciiInput(pub char);
+
+impl Display for NonAsciiInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "non ASCII character '{}' in input line", self.0)
+    }
+}
+
+impl std::error::Error for NonAsciiInput {}
+
+#[derive(Debug, Clone)]
+pub struct AsciiIntcode {
+    computer: IntCodeComputer,
+    input: VecDeque<i64>,
+    text: String,
+}
+
+impl From<IntCodeComputer> for AsciiIntcode {
+    fn from(computer: IntCodeComputer) -> Self {
+        AsciiIntcode {
+            computer,
+            input: VecDeque::new(),
+            text: String::new(),
+        }
+    }
+}
+
+impl AsciiIntcode {
+    pub fn computer(&self) -> &IntCodeComputer {
+        &self.computer
+    }
+    pub fn computer_mut(&mut self) -> &mut IntCodeComputer {
+        &mut self.computer
+    }
+    /// Snapshot including queued input and text not read yet.
+    pub fn snapshot(&self) -> Snapshot {
+        let text: Vec<i64> = self.text.chars().map(|c| c as i64).collect();
+        self.computer.snapshot_with_io(&self.input, &text)
+    }
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.computer.restore(snapshot);
+        self.input = snapshot.input().clone();
+        self.text = snapshot.output().iter().map(|c| *c as u8 as char).collect();
+    }
+    /// Queues `line` followed by a newline as input. Nothing is queued, if line is not ASCII.
+    pub fn send_line(&mut self, line: &str) -> Result<(), NonAsciiInput> {
+        if let Some(ch) = line.chars().find(|c| !c.is_ascii()) {
+            return Err(NonAsciiInput(ch));
+        }
+        self.input
+            .extend(line.bytes().chain(Some(b'\n')).map(i64::from));
+        Ok(())
+    }
+    /// Reads next line. Returns Prompt instead, if program waits for input before
+    /// line is finished.
+    pub fn read_line(&mut self) -> Result<AsciiOutput, IntcodeError> {
+        self.read(true)
+    }
+    /// Reads all text until program waits for input.
+    pub fn read_until_prompt(&mut self) -> Result<AsciiOutput, IntcodeError> {
+        self.read(false)
+    }
+    fn read(&mut self, stop_at_newline: bool) -> Result<AsciiOutput, IntcodeError> {
+        loop {
+            match self.computer.run_cooperative(&mut self.input, BUDGET)? {
+                RunState::Output(value) => match u8::try_from(value) {
+                    Ok(b'\n') if stop_at_newline => {
+                        return Ok(AsciiOutput::Line(std::mem::take(&mut self.text)));
+                    }
+                    // text received before value stays buffered for next read
+                    Ok(byte) => self.text.push(byte as char),
+                    Err(_) => return Ok(AsciiOutput::Value(value)),
+                },
+                RunState::BlockedOnInput => {
+                    return Ok(AsciiOutput::Prompt(std::mem::take(&mut self.text)));
+                }
+                RunState::Halted => return Ok(AsciiOutput::Halted(std::mem::take(&mut self.text))),
+                RunState::Yielded => (),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_intcode() {
+        // prints "Hi\n?" and waits for input; echoes input line and outputs 1000 at newline
+        let program = "4,100,4,101,4,102,4,103,3,104,4,104,1008,104,10,105,1006,105,8,104,1000,99";
+        let mut code = IntCodeComputer::from(program);
+        for (address, ch) in "Hi\n?".chars().enumerate() {
+            code.set_address(100 + address as i64, ch as i64);
+        }
+        let mut ascii = AsciiIntcode::from(code);
+        assert_eq!(ascii.read_line(), Ok(AsciiOutput::Line("Hi".into())));
+        assert_eq!(ascii.read_line(), Ok(AsciiOutput::Prompt("?".into())));
+        assert_eq!(ascii.send_line("ök"), Err(NonAsciiInput('ö')));
+        assert_eq!(ascii.send_line("ok"), Ok(()));
+        assert_eq!(ascii.read_line(), Ok(AsciiOutput::Line("ok".into())));
+        assert_eq!(ascii.read_until_prompt(), Ok(AsciiOutput::Value(1000)));
+        assert_eq!(
+            ascii.read_until_prompt(),
+            Ok(AsciiOutput::Halted("".into()))
+        );
+    }
+}
@@ -0,0 +1,383 @@
+//!assembler.rs
+
+// Assembler for Intcode programs written in the mnemonics of the disassembler:
+//
+//         in [n]              ; parameter modes: [address], #immediate, rb+offset
+//   loop: add [sum], [n], [sum]
+//         add [n], #-1, [n]
+//         jt [n], #loop       ; labels may be used in every operand, e.g. [data+2]
+//         out [sum]
+//         hlt
+//   n:    data 0
+//   sum:  data 0, "text\n"  ; data takes numbers, labels and strings
+//
+// `;` starts a comment. A numeric prefix like `12:` is an address, which must match the
+// current address. Therefore listings of the disassembler assemble into the same program.
+
+use super::{Instruction, Opcode, ParamMode};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblerError {
+    /// Line of source counting from 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for AssemblerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AssemblerError {}
+
+/// Assembled program with addresses of all labels.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Assembly {
+    pub program: Vec<i64>,
+    pub labels: BTreeMap<String, i64>,
+}
+
+/// Comma separated program, which is the format of puzzle inputs.
+impl Display for Assembly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<String> = self.program.iter().map(|v| v.to_string()).collect();
+        write!(f, "{}", values.join(","))
+    }
+}
+
+// number or label with optional offset
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Expr {
+    label: Option<String>,
+    offset: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Statement {
+    Instruction {
+        opcode: Opcode,
+        operands: Vec<(ParamMode, Expr)>,
+    },
+    Data(Vec<Expr>),
+}
+
+impl Statement {
+    fn size(&self) -> i64 {
+        match self {
+            Statement::Instruction { operands, .. } => operands.len() as i64 + 1,
+            Statement::Data(values) => values.len() as i64,
+        }
+    }
+}
+
+pub fn assemble(source: &str) -> Result<Assembly, AssemblerError> {
+    // first pass: parse statements and collect label addresses
+    let mut statements: Vec<(usize, Statement)> = Vec::new();
+    let mut labels: BTreeMap<String, i64> = BTreeMap::new();
+    let mut address = 0;
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let error = |message: String| AssemblerError {
+            line: line_number,
+            message,
+        };
+        let mut text = strip_comment(line).trim();
+        while let Some((prefix, rest)) = text.split_once(':') {
+            let prefix = prefix.trim();
+            if let Ok(expected) = prefix.parse::<i64>() {
+                if expected != address {
+                    return Err(error(format!(
+                        "address {expected} does not match current address {address}"
+                    )));
+                }
+            } else if is_label(prefix) {
+                if labels.insert(prefix.to_string(), address).is_some() {
+                    return Err(error(format!("label '{prefix}' is defined twice")));
+                }
+            } else {
+                break;
+            }
+            text = rest.trim();
+        }
+        if text.is_empty() {
+            continue;
+        }
+        let statement = parse_statement(text).map_err(error)?;
+        address += statement.size();
+        statements.push((line_number, statement));
+    }
+
+    // second pass: encode statements
+    let mut program: Vec<i64> = Vec::with_capacity(address as usize);
+    for (line_number, statement) in statements {
+        let resolve = |expr: &Expr| -> Result<i64, AssemblerError> {
+            match &expr.label {
+                Some(label) => labels
+                    .get(label)
+                    .map(|address| address + expr.offset)
+                    .ok_or(AssemblerError {
+                        line: line_number,
+                        message: format!("unknown label '{label}'"),
+                    }),
+                None => Ok(expr.offset),
+            }
+        };
+        match statement {
+            Statement::Instruction { opcode, operands } => {
+                let mut modes = [ParamMode::default(); 3];
+                for (mode, (operand_mode, _)) in modes.iter_mut().zip(operands.iter()) {
+                    *mode = *operand_mode;
+                }
+                program.push(Instruction { opcode, modes }.encode());
+                for (_, expr) in operands.iter() {
+                    program.push(resolve(expr)?);
+                }
+            }
+            Statement::Data(values) => {
+                for expr in values.iter() {
+                    program.push(resolve(expr)?);
+                }
+            }
+        }
+    }
+    Ok(Assembly { program, labels })
+}
+
+// removes comment, which starts with `;` outside of strings
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, ch) in line.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            ';' if !in_string => return &line[..index],
+            _ => (),
+        }
+    }
+    line
+}
+
+fn is_label(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_statement(text: &str) -> Result<Statement, String> {
+    let (mnemonic, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let arguments = split_arguments(rest)?;
+    if mnemonic == "data" {
+        let mut values = Vec::new();
+        for argument in arguments.iter() {
+            if let Some(string) = argument.strip_prefix('"') {
+                let string = string
+                    .strip_suffix('"')
+                    .ok_or(format!("unterminated string {argument}"))?;
+                values.extend(unescape(string)?.chars().map(|c| Expr {
+                    label: None,
+                    offset: c as i64,
+                }));
+            } else {
+                values.push(parse_expr(argument)?);
+            }
+        }
+        return Ok(Statement::Data(values));
+    }
+    let opcode = Opcode::from_mnemonic(mnemonic).ok_or(format!("unknown mnemonic '{mnemonic}'"))?;
+    if arguments.len() != opcode.num_params() {
+        return Err(format!(
+            "'{mnemonic}' expects {} operands, found {}",
+            opcode.num_params(),
+            arguments.len()
+        ));
+    }
+    let operands = arguments
+        .iter()
+        .map(|argument| parse_operand(argument))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(param) = opcode.write_param()
+        && operands[param].0 == ParamMode::Immediate
+    {
+        return Err(format!(
+            "operand {} of '{mnemonic}' is written to",
+            param + 1
+        ));
+    }
+    Ok(Statement::Instruction { opcode, operands })
+}
+
+// splits at commas outside of strings
+fn split_arguments(text: &str) -> Result<Vec<&str>, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut arguments = Vec::new();
+    let mut start = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            ',' if !in_string => {
+                arguments.push(text[start..index].trim());
+                start = index + 1;
+            }
+            _ => (),
+        }
+    }
+    arguments.push(text[start..].trim());
+    if arguments.iter().any(|a| a.is_empty()) {
+        return Err(format!("empty operand in '{text}'"));
+    }
+    Ok(arguments)
+}
+
+fn unescape(text: &str) -> Result<String, String> {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('\\') => result.push('\\'),
+            Some('"') => result.push('"'),
+            other => {
+                return Err(format!(
+                    "invalid escape sequence \\{}",
+                    other.unwrap_or(' ')
+                ));
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn parse_operand(text: &str) -> Result<(ParamMode, Expr), String> {
+    if let Some(inner) = text.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        Ok((ParamMode::Position, parse_expr(inner)?))
+    } else if let Some(value) = text.strip_prefix('#') {
+        Ok((ParamMode::Immediate, parse_expr(value)?))
+    } else if let Some(offset) = text.strip_prefix("rb") {
+        let offset = offset.trim();
+        let expr = match offset.strip_prefix('+') {
+            Some(offset) => parse_expr(offset)?,
+            None if offset.is_empty() => Expr {
+                label: None,
+                offset: 0,
+            },
+            None if offset.starts_with('-') => parse_expr(offset)?,
+            None => return Err(format!("invalid relative operand '{text}'")),
+        };
+        Ok((ParamMode::Relative, expr))
+    } else {
+        Err(format!(
+            "operand '{text}' needs a mode: [address], #immediate or rb+offset"
+        ))
+    }
+}
+
+fn parse_expr(text: &str) -> Result<Expr, String> {
+    let text = text.trim();
+    if let Ok(offset) = text.parse::<i64>() {
+        return Ok(Expr {
+            label: None,
+            offset,
+        });
+    }
+    let (label, offset) = match text.rfind(['+', '-']) {
+        Some(index) if index > 0 => {
+            let offset = text[index..]
+                .replace(' ', "")
+                .trim_start_matches('+')
+                .parse::<i64>()
+                .map_err(|_| format!("invalid offset in '{text}'"))?;
+            (text[..index].trim(), offset)
+        }
+        _ => (text, 0),
+    };
+    if !is_label(label) {
+        return Err(format!("invalid value '{text}'"));
+    }
+    Ok(Expr {
+        label: Some(label.to_string()),
+        offset,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::{IntCodeComputer, disassemble};
+
+    const SUM: &str = "
+        ; sums n, n-1, ..., 1
+                in [n]
+        loop:   add [sum], [n], [sum]
+                add [n], #-1, [n]
+                jt [n], #loop
+                out [sum]
+                hlt
+        n:      data 0
+        sum:    data 0
+    ";
+
+    #[test]
+    fn test_assemble() {
+        let assembly = assemble(SUM).unwrap();
+        assert_eq!(
+            assembly.to_string(),
+            "3,16,1,17,16,17,1001,16,-1,16,1005,16,2,4,17,99,0,0"
+        );
+        assert_eq!(assembly.labels["loop"], 2);
+        let mut code = IntCodeComputer::from(assembly.to_string().as_str());
+        assert_eq!(code.run_int_code(&[4]), Ok(Some(10)));
+
+        let assembly = assemble("arb #buf+1\nout rb-1\nhlt\nbuf: data \"a;b,\\\"\", 7").unwrap();
+        assert_eq!(
+            assembly.program,
+            [109, 6, 204, -1, 99, 97, 59, 98, 44, 34, 7]
+        );
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        let error = |source| assemble(source).unwrap_err();
+        assert_eq!(error("hlt\nfoo [1]").line, 2);
+        assert_eq!(error("in #1").message, "operand 1 of 'in' is written to");
+        assert_eq!(error("jt [a], #b\na: data 1").message, "unknown label 'b'");
+        assert_eq!(error("out 5").line, 1);
+        assert_eq!(error("add [1], [2]").line, 1);
+        assert_eq!(error("a: hlt\na: hlt").line, 2);
+        assert_eq!(
+            error("hlt\n3: hlt").message,
+            "address 3 does not match current address 1"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let program = [
+            3, 100, 1105, 1, 9, 204, -1, 99, 0, 1001, 100, 1, 100, 1106, 0, 5, 72, 105, 10,
+        ];
+        let listing = disassemble(&program).to_string();
+        assert_eq!(assemble(&listing).unwrap().program, program);
+
+        let program = assemble(SUM).unwrap().program;
+        let listing = disassemble(&program).to_string();
+        assert_eq!(assemble(&listing).unwrap().program, program);
+    }
+}
@@ -0,0 +1,475 @@
+//!computer.rs
+
+use super::{HangDetection, HangDetector, Instruction, IntcodeError, Memory, Opcode, ParamMode};
+use std::collections::VecDeque;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+
+/// Instructions executed by async execution before yielding to other tasks.
+const ASYNC_BUDGET: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntOut {
+    None,
+    Out(i64),
+    Halt,
+    /// Computer waits for input.
+    BlockedOnInput,
+}
+
+/// State of computer after suspending cooperative execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunState {
+    Output(i64),
+    /// Next instruction is an input instruction and input queue is empty. Execution resumes
+    /// at this instruction.
+    BlockedOnInput,
+    Halted,
+    /// Instruction budget is used up.
+    Yielded,
+}
+
+#[derive(Debug, Clone)]
+pub struct IntCodeComputer {
+    pub(super) memory: Memory,
+    pc: i64,
+    relative_base: i64,
+    id: i64,
+    hang: HangDetector,
+}
+
+impl From<&str> for IntCodeComputer {
+    fn from(value: &str) -> Self {
+        let mut memory = Memory::default();
+        for (address, number) in value
+            .split(',')
+            .enumerate()
+            .filter_map(|(i, n)| n.trim().parse().ok().map(|d| (i as i64, d)))
+        {
+            memory.set(address, number);
+        }
+        IntCodeComputer {
+            memory,
+            pc: 0,
+            relative_base: 0,
+            id: 0,
+            hang: HangDetector::default(),
+        }
+    }
+}
+
+impl IntCodeComputer {
+    pub fn clone_n_times(&self, n: usize) -> Vec<Self> {
+        let mut clones: Vec<Self> = Vec::with_capacity(n);
+        for _ in 0..n {
+            clones.push(self.clone());
+        }
+        clones
+    }
+    /// Moves all values to sparse memory, see Memory::sparse().
+    pub fn use_sparse_memory(&mut self) {
+        self.memory = self.memory.to_sparse();
+    }
+    /// Runs until halt and returns last output. All outputs before last output are results of
+    /// diagnostic tests, which must be zero.
+    pub fn run_until_finished(&mut self, inputs: &[i64]) -> Result<i64, IntcodeError> {
+        let mut last_out: Option<i64> = None;
+        let mut inputs = inputs;
+        while let Some(out) = self.run_int_code(inputs)? {
+            inputs = &[];
+            if let Some(lo) = last_out
+                && lo != 0
+            {
+                return Err(IntcodeError::DiagnosticFailure {
+                    pc: self.pc,
+                    value: lo,
+                });
+            }
+            last_out = Some(out);
+        }
+        last_out.ok_or(IntcodeError::NoOutput { pc: self.pc })
+    }
+    pub fn run_int_code(&mut self, inputs: &[i64]) -> Result<Option<i64>, IntcodeError> {
+        // this is run like a state machine
+        // it returns None if finished or Some(out), if some out command has been executed
+        let mut inputs = inputs.iter();
+
+        while let Some(instruction) = self.next_instruction() {
+            let instruction = instruction?;
+            let input = if instruction.opcode == Opcode::Input {
+                Some(
+                    *inputs
+                        .next()
+                        .ok_or(IntcodeError::InputExhausted { pc: self.pc })?,
+                )
+            } else {
+                None
+            };
+            match self.execute(instruction, input)? {
+                IntOut::Out(out) => return Ok(Some(out)),
+                IntOut::Halt => break,
+                _ => (),
+            }
+        }
+        Ok(None)
+    }
+    /// Decodes instruction at pc. Returns None, if pc points outside of loaded code.
+    pub fn next_instruction(&self) -> Option<Result<Instruction, IntcodeError>> {
+        if self.pc < 0 {
+            return Some(Err(IntcodeError::NegativeAddress {
+                pc: self.pc,
+                address: self.pc,
+            }));
+        }
+        self.memory
+            .contains(self.pc)
+            .then(|| Instruction::decode(self.memory.get(self.pc), self.pc))
+    }
+    /// Executes a single instruction. `input` is only used by input instruction.
+    /// Returns halt without moving pc, if pc points to halt or outside of loaded code.
+    pub fn step(&mut self, input: Option<i64>) -> Result<IntOut, IntcodeError> {
+        match self.next_instruction() {
+            Some(instruction) => self.execute(instruction?, input),
+            None => Ok(IntOut::Halt),
+        }
+    }
+    /// Executes instruction at pc. `input` is only used by input instruction.
+    fn execute(
+        &mut self,
+        instruction: Instruction,
+        input: Option<i64>,
+    ) -> Result<IntOut, IntcodeError> {
+        if instruction.opcode == Opcode::Input {
+            self.hang.reset();
+        } else if self.hang.is_enabled() {
+            let mut hang = std::mem::take(&mut self.hang);
+            let check = hang.check(self);
+            self.hang = hang;
+            check?;
+        }
+        let pc = self.pc;
+        match instruction.opcode {
+            Opcode::Add | Opcode::Mul => {
+                let a = self.read_param(&instruction, 0)?;
+                let b = self.read_param(&instruction, 1)?;
+                let value = if instruction.opcode == Opcode::Add {
+                    a.checked_add(b)
+                } else {
+                    a.checked_mul(b)
+                }
+                .ok_or(IntcodeError::Overflow { pc })?;
+                let address = self.param_address(&instruction, 2)?;
+                self.set(address, value);
+            }
+            Opcode::Input => {
+                let value = input.ok_or(IntcodeError::InputExhausted { pc })?;
+                let address = self.param_address(&instruction, 0)?;
+                self.set(address, value);
+            }
+            Opcode::Output => {
+                let out = self.read_param(&instruction, 0)?;
+                self.pc += instruction.size();
+                return Ok(IntOut::Out(out));
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.read_param(&instruction, 0)? != 0;
+                if condition == (instruction.opcode == Opcode::JumpIfTrue) {
+                    self.pc = self.read_param(&instruction, 1)?;
+                    return Ok(IntOut::None);
+                }
+            }
+            Opcode::LessThan | Opcode::Equals => {
+                let a = self.read_param(&instruction, 0)?;
+                let b = self.read_param(&instruction, 1)?;
+                let value = i64::from(if instruction.opcode == Opcode::LessThan {
+                    a < b
+                } else {
+                    a == b
+                });
+                let address = self.param_address(&instruction, 2)?;
+                self.set(address, value);
+            }
+            Opcode::AdjustRelativeBase => {
+                let offset = self.read_param(&instruction, 0)?;
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(offset)
+                    .ok_or(IntcodeError::Overflow { pc })?;
+            }
+            Opcode::Halt => return Ok(IntOut::Halt),
+        }
+        self.pc += instruction.size();
+        Ok(IntOut::None)
+    }
+    /// Address a parameter refers to. Immediate parameters refer to their own address.
+    fn param_address(&self, instruction: &Instruction, param: usize) -> Result<i64, IntcodeError> {
+        let pc = self.pc;
+        let param_address = pc + 1 + param as i64;
+        let address = match instruction.modes[param] {
+            ParamMode::Position => self.get(param_address),
+            ParamMode::Immediate => param_address,
+            ParamMode::Relative => self
+                .get(param_address)
+                .checked_add(self.relative_base)
+                .ok_or(IntcodeError::Overflow { pc })?,
+        };
+        if address < 0 {
+            return Err(IntcodeError::NegativeAddress { pc, address });
+        }
+        Ok(address)
+    }
+    fn read_param(&self, instruction: &Instruction, param: usize) -> Result<i64, IntcodeError> {
+        Ok(self.get(self.param_address(instruction, param)?))
+    }
+    fn get(&self, address: i64) -> i64 {
+        self.memory.get(address)
+    }
+    fn set(&mut self, address: i64, value: i64) {
+        self.memory.set(address, value);
+    }
+    pub fn get_address(&self, add: i64) -> i64 {
+        self.get(add)
+    }
+    pub fn set_address(&mut self, add: i64, val: i64) {
+        self.set(add, val);
+    }
+    pub fn pc(&self) -> i64 {
+        self.pc
+    }
+    pub fn set_pc(&mut self, pc: i64) {
+        self.pc = pc;
+    }
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+    pub fn set_relative_base(&mut self, relative_base: i64) {
+        self.relative_base = relative_base;
+    }
+    /// Memory from address 0 up to highest address set so far.
+    pub fn memory(&self) -> Vec<i64> {
+        self.memory.to_vec()
+    }
+    /// Runs int code until it outputs a value, halts, blocks on empty `input` or has
+    /// executed `budget` instructions.
+    pub fn run_cooperative(
+        &mut self,
+        input: &mut VecDeque<i64>,
+        budget: usize,
+    ) -> Result<RunState, IntcodeError> {
+        for _ in 0..budget {
+            let Some(instruction) = self.next_instruction() else {
+                return Ok(RunState::Halted);
+            };
+            let instruction = instruction?;
+            let value = if instruction.opcode == Opcode::Input {
+                match input.pop_front() {
+                    Some(value) => Some(value),
+                    None => return Ok(RunState::BlockedOnInput),
+                }
+            } else {
+                None
+            };
+            match self.execute(instruction, value)? {
+                IntOut::Out(out) => return Ok(RunState::Output(out)),
+                IntOut::Halt => return Ok(RunState::Halted),
+                _ => (),
+            }
+        }
+        Ok(RunState::Yielded)
+    }
+    /// Runs int code as async task. If input is required and no input is available,
+    /// IntOut::BlockedOnInput is send and the task suspends until input arrives. Errors are
+    /// send to `out_sender`, before they are returned. Returns Ok, if program halts or one of
+    /// the channels is closed. Use set_hang_detection() to stop programs, which hang.
+    pub async fn run_async<E: From<IntcodeError>>(
+        &mut self,
+        mut in_receiver: UnboundedReceiver<i64>,
+        out_sender: UnboundedSender<Result<(i64, IntOut), E>>,
+    ) -> Result<(), IntcodeError> {
+        let fail = |err: IntcodeError| {
+            let _ = out_sender.send(Err(err.clone().into()));
+            Err(err)
+        };
+        let mut budget = ASYNC_BUDGET;
+        while let Some(instruction) = self.next_instruction() {
+            let instruction = match instruction {
+                Ok(instruction) => instruction,
+                Err(err) => return fail(err),
+            };
+            let input = if instruction.opcode == Opcode::Input {
+                let input = match in_receiver.try_recv() {
+                    Ok(input) => input,
+                    Err(_) => {
+                        if out_sender
+                            .send(Ok((self.id, IntOut::BlockedOnInput)))
+                            .is_err()
+                        {
+                            // receiver of outputs is gone
+                            break;
+                        }
+                        match in_receiver.recv().await {
+                            Some(input) => input,
+                            // sender of inputs is gone
+                            None => break,
+                        }
+                    }
+                };
+                Some(input)
+            } else {
+                None
+            };
+            let message = match self.execute(instruction, input) {
+                Ok(IntOut::None) => IntOut::None,
+                Ok(out) => out,
+                Err(err) => return fail(err),
+            };
+            if message != IntOut::None
+                && (out_sender.send(Ok((self.id, message))).is_err() || message == IntOut::Halt)
+            {
+                // receiver of outputs is gone or program halted
+                break;
+            }
+            budget -= 1;
+            if budget == 0 {
+                // give other tasks a chance to run
+                budget = ASYNC_BUDGET;
+                tokio::task::yield_now().await;
+            }
+        }
+        Ok(())
+    }
+    /// Enables detection of programs, which run forever without reading input. Detected hangs
+    /// are reported as IntcodeError::InfiniteLoop.
+    pub fn set_hang_detection(&mut self, detection: HangDetection) {
+        self.hang = HangDetector::new(detection);
+    }
+    pub fn hang_detection(&self) -> HangDetection {
+        self.hang.detection
+    }
+    pub fn set_id(&mut self, id: i64) {
+        self.id = id;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_run_int_code() {
+        // outputs input * 3 until halt
+        let mut code = IntCodeComputer::from("3,9,1002,9,3,9,4,9,99,0");
+        assert_eq!(code.run_int_code(&[14]), Ok(Some(42)));
+        assert_eq!(code.run_int_code(&[]), Ok(None));
+
+        // quine with relative mode and memory beyond program
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let mut code = IntCodeComputer::from(quine);
+        let mut outs: Vec<String> = Vec::new();
+        while let Some(out) = code.run_int_code(&[]).unwrap() {
+            outs.push(out.to_string());
+        }
+        assert_eq!(outs.join(","), quine);
+    }
+
+    #[test]
+    fn test_int_code_errors() {
+        let mut code = IntCodeComputer::from("3,0,3,0,99");
+        assert_eq!(
+            code.run_int_code(&[1]),
+            Err(IntcodeError::InputExhausted { pc: 2 })
+        );
+        // pc did not move, program continues with new input
+        assert_eq!(code.run_int_code(&[1]), Ok(None));
+
+        let mut code = IntCodeComputer::from("1,0,0,0,42");
+        assert_eq!(
+            code.run_int_code(&[]),
+            Err(IntcodeError::BadOpcode { pc: 4, value: 42 })
+        );
+
+        let mut code = IntCodeComputer::from("1,-3,0,0,99");
+        assert_eq!(
+            code.run_int_code(&[]),
+            Err(IntcodeError::NegativeAddress { pc: 0, address: -3 })
+        );
+
+        let mut code = IntCodeComputer::from("1101,1,1,0,11101,1,1,0,99");
+        assert_eq!(
+            code.run_int_code(&[]),
+            Err(IntcodeError::ImmediateWrite { pc: 4, param: 2 })
+        );
+
+        let mut code = IntCodeComputer::from("104,1,304,1,99");
+        assert_eq!(code.run_int_code(&[]), Ok(Some(1)));
+        assert_eq!(
+            code.run_int_code(&[]),
+            Err(IntcodeError::BadParamMode {
+                pc: 2,
+                param: 0,
+                mode: 3
+            })
+        );
+
+        let mut code = IntCodeComputer::from("1102,9223372036854775807,2,0,99");
+        let err = code.run_int_code(&[]).unwrap_err();
+        assert_eq!(err, IntcodeError::Overflow { pc: 0 });
+        assert_eq!(err.pc(), 0);
+    }
+
+    #[test]
+    fn test_run_cooperative() {
+        // echo input until input is zero
+        let mut code = IntCodeComputer::from("3,11,4,11,1005,11,0,99");
+        let mut input = VecDeque::new();
+        assert_eq!(
+            code.run_cooperative(&mut input, 100),
+            Ok(RunState::BlockedOnInput)
+        );
+        assert_eq!(code.pc(), 0);
+        input.extend([5, 0]);
+        assert_eq!(code.run_cooperative(&mut input, 1), Ok(RunState::Yielded));
+        assert_eq!(
+            code.run_cooperative(&mut input, 100),
+            Ok(RunState::Output(5))
+        );
+        assert_eq!(
+            code.run_cooperative(&mut input, 100),
+            Ok(RunState::Output(0))
+        );
+        assert_eq!(code.run_cooperative(&mut input, 100), Ok(RunState::Halted));
+        assert!(input.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_async() {
+        // echo input until input is zero
+        let mut code = IntCodeComputer::from("3,11,4,11,1005,11,0,99");
+        code.set_id(7);
+        let (in_sender, in_receiver) = mpsc::unbounded_channel();
+        let (out_sender, mut out_receiver) = mpsc::unbounded_channel::<Result<_, IntcodeError>>();
+        let task = tokio::spawn(async move { code.run_async(in_receiver, out_sender).await });
+        let mut inputs = [5, 3, 0].into_iter();
+        let mut outs = Vec::new();
+        while let Some(message) = out_receiver.recv().await {
+            let message = message.unwrap();
+            if message.1 == IntOut::BlockedOnInput {
+                in_sender.send(inputs.next().unwrap()).unwrap();
+            }
+            outs.push(message);
+        }
+        assert_eq!(task.await.unwrap(), Ok(()));
+        assert_eq!(
+            outs,
+            [
+                (7, IntOut::BlockedOnInput),
+                (7, IntOut::Out(5)),
+                (7, IntOut::BlockedOnInput),
+                (7, IntOut::Out(3)),
+                (7, IntOut::BlockedOnInput),
+                (7, IntOut::Out(0)),
+                (7, IntOut::Halt)
+            ]
+        );
+    }
+}
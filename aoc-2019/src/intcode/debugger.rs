@@ -0,0 +1,273 @@
+//!debugger.rs
+
+use super::{IntCodeComputer, IntOut, IntcodeError, Listing, Opcode, disassemble_from};
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Breakpoint {
+    /// Stop before executing instruction at address.
+    Address(i64),
+    /// Stop before executing any instruction with this opcode.
+    Opcode(Opcode),
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Breakpoint::Address(address) => write!(f, "address {address}"),
+            Breakpoint::Opcode(opcode) => write!(f, "opcode {}", opcode.mnemonic()),
+        }
+    }
+}
+
+/// Reason, why the debugger stopped execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StopReason {
+    /// Requested number of steps is executed.
+    Stepped,
+    Breakpoint(Breakpoint),
+    /// Watched memory cell changed its value.
+    Watchpoint {
+        address: i64,
+        old: i64,
+        new: i64,
+    },
+    /// Next instruction reads input, but no input is pending.
+    NeedsInput,
+    Halted,
+    Error(IntcodeError),
+}
+
+impl Display for StopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StopReason::Stepped => write!(f, "stepped"),
+            StopReason::Breakpoint(breakpoint) => write!(f, "breakpoint at {breakpoint}"),
+            StopReason::Watchpoint { address, old, new } => {
+                write!(f, "watchpoint [{address}] changed from {old} to {new}")
+            }
+            StopReason::NeedsInput => write!(f, "waiting for input"),
+            StopReason::Halted => write!(f, "halted"),
+            StopReason::Error(err) => write!(f, "error: {err}"),
+        }
+    }
+}
+
+/// Executes an Intcode program step by step with breakpoints and watchpoints. Input is fed
+/// from a queue of pending input, output is collected until it is taken.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    computer: IntCodeComputer,
+    breakpoints: BTreeSet<Breakpoint>,
+    watchpoints: BTreeSet<i64>,
+    input: VecDeque<i64>,
+    output: Vec<i64>,
+    steps: u64,
+}
+
+impl From<IntCodeComputer> for Debugger {
+    fn from(computer: IntCodeComputer) -> Self {
+        Debugger {
+            computer,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            input: VecDeque::new(),
+            output: Vec::new(),
+            steps: 0,
+        }
+    }
+}
+
+impl Debugger {
+    /// Computer to inspect or modify pc, relative base and memory.
+    pub fn computer(&self) -> &IntCodeComputer {
+        &self.computer
+    }
+    pub fn computer_mut(&mut self) -> &mut IntCodeComputer {
+        &mut self.computer
+    }
+    /// Returns false, if breakpoint already exists.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        self.breakpoints.insert(breakpoint)
+    }
+    /// Returns false, if breakpoint does not exist.
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        self.breakpoints.remove(&breakpoint)
+    }
+    pub fn breakpoints(&self) -> impl Iterator<Item = &Breakpoint> {
+        self.breakpoints.iter()
+    }
+    /// Returns false, if watchpoint already exists.
+    pub fn add_watchpoint(&mut self, address: i64) -> bool {
+        self.watchpoints.insert(address)
+    }
+    /// Returns false, if watchpoint does not exist.
+    pub fn remove_watchpoint(&mut self, address: i64) -> bool {
+        self.watchpoints.remove(&address)
+    }
+    pub fn watchpoints(&self) -> impl Iterator<Item = &i64> {
+        self.watchpoints.iter()
+    }
+    /// Pending input, which is consumed by input instructions.
+    pub fn input(&self) -> &VecDeque<i64> {
+        &self.input
+    }
+    pub fn input_mut(&mut self) -> &mut VecDeque<i64> {
+        &mut self.input
+    }
+    pub fn push_input(&mut self, input: impl IntoIterator<Item = i64>) {
+        self.input.extend(input);
+    }
+    /// Returns output collected since last call.
+    pub fn take_output(&mut self) -> Vec<i64> {
+        std::mem::take(&mut self.output)
+    }
+    /// Number of executed instructions.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+    /// Listing of `count` instructions from address on.
+    pub fn listing(&self, address: i64, count: usize) -> Listing {
+        let address = usize::try_from(address).unwrap_or_default();
+        disassemble_from(&self.computer.memory(), address, count)
+    }
+    /// Executes up to `steps` instructions. Breakpoints are ignored.
+    pub fn step(&mut self, steps: usize) -> StopReason {
+        for _ in 0..steps {
+            if let Some(reason) = self.execute_next() {
+                return reason;
+            }
+        }
+        StopReason::Stepped
+    }
+    /// Executes instructions until a breakpoint, watchpoint, missing input, halt or error.
+    /// The instruction at pc is always executed, which continues from a breakpoint.
+    pub fn run(&mut self) -> StopReason {
+        if let Some(reason) = self.execute_next() {
+            return reason;
+        }
+        loop {
+            if let Some(breakpoint) = self.breakpoint_at_pc() {
+                return StopReason::Breakpoint(breakpoint);
+            }
+            if let Some(reason) = self.execute_next() {
+                return reason;
+            }
+        }
+    }
+    fn breakpoint_at_pc(&self) -> Option<Breakpoint> {
+        let address = Breakpoint::Address(self.computer.pc());
+        if self.breakpoints.contains(&address) {
+            return Some(address);
+        }
+        let Some(Ok(instruction)) = self.computer.next_instruction() else {
+            return None;
+        };
+        let opcode = Breakpoint::Opcode(instruction.opcode);
+        self.breakpoints.contains(&opcode).then_some(opcode)
+    }
+    /// Executes instruction at pc. Returns reason to stop, if any.
+    fn execute_next(&mut self) -> Option<StopReason> {
+        let instruction = match self.computer.next_instruction() {
+            Some(Ok(instruction)) => instruction,
+            Some(Err(err)) => return Some(StopReason::Error(err)),
+            None => return Some(StopReason::Halted),
+        };
+        let input = match instruction.opcode {
+            Opcode::Halt => return Some(StopReason::Halted),
+            Opcode::Input => match self.input.front() {
+                Some(input) => Some(*input),
+                None => return Some(StopReason::NeedsInput),
+            },
+            _ => None,
+        };
+        let watched: Vec<(i64, i64)> = self
+            .watchpoints
+            .iter()
+            .map(|address| (*address, self.computer.get_address(*address)))
+            .collect();
+        match self.computer.step(input) {
+            Ok(IntOut::Out(out)) => self.output.push(out),
+            Ok(_) => (),
+            Err(err) => return Some(StopReason::Error(err)),
+        }
+        if input.is_some() {
+            self.input.pop_front();
+        }
+        self.steps += 1;
+        watched.into_iter().find_map(|(address, old)| {
+            let new = self.computer.get_address(address);
+            (new != old).then_some(StopReason::Watchpoint { address, old, new })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // counts [20] from input down to zero, outputs each value and halts
+    const COUNT_DOWN: &str = "3,20,4,20,1001,20,-1,20,1005,20,2,99";
+
+    #[test]
+    fn test_step_and_run() {
+        let mut debugger = Debugger::from(IntCodeComputer::from(COUNT_DOWN));
+        assert_eq!(debugger.step(1), StopReason::NeedsInput);
+        debugger.push_input([3]);
+        assert_eq!(debugger.step(2), StopReason::Stepped);
+        assert_eq!(debugger.computer().pc(), 4);
+        assert_eq!(debugger.take_output(), [3]);
+        assert!(debugger.input().is_empty());
+
+        assert!(debugger.add_breakpoint(Breakpoint::Opcode(Opcode::Output)));
+        assert_eq!(
+            debugger.run(),
+            StopReason::Breakpoint(Breakpoint::Opcode(Opcode::Output))
+        );
+        assert_eq!(debugger.computer().pc(), 2);
+        assert!(debugger.remove_breakpoint(Breakpoint::Opcode(Opcode::Output)));
+
+        debugger.add_breakpoint(Breakpoint::Address(11));
+        assert_eq!(
+            debugger.run(),
+            StopReason::Breakpoint(Breakpoint::Address(11))
+        );
+        assert_eq!(debugger.take_output(), [2, 1]);
+        assert_eq!(debugger.run(), StopReason::Halted);
+        assert_eq!(debugger.steps(), 10);
+    }
+
+    #[test]
+    fn test_watchpoint_and_modify() {
+        let mut debugger = Debugger::from(IntCodeComputer::from(COUNT_DOWN));
+        debugger.push_input([5]);
+        debugger.add_watchpoint(20);
+        assert_eq!(
+            debugger.run(),
+            StopReason::Watchpoint {
+                address: 20,
+                old: 0,
+                new: 5
+            }
+        );
+        debugger.remove_watchpoint(20);
+        // skip count down by setting counter to 1
+        debugger.computer_mut().set_address(20, 1);
+        assert_eq!(debugger.run(), StopReason::Halted);
+        assert_eq!(debugger.take_output(), [1]);
+
+        let mut debugger = Debugger::from(IntCodeComputer::from("204,-1,99"));
+        debugger.computer_mut().set_relative_base(3);
+        assert_eq!(debugger.step(1), StopReason::Stepped);
+        assert_eq!(debugger.take_output(), [99]);
+        debugger.computer_mut().set_pc(-2);
+        assert_eq!(
+            debugger.step(1),
+            StopReason::Error(IntcodeError::NegativeAddress {
+                pc: -2,
+                address: -2
+            })
+        );
+    }
+}
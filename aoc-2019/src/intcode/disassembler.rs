@@ -0,0 +1,348 @@
+//!disassembler.rs
+
+// Listing of an Intcode program. Instructions reachable from address 0 (following jumps with
+// immediate target) are code. Unreached gaps are code, if they decode into at least two
+// instructions ending in a halt or an unconditional jump (e.g. functions, which are only
+// reached via return addresses). Everything else is data.
+
+use super::{Instruction, IntCodeComputer, Opcode, ParamMode};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// Minimum number of instructions of code found in unreached gaps.
+const MIN_GAP_INSTRUCTIONS: usize = 2;
+/// Values per line of data.
+const DATA_PER_LINE: usize = 8;
+/// Width of instruction text before comment.
+const TEXT_WIDTH: usize = 28;
+
+/// Parameter of an instruction, rendered as `[addr]`, `#imm` or `rb+off`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operand {
+    pub mode: ParamMode,
+    pub value: i64,
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.mode {
+            ParamMode::Position => write!(f, "[{}]", self.value),
+            ParamMode::Immediate => write!(f, "#{}", self.value),
+            ParamMode::Relative if self.value < 0 => write!(f, "rb{}", self.value),
+            ParamMode::Relative => write!(f, "rb+{}", self.value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListingLine {
+    Code {
+        address: i64,
+        instruction: Instruction,
+        operands: Vec<Operand>,
+        /// Addresses of jumps with this instruction as immediate target.
+        jumps_from: Vec<i64>,
+    },
+    Data {
+        address: i64,
+        values: Vec<i64>,
+    },
+}
+
+impl ListingLine {
+    pub fn address(&self) -> i64 {
+        match self {
+            ListingLine::Code { address, .. } | ListingLine::Data { address, .. } => *address,
+        }
+    }
+}
+
+impl Display for ListingLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListingLine::Code {
+                address,
+                instruction,
+                operands,
+                jumps_from,
+            } => {
+                if !jumps_from.is_empty() {
+                    let sources: Vec<String> = jumps_from.iter().map(|a| a.to_string()).collect();
+                    writeln!(f, "{:>7}; jump target of {}", "", sources.join(", "))?;
+                }
+                let raw: Vec<String> = std::iter::once(instruction.encode())
+                    .chain(operands.iter().map(|o| o.value))
+                    .map(|v| v.to_string())
+                    .collect();
+                let operands: Vec<String> = operands.iter().map(|o| o.to_string()).collect();
+                let text = format!("{} {}", instruction.opcode.mnemonic(), operands.join(", "));
+                write!(
+                    f,
+                    "{address:>5}: {:<TEXT_WIDTH$} ; {}",
+                    text.trim_end(),
+                    raw.join(",")
+                )
+            }
+            ListingLine::Data { address, values } => {
+                let values_text: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                let text = format!("data {}", values_text.join(", "));
+                match ascii_text(values) {
+                    Some(ascii) => write!(f, "{address:>5}: {text:<TEXT_WIDTH$} ; {ascii:?}"),
+                    None => write!(f, "{address:>5}: {text}"),
+                }
+            }
+        }
+    }
+}
+
+/// Returns text of data, if all values are printable ascii.
+fn ascii_text(values: &[i64]) -> Option<String> {
+    values
+        .iter()
+        .map(|v| {
+            u8::try_from(*v)
+                .ok()
+                .filter(|b| b.is_ascii_graphic() || *b == b' ' || *b == b'\n')
+                .map(char::from)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Listing {
+    pub lines: Vec<ListingLine>,
+}
+
+impl Display for Listing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in self.lines.iter() {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+impl IntCodeComputer {
+    pub fn disassemble(&self) -> Listing {
+        disassemble(&self.memory())
+    }
+}
+
+/// Disassembles program with entry point at address 0.
+pub fn disassemble(program: &[i64]) -> Listing {
+    let mut code: BTreeMap<usize, Instruction> = BTreeMap::new();
+    let mut covered = vec![false; program.len()];
+    let mut jumps_from: BTreeMap<usize, Vec<i64>> = BTreeMap::new();
+
+    // follow control flow
+    let mut entry_points = vec![0];
+    while let Some(mut address) = entry_points.pop() {
+        while let Some(instruction) = decode_at(program, address) {
+            let size = instruction.size() as usize;
+            if covered[address..address + size].iter().any(|c| *c) {
+                // already visited or overlapping other instruction
+                break;
+            }
+            covered[address..address + size].fill(true);
+            code.insert(address, instruction);
+            if let Some(target) = jump_target(program, address, &instruction) {
+                jumps_from.entry(target).or_default().push(address as i64);
+                entry_points.push(target);
+            }
+            if ends_flow(program, address, &instruction) {
+                break;
+            }
+            address += size;
+        }
+    }
+
+    // search for code in unreached gaps
+    let mut address = 0;
+    while address < program.len() {
+        if covered[address] {
+            address += 1;
+            continue;
+        }
+        match sweep_gap(program, &covered, address) {
+            Some(instructions) => {
+                for (start, instruction) in instructions {
+                    let size = instruction.size() as usize;
+                    covered[start..start + size].fill(true);
+                    code.insert(start, instruction);
+                    address = start + size;
+                }
+            }
+            None => address += 1,
+        }
+    }
+
+    // build lines
+    let mut lines: Vec<ListingLine> = Vec::new();
+    let mut address = 0;
+    while address < program.len() {
+        if let Some(instruction) = code.get(&address) {
+            let jumps_from = jumps_from.remove(&address).unwrap_or_default();
+            lines.push(code_line(program, address, *instruction, jumps_from));
+            address += instruction.size() as usize;
+        } else {
+            let start = address;
+            while address < program.len()
+                && !code.contains_key(&address)
+                && address - start < DATA_PER_LINE
+            {
+                address += 1;
+            }
+            lines.push(ListingLine::Data {
+                address: start as i64,
+                values: program[start..address].to_vec(),
+            });
+        }
+    }
+    Listing { lines }
+}
+
+/// Decodes `count` lines from address on without searching for code, e.g. at pc of a
+/// debugger. Values, which are no valid instruction, are shown as single data values.
+pub fn disassemble_from(program: &[i64], address: usize, count: usize) -> Listing {
+    let mut lines: Vec<ListingLine> = Vec::with_capacity(count);
+    let mut address = address;
+    while lines.len() < count && address < program.len() {
+        match decode_at(program, address) {
+            Some(instruction) => {
+                lines.push(code_line(program, address, instruction, Vec::new()));
+                address += instruction.size() as usize;
+            }
+            None => {
+                lines.push(ListingLine::Data {
+                    address: address as i64,
+                    values: vec![program[address]],
+                });
+                address += 1;
+            }
+        }
+    }
+    Listing { lines }
+}
+
+fn code_line(
+    program: &[i64],
+    address: usize,
+    instruction: Instruction,
+    jumps_from: Vec<i64>,
+) -> ListingLine {
+    let operands = (0..instruction.opcode.num_params())
+        .map(|param| Operand {
+            mode: instruction.modes[param],
+            value: program[address + 1 + param],
+        })
+        .collect();
+    ListingLine::Code {
+        address: address as i64,
+        instruction,
+        operands,
+        jumps_from,
+    }
+}
+
+/// Decodes instruction at address, if it is valid and fits into program.
+fn decode_at(program: &[i64], address: usize) -> Option<Instruction> {
+    let instruction = Instruction::decode(*program.get(address)?, address as i64).ok()?;
+    (address + instruction.size() as usize <= program.len()).then_some(instruction)
+}
+
+fn jump_target(program: &[i64], address: usize, instruction: &Instruction) -> Option<usize> {
+    if !matches!(instruction.opcode, Opcode::JumpIfTrue | Opcode::JumpIfFalse)
+        || instruction.modes[1] != ParamMode::Immediate
+    {
+        return None;
+    }
+    usize::try_from(program[address + 2])
+        .ok()
+        .filter(|target| *target < program.len())
+}
+
+/// True for halt and jumps with immediate condition, which always jump.
+fn ends_flow(program: &[i64], address: usize, instruction: &Instruction) -> bool {
+    let always_jumps = |jump_if_true: bool| {
+        instruction.modes[0] == ParamMode::Immediate && (program[address + 1] != 0) == jump_if_true
+    };
+    match instruction.opcode {
+        Opcode::Halt => true,
+        Opcode::JumpIfTrue => always_jumps(true),
+        Opcode::JumpIfFalse => always_jumps(false),
+        _ => false,
+    }
+}
+
+/// Decodes instructions of gap starting at address up to an instruction, which ends flow.
+fn sweep_gap(
+    program: &[i64],
+    covered: &[bool],
+    address: usize,
+) -> Option<Vec<(usize, Instruction)>> {
+    let mut instructions = Vec::new();
+    let mut current = address;
+    while let Some(instruction) = decode_at(program, current) {
+        let size = instruction.size() as usize;
+        if covered[current..current + size].iter().any(|c| *c) {
+            return None;
+        }
+        instructions.push((current, instruction));
+        if ends_flow(program, current, &instruction) {
+            return (instructions.len() >= MIN_GAP_INSTRUCTIONS).then_some(instructions);
+        }
+        current += size;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disassemble() {
+        // read input, jump to function at 9, which is only reached via jump target,
+        // output and halt; followed by function and string data
+        let code =
+            IntCodeComputer::from("3,100,1105,1,9,204,-1,99,0,1001,100,1,100,1106,0,5,72,105,10");
+        let listing = code.disassemble();
+        let text = listing.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "    0: in [100]                     ; 3,100");
+        assert_eq!(lines[1], "    2: jt #1, #9                    ; 1105,1,9");
+        assert_eq!(lines[2], "       ; jump target of 13");
+        assert_eq!(lines[3], "    5: out rb-1                     ; 204,-1");
+        assert_eq!(lines[4], "    7: hlt                          ; 99");
+        assert_eq!(lines[5], "    8: data 0");
+        assert_eq!(lines[6], "       ; jump target of 2");
+        assert_eq!(
+            lines[7],
+            "    9: add [100], #1, [100]         ; 1001,100,1,100"
+        );
+        assert_eq!(lines[8], "   13: jf #0, #5                    ; 1106,0,5");
+        assert_eq!(lines[9], "   16: data 72, 105, 10             ; \"Hi\\n\"");
+        assert_eq!(lines.len(), 10);
+    }
+
+    #[test]
+    fn test_disassemble_unreached_gap() {
+        // code at 3 is only reachable via indirect jump
+        let listing = disassemble(&[1106, 0, 100, 1, 0, 0, 0, 99, 99]);
+        let addresses: Vec<(i64, bool)> = listing
+            .lines
+            .iter()
+            .map(|l| (l.address(), matches!(l, ListingLine::Code { .. })))
+            .collect();
+        assert_eq!(addresses, [(0, true), (3, true), (7, true), (8, false)]);
+    }
+
+    #[test]
+    fn test_disassemble_from() {
+        let listing = disassemble_from(&[0, 1101, 1, 2, 3, -5, 99], 1, 2);
+        assert_eq!(
+            listing.to_string(),
+            "    1: add #1, #2, [3]              ; 1101,1,2,3\n    5: data -5\n"
+        );
+    }
+}
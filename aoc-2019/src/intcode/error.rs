@@ -0,0 +1,81 @@
+//!error.rs
+
+use std::fmt::Display;
+
+/// Errors of the Intcode computer. Each error carries the address (`pc`) of the faulting
+/// instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntcodeError {
+    /// Value at `pc` does not encode a known opcode.
+    BadOpcode { pc: i64, value: i64 },
+    /// Parameter `param` (counting from 0) uses an unknown parameter mode.
+    BadParamMode { pc: i64, param: usize, mode: i64 },
+    /// Instruction tried to access a negative memory address.
+    NegativeAddress { pc: i64, address: i64 },
+    /// Parameter `param` (counting from 0) is written to, but uses immediate mode.
+    ImmediateWrite { pc: i64, param: usize },
+    /// Arithmetic of instruction overflowed i64.
+    Overflow { pc: i64 },
+    /// Input instruction without any input left.
+    InputExhausted { pc: i64 },
+    /// Instruction budget exceeded or state repeated without reading input. `steps` counts
+    /// instructions since last input.
+    InfiniteLoop { pc: i64, steps: u64 },
+    /// Diagnostic program reported failed test with output `value` not equal to zero before
+    /// final output.
+    DiagnosticFailure { pc: i64, value: i64 },
+    /// Program halted without any output.
+    NoOutput { pc: i64 },
+}
+
+impl IntcodeError {
+    /// Address of faulting instruction.
+    pub fn pc(&self) -> i64 {
+        match self {
+            IntcodeError::BadOpcode { pc, .. }
+            | IntcodeError::BadParamMode { pc, .. }
+            | IntcodeError::NegativeAddress { pc, .. }
+            | IntcodeError::ImmediateWrite { pc, .. }
+            | IntcodeError::Overflow { pc }
+            | IntcodeError::InputExhausted { pc }
+            | IntcodeError::InfiniteLoop { pc, .. }
+            | IntcodeError::DiagnosticFailure { pc, .. }
+            | IntcodeError::NoOutput { pc } => *pc,
+        }
+    }
+}
+
+impl Display for IntcodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntcodeError::BadOpcode { pc, value } => {
+                write!(f, "invalid instruction '{value}' at address {pc}")
+            }
+            IntcodeError::BadParamMode { pc, param, mode } => write!(
+                f,
+                "invalid mode '{mode}' of parameter {param} at address {pc}"
+            ),
+            IntcodeError::NegativeAddress { pc, address } => {
+                write!(f, "negative memory address '{address}' at address {pc}")
+            }
+            IntcodeError::ImmediateWrite { pc, param } => write!(
+                f,
+                "immediate mode of write parameter {param} at address {pc}"
+            ),
+            IntcodeError::Overflow { pc } => write!(f, "integer overflow at address {pc}"),
+            IntcodeError::InputExhausted { pc } => write!(f, "no input left at address {pc}"),
+            IntcodeError::InfiniteLoop { pc, steps } => write!(
+                f,
+                "infinite loop at address {pc} after {steps} instructions without input"
+            ),
+            IntcodeError::DiagnosticFailure { pc, value } => {
+                write!(f, "failed diagnostic test '{value}' before address {pc}")
+            }
+            IntcodeError::NoOutput { pc } => {
+                write!(f, "no output generated until halt at address {pc}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntcodeError {}
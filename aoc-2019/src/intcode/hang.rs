@@ -0,0 +1,118 @@
+//!hang.rs
+
+// Detection of Intcode programs, which run forever without reading input. Both checks are
+// reset by every input instruction and cost nothing if they are disabled (default).
+
+use super::{IntCodeComputer, IntcodeError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HangDetection {
+    /// Maximum number of instructions executed between two inputs.
+    pub budget: Option<u64>,
+    /// Detects exact repetition of computer state between two inputs (Brent's algorithm).
+    /// State is only hashed, if pc and relative base match the saved state.
+    pub detect_cycles: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct HangDetector {
+    pub(super) detection: HangDetection,
+    steps: u64,
+    power: u64,
+    lambda: u64,
+    // pc, relative base and state hash
+    saved: Option<(i64, i64, u64)>,
+}
+
+impl HangDetector {
+    pub(super) fn new(detection: HangDetection) -> Self {
+        HangDetector {
+            detection,
+            ..Default::default()
+        }
+    }
+    pub(super) fn is_enabled(&self) -> bool {
+        self.detection != HangDetection::default()
+    }
+    pub(super) fn reset(&mut self) {
+        self.steps = 0;
+        self.power = 0;
+        self.lambda = 0;
+        self.saved = None;
+    }
+    /// Checks state of computer before next instruction is executed.
+    pub(super) fn check(&mut self, computer: &IntCodeComputer) -> Result<(), IntcodeError> {
+        self.steps += 1;
+        let pc = computer.pc();
+        let infinite_loop = IntcodeError::InfiniteLoop {
+            pc,
+            steps: self.steps,
+        };
+        if let Some(budget) = self.detection.budget
+            && self.steps > budget
+        {
+            return Err(infinite_loop);
+        }
+        if !self.detection.detect_cycles {
+            return Ok(());
+        }
+        let relative_base = computer.relative_base();
+        if let Some((saved_pc, saved_relative_base, saved_hash)) = self.saved
+            && saved_pc == pc
+            && saved_relative_base == relative_base
+            && saved_hash == computer.state_hash()
+        {
+            return Err(infinite_loop);
+        }
+        self.lambda += 1;
+        if self.lambda >= self.power {
+            // cycle may be longer than distance to saved state
+            self.power = (self.power * 2).max(1);
+            self.lambda = 0;
+            self.saved = Some((pc, relative_base, computer.state_hash()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hang_detection() {
+        // reads input and loops forever, if input is zero; counts up to input otherwise
+        let program = "3,100,1006,100,2,1001,101,1,101,7,101,100,102,1005,102,5,99";
+        let mut code = IntCodeComputer::from(program);
+        code.set_hang_detection(HangDetection {
+            budget: Some(1_000),
+            detect_cycles: false,
+        });
+        assert_eq!(code.clone().run_int_code(&[300]), Ok(None));
+        assert_eq!(
+            code.clone().run_int_code(&[400]),
+            Err(IntcodeError::InfiniteLoop {
+                pc: 5,
+                steps: 1_001
+            })
+        );
+        assert_eq!(
+            code.clone().run_int_code(&[0]),
+            Err(IntcodeError::InfiniteLoop {
+                pc: 2,
+                steps: 1_001
+            })
+        );
+
+        code.set_hang_detection(HangDetection {
+            budget: None,
+            detect_cycles: true,
+        });
+        assert_eq!(code.clone().run_int_code(&[400]), Ok(None));
+        let Err(IntcodeError::InfiniteLoop { pc, steps }) = code.run_int_code(&[0]) else {
+            panic!("cycle not detected");
+        };
+        assert_eq!(pc, 2);
+        assert!(steps < 10);
+    }
+}
@@ -0,0 +1,238 @@
+//!instruction.rs
+
+use super::IntcodeError;
+
+/// Operation of an instruction, encoded in the two lowest decimal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Opcode {
+    Add,
+    Mul,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 10] = [
+        Opcode::Add,
+        Opcode::Mul,
+        Opcode::Input,
+        Opcode::Output,
+        Opcode::JumpIfTrue,
+        Opcode::JumpIfFalse,
+        Opcode::LessThan,
+        Opcode::Equals,
+        Opcode::AdjustRelativeBase,
+        Opcode::Halt,
+    ];
+    pub fn from_code(code: i64) -> Option<Self> {
+        match code {
+            1 => Some(Opcode::Add),
+            2 => Some(Opcode::Mul),
+            3 => Some(Opcode::Input),
+            4 => Some(Opcode::Output),
+            5 => Some(Opcode::JumpIfTrue),
+            6 => Some(Opcode::JumpIfFalse),
+            7 => Some(Opcode::LessThan),
+            8 => Some(Opcode::Equals),
+            9 => Some(Opcode::AdjustRelativeBase),
+            99 => Some(Opcode::Halt),
+            _ => None,
+        }
+    }
+    pub fn code(self) -> i64 {
+        match self {
+            Opcode::Add => 1,
+            Opcode::Mul => 2,
+            Opcode::Input => 3,
+            Opcode::Output => 4,
+            Opcode::JumpIfTrue => 5,
+            Opcode::JumpIfFalse => 6,
+            Opcode::LessThan => 7,
+            Opcode::Equals => 8,
+            Opcode::AdjustRelativeBase => 9,
+            Opcode::Halt => 99,
+        }
+    }
+    /// Short name used by disassembler.
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Add => "add",
+            Opcode::Mul => "mul",
+            Opcode::Input => "in",
+            Opcode::Output => "out",
+            Opcode::JumpIfTrue => "jt",
+            Opcode::JumpIfFalse => "jf",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::AdjustRelativeBase => "arb",
+            Opcode::Halt => "hlt",
+        }
+    }
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Opcode::ALL.into_iter().find(|o| o.mnemonic() == mnemonic)
+    }
+    /// Number of parameters following the opcode.
+    pub fn num_params(self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output | Opcode::AdjustRelativeBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+    /// Index of parameter, which is the address the result is written to.
+    pub fn write_param(self) -> Option<usize> {
+        match self {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => Some(2),
+            Opcode::Input => Some(0),
+            _ => None,
+        }
+    }
+}
+
+/// Mode of a parameter, encoded in the decimal digits above the opcode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ParamMode {
+    /// Parameter is an address.
+    #[default]
+    Position,
+    /// Parameter is a value.
+    Immediate,
+    /// Parameter is an address relative to the relative base.
+    Relative,
+}
+
+impl ParamMode {
+    pub fn from_code(code: i64) -> Option<Self> {
+        match code {
+            0 => Some(ParamMode::Position),
+            1 => Some(ParamMode::Immediate),
+            2 => Some(ParamMode::Relative),
+            _ => None,
+        }
+    }
+    pub fn code(self) -> i64 {
+        match self {
+            ParamMode::Position => 0,
+            ParamMode::Immediate => 1,
+            ParamMode::Relative => 2,
+        }
+    }
+}
+
+/// Decoded instruction. Modes of unused parameters are `ParamMode::Position`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [ParamMode; 3],
+}
+
+impl Instruction {
+    /// Decodes `value` found at address `pc`.
+    pub fn decode(value: i64, pc: i64) -> Result<Self, IntcodeError> {
+        let opcode = Opcode::from_code(value % 100).ok_or(IntcodeError::BadOpcode { pc, value })?;
+        let mut modes = [ParamMode::Position; 3];
+        let mut mode_codes = value / 100;
+        for (param, mode) in modes.iter_mut().enumerate().take(opcode.num_params()) {
+            let code = mode_codes % 10;
+            mode_codes /= 10;
+            *mode = ParamMode::from_code(code).ok_or(IntcodeError::BadParamMode {
+                pc,
+                param,
+                mode: code,
+            })?;
+            if *mode == ParamMode::Immediate && opcode.write_param() == Some(param) {
+                return Err(IntcodeError::ImmediateWrite { pc, param });
+            }
+        }
+        if mode_codes != 0 {
+            // modes for parameters the opcode does not have
+            return Err(IntcodeError::BadOpcode { pc, value });
+        }
+        Ok(Instruction { opcode, modes })
+    }
+    /// Encodes instruction into its memory value.
+    pub fn encode(&self) -> i64 {
+        self.modes
+            .iter()
+            .take(self.opcode.num_params())
+            .rev()
+            .fold(0, |codes, mode| codes * 10 + mode.code())
+            * 100
+            + self.opcode.code()
+    }
+    /// Number of memory values of instruction including its parameters.
+    pub fn size(&self) -> i64 {
+        self.opcode.num_params() as i64 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_instruction() {
+        let instruction = Instruction::decode(1002, 0).unwrap();
+        assert_eq!(instruction.opcode, Opcode::Mul);
+        assert_eq!(
+            instruction.modes,
+            [
+                ParamMode::Position,
+                ParamMode::Immediate,
+                ParamMode::Position
+            ]
+        );
+        assert_eq!(instruction.size(), 4);
+        assert_eq!(instruction.encode(), 1002);
+
+        let instruction = Instruction::decode(204, 7).unwrap();
+        assert_eq!(instruction.opcode, Opcode::Output);
+        assert_eq!(instruction.modes[0], ParamMode::Relative);
+        assert_eq!(instruction.encode(), 204);
+        assert_eq!(Instruction::decode(99, 0).unwrap().size(), 1);
+        assert_eq!(
+            Opcode::from_mnemonic("arb"),
+            Some(Opcode::AdjustRelativeBase)
+        );
+        assert_eq!(Opcode::from_mnemonic("nop"), None);
+
+        assert_eq!(
+            Instruction::decode(42, 3),
+            Err(IntcodeError::BadOpcode { pc: 3, value: 42 })
+        );
+        assert_eq!(
+            Instruction::decode(-1, 3),
+            Err(IntcodeError::BadOpcode { pc: 3, value: -1 })
+        );
+        assert_eq!(
+            Instruction::decode(10104, 3),
+            Err(IntcodeError::BadOpcode {
+                pc: 3,
+                value: 10104
+            })
+        );
+        assert_eq!(
+            Instruction::decode(301, 5),
+            Err(IntcodeError::BadParamMode {
+                pc: 5,
+                param: 0,
+                mode: 3
+            })
+        );
+        assert_eq!(
+            Instruction::decode(10001, 5),
+            Err(IntcodeError::ImmediateWrite { pc: 5, param: 2 })
+        );
+        assert_eq!(
+            Instruction::decode(103, 5),
+            Err(IntcodeError::ImmediateWrite { pc: 5, param: 0 })
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//!memory.rs
+
+// Memory of the Intcode computer. Addresses below DENSE_LIMIT live in pages of 1024 values,
+// which are allocated on first write. Higher (and negative) addresses fall back to a HashMap.
+// Pages and HashMap are shared copy-on-write between clones, which makes snapshots cheap.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const PAGE_BITS: u32 = 10;
+const PAGE_SIZE: usize = 1 << PAGE_BITS;
+const PAGE_MASK: usize = PAGE_SIZE - 1;
+/// Addresses from this limit on are stored in sparse memory.
+const DENSE_LIMIT: i64 = 1 << 24;
+
+type Page = Arc<[i64; PAGE_SIZE]>;
+
+#[derive(Debug, Clone)]
+pub struct Memory {
+    pages: Vec<Option<Page>>,
+    sparse: Arc<HashMap<i64, i64>>,
+    /// Highest dense address set so far + 1.
+    len: i64,
+    dense_limit: i64,
+}
+
+impl Default for Memory {
+    fn default() -> Self {
+        Memory {
+            pages: Vec::new(),
+            sparse: Arc::default(),
+            len: 0,
+            dense_limit: DENSE_LIMIT,
+        }
+    }
+}
+
+impl Memory {
+    /// Memory without pages, which stores all addresses in a HashMap. This is how memory
+    /// worked before pages. It is kept as baseline for benchmarks.
+    pub fn sparse() -> Self {
+        Memory {
+            dense_limit: 0,
+            ..Default::default()
+        }
+    }
+    /// Memory not set yet is zero.
+    pub fn get(&self, address: i64) -> i64 {
+        if (0..self.dense_limit).contains(&address) {
+            let address = address as usize;
+            match self.pages.get(address >> PAGE_BITS) {
+                Some(Some(page)) => page[address & PAGE_MASK],
+                _ => 0,
+            }
+        } else {
+            self.sparse.get(&address).copied().unwrap_or(0)
+        }
+    }
+    pub fn set(&mut self, address: i64, value: i64) {
+        if (0..self.dense_limit).contains(&address) {
+            let index = address as usize;
+            let page_index = index >> PAGE_BITS;
+            if page_index >= self.pages.len() {
+                self.pages.resize(page_index + 1, None);
+            }
+            let page = self.pages[page_index].get_or_insert_with(|| Arc::new([0; PAGE_SIZE]));
+            Arc::make_mut(page)[index & PAGE_MASK] = value;
+            self.len = self.len.max(address + 1);
+        } else {
+            Arc::make_mut(&mut self.sparse).insert(address, value);
+        }
+    }
+    /// True, if address is below highest set dense address or if sparse address is set.
+    pub fn contains(&self, address: i64) -> bool {
+        (0..self.len).contains(&address) || self.sparse.contains_key(&address)
+    }
+    /// Memory from address 0 up to highest address set so far below dense limit.
+    pub fn to_vec(&self) -> Vec<i64> {
+        let len = self
+            .sparse
+            .keys()
+            .filter(|address| (0..DENSE_LIMIT).contains(*address))
+            .map(|address| address + 1)
+            .fold(self.len, i64::max);
+        (0..len).map(|address| self.get(address)).collect()
+    }
+    /// Copy of memory with all values in sparse memory.
+    pub fn to_sparse(&self) -> Self {
+        let mut sparse = Memory::sparse();
+        for address in 0..self.len {
+            sparse.set(address, self.get(address));
+        }
+        Arc::make_mut(&mut sparse.sparse).extend(self.sparse.iter());
+        sparse
+    }
+    /// All values not equal to zero sorted by address. The result does not depend on how
+    /// values are stored.
+    pub fn cells(&self) -> Vec<(i64, i64)> {
+        let mut cells: Vec<(i64, i64)> = self
+            .pages
+            .iter()
+            .enumerate()
+            .filter_map(|(page_index, page)| page.as_ref().map(|page| (page_index, page)))
+            .flat_map(|(page_index, page)| {
+                page.iter().enumerate().map(move |(offset, value)| {
+                    (((page_index << PAGE_BITS) + offset) as i64, *value)
+                })
+            })
+            .chain(
+                self.sparse
+                    .iter()
+                    .map(|(address, value)| (*address, *value)),
+            )
+            .filter(|(_, value)| *value != 0)
+            .collect();
+        cells.sort_unstable();
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory() {
+        let mut memory = Memory::default();
+        assert_eq!(memory.get(5_000), 0);
+        assert!(!memory.contains(0));
+        memory.set(0, 1);
+        memory.set(2_000, 2);
+        memory.set(DENSE_LIMIT + 7, 3);
+        assert_eq!(memory.get(0), 1);
+        assert_eq!(memory.get(1_999), 0);
+        assert_eq!(memory.get(2_000), 2);
+        assert_eq!(memory.get(DENSE_LIMIT + 7), 3);
+        assert!(memory.contains(1_999));
+        assert!(!memory.contains(2_001));
+        assert!(memory.contains(DENSE_LIMIT + 7));
+        assert_eq!(memory.pages.iter().filter(|p| p.is_some()).count(), 2);
+        assert_eq!(memory.to_vec().len(), 2_001);
+
+        let sparse = memory.to_sparse();
+        assert!(sparse.pages.is_empty());
+        assert_eq!(sparse.get(2_000), 2);
+        assert_eq!(sparse.get(DENSE_LIMIT + 7), 3);
+        assert_eq!(sparse.to_vec(), memory.to_vec());
+        assert_eq!(sparse.cells(), memory.cells());
+        assert_eq!(memory.cells(), [(0, 1), (2_000, 2), (DENSE_LIMIT + 7, 3)]);
+
+        // clones share pages until they are written to
+        let mut copy = memory.clone();
+        copy.set(1, 4);
+        assert!(Arc::ptr_eq(
+            memory.pages[1].as_ref().unwrap(),
+            copy.pages[1].as_ref().unwrap()
+        ));
+        assert!(!Arc::ptr_eq(
+            memory.pages[0].as_ref().unwrap(),
+            copy.pages[0].as_ref().unwrap()
+        ));
+        assert_eq!(memory.get(1), 0);
+        assert_eq!(copy.get(1), 4);
+    }
+}
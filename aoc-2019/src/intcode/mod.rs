@@ -0,0 +1,28 @@
+//!mod.rs
+
+// Intcode virtual machine shared by all Intcode days of 2019 (days 05, 07, 09, 11, 13, 15,
+// 17, 19, 21, 23 and the day 25 tui).
+
+mod ascii;
+mod assembler;
+mod computer;
+mod debugger;
+mod disassembler;
+mod error;
+mod hang;
+mod instruction;
+mod memory;
+mod network;
+mod snapshot;
+
+pub use ascii::*;
+pub use assembler::*;
+pub use computer::*;
+pub use debugger::*;
+pub use disassembler::*;
+pub use error::*;
+pub use hang::*;
+pub use instruction::*;
+pub use memory::*;
+pub use network::*;
+pub use snapshot::*;
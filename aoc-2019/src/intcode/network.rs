@@ -0,0 +1,311 @@
+//!network.rs
+
+// Deterministic network of Intcode computers. All computers run in the current thread and
+// are scheduled round-robin in address order, each for a fixed time slice. Therefore the
+// result of a network does not depend on timing.
+
+use super::{IntCodeComputer, IntcodeError, RunState};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+/// Default number of instructions a computer may execute, before the next one is scheduled.
+pub const DEFAULT_TIME_SLICE: usize = 1_000;
+/// Computer is idle, if it polled empty input this many times in a row.
+const IDLE_POLLS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Packet {
+    pub source: i64,
+    pub destination: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} -> {}: ({}, {})",
+            self.source, self.destination, self.x, self.y
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkError {
+    /// Computer at `address` failed.
+    Intcode { address: i64, error: IntcodeError },
+    /// Computer at `address` halted.
+    Halted { address: i64 },
+    /// Packet is addressed to neither a computer nor the policy.
+    UnknownAddress { packet: Packet },
+    /// Network is idle and policy did not inject any packet.
+    Deadlock,
+}
+
+impl Display for NetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkError::Intcode { address, error } => {
+                write!(f, "computer {address} failed: {error}")
+            }
+            NetworkError::Halted { address } => write!(f, "unexpected halt of computer {address}"),
+            NetworkError::UnknownAddress { packet } => {
+                write!(f, "packet to unknown address: {packet}")
+            }
+            NetworkError::Deadlock => write!(f, "network is idle and no packet is injected"),
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+/// Action requested by a policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action<T> {
+    Continue,
+    /// Inject packet into network.
+    Send(Packet),
+    /// Stop network with result.
+    Stop(T),
+}
+
+/// Policy for special addresses, which are not served by a computer (e.g. the NAT of
+/// day 23 at address 255), and for idle network.
+pub trait NetworkPolicy {
+    type Output;
+    /// Returns true, if packets to `address` are received by policy.
+    fn handles(&self, address: i64) -> bool;
+    /// Receives packet send to a special address.
+    fn receive(&mut self, packet: Packet) -> Action<Self::Output>;
+    /// Called, if all computers are idle. Continue results in NetworkError::Deadlock.
+    fn idle(&mut self) -> Action<Self::Output> {
+        Action::Continue
+    }
+    /// Called for every packet, before it is routed.
+    fn trace(&mut self, _packet: &Packet) {}
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    computer: IntCodeComputer,
+    input: VecDeque<i64>,
+    output: Vec<i64>,
+    idle_polls: usize,
+}
+
+impl Node {
+    fn receive(&mut self, packet: &Packet) {
+        self.input.extend([packet.x, packet.y]);
+        self.idle_polls = 0;
+    }
+    fn is_idle(&self) -> bool {
+        self.idle_polls >= IDLE_POLLS && self.input.iter().all(|i| *i == -1)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    nodes: Vec<Node>,
+    time_slice: usize,
+}
+
+impl Network {
+    /// Boots `size` copies of `code` with addresses 0..size. Each computer receives its
+    /// address as first input. Computers polling empty input receive -1.
+    pub fn new(code: &IntCodeComputer, size: usize) -> Self {
+        let nodes = (0..size as i64)
+            .map(|address| {
+                let mut computer = code.clone();
+                computer.set_id(address);
+                Node {
+                    computer,
+                    input: VecDeque::from([address]),
+                    output: Vec::with_capacity(3),
+                    idle_polls: 0,
+                }
+            })
+            .collect();
+        Network {
+            nodes,
+            time_slice: DEFAULT_TIME_SLICE,
+        }
+    }
+    pub fn with_time_slice(mut self, time_slice: usize) -> Self {
+        self.time_slice = time_slice.max(1);
+        self
+    }
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+    /// Network is idle, if all computers repeatedly polled empty input.
+    pub fn is_idle(&self) -> bool {
+        self.nodes.iter().all(|node| node.is_idle())
+    }
+    /// Runs network until policy stops it.
+    pub fn run<P: NetworkPolicy>(&mut self, policy: &mut P) -> Result<P::Output, NetworkError> {
+        loop {
+            if let Some(output) = self.run_round(policy)? {
+                return Ok(output);
+            }
+            if self.is_idle() {
+                match policy.idle() {
+                    Action::Continue => return Err(NetworkError::Deadlock),
+                    Action::Send(packet) => {
+                        if let Some(output) = self.route(packet, policy)? {
+                            return Ok(output);
+                        }
+                    }
+                    Action::Stop(output) => return Ok(output),
+                }
+            }
+        }
+    }
+    /// Schedules every computer once in address order and routes completed packets.
+    pub fn run_round<P: NetworkPolicy>(
+        &mut self,
+        policy: &mut P,
+    ) -> Result<Option<P::Output>, NetworkError> {
+        for index in 0..self.nodes.len() {
+            let address = index as i64;
+            let node = &mut self.nodes[index];
+            let state = node
+                .computer
+                .run_cooperative(&mut node.input, self.time_slice)
+                .map_err(|error| NetworkError::Intcode { address, error })?;
+            match state {
+                RunState::Output(value) => {
+                    node.idle_polls = 0;
+                    node.output.push(value);
+                    if let [destination, x, y] = node.output[..] {
+                        node.output.clear();
+                        let packet = Packet {
+                            source: address,
+                            destination,
+                            x,
+                            y,
+                        };
+                        if let Some(output) = self.route(packet, policy)? {
+                            return Ok(Some(output));
+                        }
+                    }
+                }
+                RunState::BlockedOnInput => {
+                    node.idle_polls += 1;
+                    node.input.push_back(-1);
+                }
+                RunState::Yielded => (),
+                RunState::Halted => return Err(NetworkError::Halted { address }),
+            }
+        }
+        Ok(None)
+    }
+    fn route<P: NetworkPolicy>(
+        &mut self,
+        packet: Packet,
+        policy: &mut P,
+    ) -> Result<Option<P::Output>, NetworkError> {
+        let mut packet = packet;
+        loop {
+            policy.trace(&packet);
+            if policy.handles(packet.destination) {
+                match policy.receive(packet) {
+                    Action::Continue => return Ok(None),
+                    // policy forwards packet
+                    Action::Send(forward) => packet = forward,
+                    Action::Stop(output) => return Ok(Some(output)),
+                }
+            } else {
+                let node = usize::try_from(packet.destination)
+                    .ok()
+                    .and_then(|index| self.nodes.get_mut(index))
+                    .ok_or(NetworkError::UnknownAddress { packet })?;
+                node.receive(&packet);
+                return Ok(None);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each computer sends one packet (address, address * 10) to address + 1 and afterward
+    // polls input forever.
+    const RELAY: &str = "3,100,1001,100,1,101,4,101,4,100,1002,100,10,102,4,102,3,103,1105,1,16";
+
+    struct LastAddress {
+        stop: bool,
+        trace: Vec<Packet>,
+        idle_calls: usize,
+    }
+
+    impl NetworkPolicy for LastAddress {
+        type Output = Packet;
+        fn handles(&self, address: i64) -> bool {
+            address == 3
+        }
+        fn receive(&mut self, packet: Packet) -> Action<Packet> {
+            if self.stop {
+                Action::Stop(packet)
+            } else {
+                Action::Continue
+            }
+        }
+        fn idle(&mut self) -> Action<Packet> {
+            self.idle_calls += 1;
+            Action::Continue
+        }
+        fn trace(&mut self, packet: &Packet) {
+            self.trace.push(*packet);
+        }
+    }
+
+    #[test]
+    fn test_network() {
+        let code = IntCodeComputer::from(RELAY);
+        let mut policy = LastAddress {
+            stop: true,
+            trace: Vec::new(),
+            idle_calls: 0,
+        };
+        let mut network = Network::new(&code, 3);
+        let packet = network.run(&mut policy).unwrap();
+        assert_eq!(
+            packet,
+            Packet {
+                source: 2,
+                destination: 3,
+                x: 2,
+                y: 20
+            }
+        );
+        assert_eq!(
+            policy.trace.iter().map(|p| p.source).collect::<Vec<_>>(),
+            [0, 1, 2]
+        );
+
+        // policy does not stop network and does not inject packets
+        policy.stop = false;
+        let mut network = Network::new(&code, 3).with_time_slice(1);
+        assert_eq!(network.run(&mut policy), Err(NetworkError::Deadlock));
+        assert!(network.is_idle());
+        assert_eq!(policy.idle_calls, 1);
+        assert_eq!(policy.trace.len(), 6);
+
+        // computer 3 sends to address 4
+        let mut network = Network::new(&code, 4);
+        assert_eq!(
+            network.run(&mut policy),
+            Err(NetworkError::UnknownAddress {
+                packet: Packet {
+                    source: 3,
+                    destination: 4,
+                    x: 3,
+                    y: 30
+                }
+            })
+        );
+    }
+}
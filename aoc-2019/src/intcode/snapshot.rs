@@ -0,0 +1,133 @@
+//!snapshot.rs
+
+// Snapshots of Intcode computers for search based solvers. Memory of a snapshot shares its
+// pages copy-on-write with the computer, therefore taking a snapshot costs about as much as
+// cloning the page table.
+
+use super::IntCodeComputer;
+use std::collections::VecDeque;
+use std::hash::Hasher;
+
+/// Saved state of a computer including pending input and not yet consumed output.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    computer: IntCodeComputer,
+    input: VecDeque<i64>,
+    output: Vec<i64>,
+}
+
+impl Snapshot {
+    pub fn input(&self) -> &VecDeque<i64> {
+        &self.input
+    }
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+    /// Stable hash of computer state and pending I/O. Without pending I/O it is equal to
+    /// state_hash() of the computer.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StableHasher::default();
+        self.computer.hash_state(&mut hasher);
+        if self.input.is_empty() && self.output.is_empty() {
+            return hasher.finish();
+        }
+        hasher.write_i64(self.input.len() as i64);
+        self.input.iter().for_each(|i| hasher.write_i64(*i));
+        hasher.write_i64(self.output.len() as i64);
+        self.output.iter().for_each(|o| hasher.write_i64(*o));
+        hasher.finish()
+    }
+}
+
+impl IntCodeComputer {
+    pub fn snapshot(&self) -> Snapshot {
+        self.snapshot_with_io(&VecDeque::new(), &[])
+    }
+    pub fn snapshot_with_io(&self, input: &VecDeque<i64>, output: &[i64]) -> Snapshot {
+        Snapshot {
+            computer: self.clone(),
+            input: input.clone(),
+            output: output.to_vec(),
+        }
+    }
+    /// Restores computer state of snapshot. Pending I/O is left to the caller.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        *self = snapshot.computer.clone();
+    }
+    /// Hash of pc, relative base and memory, which is stable between runs and does not
+    /// depend on how memory is stored. Use it to de-duplicate program states in searches.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StableHasher::default();
+        self.hash_state(&mut hasher);
+        hasher.finish()
+    }
+    fn hash_state(&self, hasher: &mut StableHasher) {
+        hasher.write_i64(self.pc());
+        hasher.write_i64(self.relative_base());
+        for (address, value) in self.memory.cells() {
+            hasher.write_i64(address);
+            hasher.write_i64(value);
+        }
+    }
+}
+
+/// FNV-1a hash, which in contrast to DefaultHasher is stable between runs and versions.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    fn write_i64(&mut self, i: i64) {
+        // fixed byte order makes hash independent of platform
+        self.write(&i.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot() {
+        // counter: outputs 1, 2, 3, ... forever
+        let mut code = IntCodeComputer::from("1001,100,1,100,4,100,1105,1,0");
+        let start = code.state_hash();
+        assert_eq!(code.run_int_code(&[]), Ok(Some(1)));
+        let snapshot = code.snapshot();
+        assert_eq!(snapshot.state_hash(), code.state_hash());
+        assert_ne!(code.state_hash(), start);
+        assert_eq!(code.run_int_code(&[]), Ok(Some(2)));
+        assert_eq!(code.run_int_code(&[]), Ok(Some(3)));
+
+        code.restore(&snapshot);
+        assert_eq!(code.state_hash(), snapshot.state_hash());
+        assert_eq!(code.run_int_code(&[]), Ok(Some(2)));
+
+        // hash does not depend on memory representation
+        let hash = code.state_hash();
+        code.use_sparse_memory();
+        assert_eq!(code.state_hash(), hash);
+
+        // pending I/O is part of snapshot
+        let mut input = VecDeque::from([7]);
+        let with_io = code.snapshot_with_io(&input, &[2]);
+        assert_eq!(with_io.input(), &input);
+        assert_eq!(with_io.output(), [2]);
+        assert_ne!(with_io.state_hash(), code.snapshot().state_hash());
+        input.clear();
+        assert_eq!(with_io.input().len(), 1);
+    }
+}
@@ -2,43 +2,55 @@
 
 pub mod days;
 
+pub mod intcode;
+
 pub mod day_25_tui;
 
-use anyhow::Result;
+pub mod visualizer;
+
+pub mod bench;
 
-pub fn run() -> Result<()> {
-    days::day_01::solution()?;
-    days::day_02::solution()?;
-    days::day_03::solution()?;
-    days::day_04::solution()?;
-    days::day_05::solution()?;
-    days::day_06::solution()?;
-    days::day_07::solution()?;
-    days::day_08::solution()?;
-    days::day_09::solution()?;
-    days::day_10::solution()?;
-    days::day_11::solution()?;
-    days::day_12::solution()?;
-    days::day_13::solution()?;
-    days::day_14::solution()?;
-    days::day_15::solution()?;
-    days::day_16::solution()?;
-    days::day_17::solution()?;
-    #[cfg(any(feature = "long-run-time", test))]
-    days::day_18::solution()?;
-    #[cfg(not(feature = "long-run-time"))]
-    {
-        println!("day 18 part 1 and 2 skipped because of long run time")
-    }
-    days::day_19::solution()?;
-    days::day_20::solution()?;
-    days::day_21::solution()?;
-    days::day_22::solution()?;
-    days::day_23::solution()?;
-    days::day_24::solution()?;
-    println!(
-        "Use day_25_tui to solve day 25. Hint: the crawler in the tui solves it automatically for you. Happy Christmas🎄🎁🎁🎁"
-    );
+use aoc_common::Registry;
+use bench::SparseMemory;
 
-    Ok(())
+/// Solvers of all days of 2019.
+///
+/// Day 25 is solved headless by the crawler engine of day 25. To play it interactively use
+/// binary day_25_tui. Hint: the crawler in the tui solves it automatically for you. Happy
+/// Christmas🎄🎁🎁🎁
+///
+/// Variant "sparse_memory" of days 09, 13 and 23 runs the Intcode computer with a HashMap as
+/// memory to benchmark paged memory.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<days::day_01::ChallengeInput>(2019, 1)
+        .register::<days::day_02::ChallengeInput>(2019, 2)
+        .register::<days::day_03::ChallengeInput>(2019, 3)
+        .register::<days::day_04::ChallengeInput>(2019, 4)
+        .register::<days::day_05::ChallengeInput>(2019, 5)
+        .register::<days::day_06::ChallengeInput>(2019, 6)
+        .register::<days::day_07::ChallengeInput>(2019, 7)
+        .register::<days::day_08::ChallengeInput<25, 6>>(2019, 8)
+        .register::<days::day_09::ChallengeInput>(2019, 9)
+        .register::<days::day_10::ChallengeInput>(2019, 10)
+        .register::<days::day_11::ChallengeInput>(2019, 11)
+        .register::<days::day_12::ChallengeInput>(2019, 12)
+        .register::<days::day_13::ChallengeInput>(2019, 13)
+        .register::<days::day_14::ChallengeInput>(2019, 14)
+        .register::<days::day_15::ChallengeInput>(2019, 15)
+        .register::<days::day_16::ChallengeInput>(2019, 16)
+        .register::<days::day_17::ChallengeInput>(2019, 17)
+        .register::<days::day_18::ChallengeInput<81, 81>>(2019, 18)
+        .register::<days::day_19::ChallengeInput>(2019, 19)
+        .register::<days::day_20::ChallengeInput<107, 109>>(2019, 20)
+        .register::<days::day_21::ChallengeInput>(2019, 21)
+        .register::<days::day_22::ChallengeInput>(2019, 22)
+        .register::<days::day_23::ChallengeInput>(2019, 23)
+        .register::<days::day_24::ChallengeInput>(2019, 24)
+        .register::<days::day_25::ChallengeInput>(2019, 25)
+        .register_variant::<SparseMemory<days::day_09::ChallengeInput>>(2019, 9, "sparse_memory")
+        .register_variant::<SparseMemory<days::day_13::ChallengeInput>>(2019, 13, "sparse_memory")
+        .register_variant::<SparseMemory<days::day_23::ChallengeInput>>(2019, 23, "sparse_memory");
+    registry
 }
@@ -0,0 +1,91 @@
+// breakout board of day 13 with autopilot or manual play
+
+use super::Visualization;
+use crate::days::day_13::{Arcade, BLOCK, WALL};
+use crate::intcode::IntCodeComputer;
+use ratatui::{
+    crossterm::event::KeyCode,
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+pub struct ArcadeVisualization {
+    arcade: Arcade,
+    started: bool,
+    manual: bool,
+    /// joystick of manual play, which is reset after each frame
+    joystick: i64,
+}
+
+impl From<IntCodeComputer> for ArcadeVisualization {
+    fn from(code: IntCodeComputer) -> Self {
+        ArcadeVisualization {
+            arcade: Arcade::free_play(code),
+            started: false,
+            manual: false,
+            joystick: 0,
+        }
+    }
+}
+
+impl Visualization for ArcadeVisualization {
+    fn title(&self) -> &'static str {
+        "AOC 2019 Day 13 Arcade"
+    }
+    fn step(&mut self) -> anyhow::Result<bool> {
+        let joystick = if !self.started {
+            self.started = true;
+            None
+        } else if self.manual {
+            Some(std::mem::take(&mut self.joystick))
+        } else {
+            Some(self.arcade.autopilot())
+        };
+        self.arcade.frame(joystick)
+    }
+    fn board(&self) -> Vec<Line<'static>> {
+        let width = self.arcade.tiles.keys().map(|p| p.x).max().unwrap_or(0) + 1;
+        let height = self.arcade.tiles.keys().map(|p| p.y).max().unwrap_or(0) + 1;
+        (0..height)
+            .map(|y| {
+                Line::from(
+                    (0..width)
+                        .map(|x| {
+                            let point = (x, y).into();
+                            let (symbol, color) = if point == self.arcade.ball {
+                                ("●", Color::Yellow)
+                            } else if point == self.arcade.paddle {
+                                ("▬", Color::Magenta)
+                            } else {
+                                match self.arcade.tiles.get(&point) {
+                                    Some(&WALL) => ("█", Color::Gray),
+                                    Some(&BLOCK) => ("▒", Color::Green),
+                                    _ => (" ", Color::Cyan),
+                                }
+                            };
+                            Span::styled(symbol, Style::new().fg(color))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect()
+    }
+    fn status(&self) -> Vec<String> {
+        vec![
+            format!("Score: {}", self.arcade.score),
+            format!("Blocks left: {}", self.arcade.blocks()),
+            format!("Mode: {}", if self.manual { "manual" } else { "autopilot" }),
+        ]
+    }
+    fn help(&self) -> &'static str {
+        "Toggle manual play with m.\nMove paddle with ◄ / Left and ► / Right."
+    }
+    fn handle_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('m') => self.manual = !self.manual,
+            KeyCode::Left if self.manual => self.joystick = -1,
+            KeyCode::Right if self.manual => self.joystick = 1,
+            _ => (),
+        }
+    }
+}
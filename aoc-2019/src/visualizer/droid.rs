@@ -0,0 +1,71 @@
+// exploration of day 15 by repair droid with frontier of breadth first search
+
+use super::Visualization;
+use crate::days::day_15::{Explorer, OXYGEN_SYSTEM, WALL};
+use crate::intcode::IntCodeComputer;
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+use std::collections::HashSet;
+
+pub struct DroidVisualization {
+    explorer: Explorer,
+}
+
+impl From<IntCodeComputer> for DroidVisualization {
+    fn from(code: IntCodeComputer) -> Self {
+        DroidVisualization {
+            explorer: Explorer::from(code),
+        }
+    }
+}
+
+impl Visualization for DroidVisualization {
+    fn title(&self) -> &'static str {
+        "AOC 2019 Day 15 Repair Droid"
+    }
+    fn step(&mut self) -> anyhow::Result<bool> {
+        self.explorer.step()
+    }
+    fn board(&self) -> Vec<Line<'static>> {
+        let map = self.explorer.map();
+        let frontier: HashSet<_> = self.explorer.frontier().collect();
+        let min_x = map.keys().map(|p| p.x).min().unwrap_or(0);
+        let max_x = map.keys().map(|p| p.x).max().unwrap_or(0);
+        let min_y = map.keys().map(|p| p.y).min().unwrap_or(0);
+        let max_y = map.keys().map(|p| p.y).max().unwrap_or(0);
+        (min_y..=max_y)
+            .map(|y| {
+                Line::from(
+                    (min_x..=max_x)
+                        .map(|x| {
+                            let point = (x, y).into();
+                            let (symbol, color) = match map.get(&point) {
+                                _ if point == self.explorer.current() => ("D", Color::Yellow),
+                                Some(cell) if cell.status == OXYGEN_SYSTEM => ("O", Color::Red),
+                                _ if frontier.contains(&point) => ("?", Color::Magenta),
+                                Some(_) if x == 0 && y == 0 => ("S", Color::Green),
+                                Some(cell) if cell.status == WALL => ("█", Color::Gray),
+                                Some(_) => ("·", Color::Cyan),
+                                None => (" ", Color::Cyan),
+                            };
+                            Span::styled(symbol, Style::new().fg(color))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect()
+    }
+    fn status(&self) -> Vec<String> {
+        let map = self.explorer.map();
+        let mut status = vec![
+            format!("Explored cells: {}", map.len()),
+            format!("Frontier: {}", self.explorer.frontier().count()),
+        ];
+        if let Some(cell) = map.values().find(|c| c.status == OXYGEN_SYSTEM) {
+            status.push(format!("Oxygen system: {} steps", cell.distance));
+        }
+        status
+    }
+}
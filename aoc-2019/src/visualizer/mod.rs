@@ -0,0 +1,141 @@
+// live terminal visualizers of Intcode days 13 and 15
+
+mod arcade;
+mod droid;
+
+pub use arcade::*;
+pub use droid::*;
+
+use color_eyre::eyre::eyre;
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Paragraph},
+};
+use std::time::{Duration, Instant};
+
+const DEFAULT_DELAY: Duration = Duration::from_millis(40);
+const MIN_DELAY: Duration = Duration::from_millis(5);
+const MAX_DELAY: Duration = Duration::from_millis(1_280);
+
+/// Simulation, which is advanced step by step and rendered after each step.
+pub trait Visualization {
+    fn title(&self) -> &'static str;
+    /// Advances one step. Returns false, if simulation is finished.
+    fn step(&mut self) -> anyhow::Result<bool>;
+    /// Board of simulation.
+    fn board(&self) -> Vec<Line<'static>>;
+    /// Lines with current state, e.g. score.
+    fn status(&self) -> Vec<String>;
+    /// Help for keys handled by handle_key().
+    fn help(&self) -> &'static str {
+        ""
+    }
+    /// Keys not used for pause, step, speed and quit.
+    fn handle_key(&mut self, _code: KeyCode) {}
+}
+
+#[derive(Debug)]
+struct Controls {
+    paused: bool,
+    delay: Duration,
+    finished: bool,
+    steps: usize,
+}
+
+/// Runs visualization until user quits.
+pub fn run<V: Visualization>(
+    mut terminal: DefaultTerminal,
+    mut visualization: V,
+) -> color_eyre::Result<()> {
+    let mut controls = Controls {
+        paused: false,
+        delay: DEFAULT_DELAY,
+        finished: false,
+        steps: 0,
+    };
+    let mut last_step = Instant::now();
+    loop {
+        terminal.draw(|frame| ui(frame, &visualization, &controls))?;
+        // without pending step wait full delay for keys instead of redrawing immediately
+        let timeout = if controls.paused || controls.finished {
+            controls.delay
+        } else {
+            controls.delay.saturating_sub(last_step.elapsed())
+        };
+        let mut step = false;
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('q') => return Ok(()),
+                    KeyCode::Char('c' | 'C') if key.modifiers == KeyModifiers::CONTROL => {
+                        return Ok(());
+                    }
+                    KeyCode::Char(' ') => controls.paused = !controls.paused,
+                    KeyCode::Char('n') if controls.paused => step = true,
+                    KeyCode::Char('+') => controls.delay = (controls.delay / 2).max(MIN_DELAY),
+                    KeyCode::Char('-') => controls.delay = (controls.delay * 2).min(MAX_DELAY),
+                    code => visualization.handle_key(code),
+                }
+            }
+        } else if !controls.paused {
+            step = true;
+        }
+        if step && !controls.finished {
+            controls.finished = !visualization.step().map_err(|err| eyre!("{err}"))?;
+            controls.steps += 1;
+            last_step = Instant::now();
+        }
+    }
+}
+
+fn ui<V: Visualization>(frame: &mut Frame, visualization: &V, controls: &Controls) {
+    let layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(50), Constraint::Length(40)])
+        .split(frame.area());
+
+    let block = Block::bordered()
+        .title(format!(" {} ", visualization.title()))
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded);
+    let board = Paragraph::new(visualization.board())
+        .block(block)
+        .fg(Color::Cyan)
+        .bg(Color::Black);
+    frame.render_widget(board, layout[0]);
+
+    let state = if controls.finished {
+        "finished"
+    } else if controls.paused {
+        "paused"
+    } else {
+        "running"
+    };
+    let mut text = vec![
+        Line::from(format!("State: {state}")),
+        Line::from(format!("Steps: {}", controls.steps)),
+        Line::from(format!("Delay: {} ms", controls.delay.as_millis())),
+    ];
+    text.extend(visualization.status().into_iter().map(Line::from));
+    text.push(Line::from(""));
+    text.push(Line::from("Pause / resume with Space."));
+    text.push(Line::from("Single step with n, if paused."));
+    text.push(Line::from("Faster with +, slower with -."));
+    text.extend(visualization.help().lines().map(Line::from));
+    text.push(Line::from("Press `Esc`, `Ctrl-C` or `q` to stop running."));
+    let block = Block::bordered()
+        .title(" Status ")
+        .title_alignment(Alignment::Left)
+        .border_type(BorderType::Rounded);
+    let status = Paragraph::new(text)
+        .block(block)
+        .fg(Color::Cyan)
+        .bg(Color::Black);
+    frame.render_widget(status, layout[1]);
+}
@@ -1,10 +1,12 @@
 //!day_03.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::HashSet;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     trees: HashSet<Point>,
     size_right: i64,
     size_down: i64,
@@ -58,29 +60,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_03.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_03 part 1: {result_part1}");
-    assert_eq!(result_part1, 156);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_03 part 2: {result_part2}");
-    assert_eq!(result_part2, 3_521_829_480);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_03() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_03_example.txt");
+        let input = read_file(2020, "day_03_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,7 +1,9 @@
 //!day_04.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 struct Passport {
     fields: HashMap<String, String>,
@@ -74,7 +76,7 @@ fn check_digit_value_range(digit_str: &str, min: u64, max: u64) -> bool {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     passports: Vec<Passport>,
 }
 
@@ -95,29 +97,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_04.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_04 part 1: {result_part1}");
-    assert_eq!(result_part1, 196);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_04 part 2: {result_part2}");
-    assert_eq!(result_part2, 114);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_04() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_04_example.txt");
+        let input = read_file(2020, "day_04_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,6 +1,8 @@
 //!day_05.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 struct BoardingPass {
     raw: String,
@@ -47,7 +49,7 @@ impl BoardingPass {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     boarding_pass: Vec<BoardingPass>,
 }
 
@@ -84,36 +86,36 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_05.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_05 part 1: {result_part1}");
-    assert_eq!(result_part1, 813);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_05 part 2: {result_part2}");
-    assert_eq!(result_part2, 612);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_05() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_05_example.txt");
+        let input = read_file(2020, "day_05_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
         println!("result day_05 part 1: {result_part1}");
         assert_eq!(result_part1, 820);
 
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_05.txt");
+        let input = read_file(2020, "day_05.txt")?;
         let mut challenge = ChallengeInput::from(input);
         challenge.solution_part_1();
 
@@ -1,7 +1,13 @@
 //!day_08.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use aoc_common::register_machine::{Flow, InstructionSet, Machine, Status};
 use std::collections::HashSet;
+use std::fmt::Display;
+
+// accumulator is register 'a' of register machine
+const ACCUMULATOR: char = 'a';
 
 #[derive(Debug, Clone, Copy)]
 enum Instruction {
@@ -23,6 +29,21 @@ impl From<&str> for Instruction {
     }
 }
 
+impl InstructionSet for Instruction {
+    type State = ();
+
+    fn execute(self, machine: &mut Machine<Self>) -> Flow {
+        match self {
+            Instruction::Acc(val) => {
+                machine.registers[ACCUMULATOR] += val;
+                Flow::NEXT
+            }
+            Instruction::Jmp(val) => Flow::Jump(val),
+            Instruction::Nop(_) => Flow::NEXT,
+        }
+    }
+}
+
 impl Instruction {
     fn is_acc(&self) -> bool {
         matches!(self, Instruction::Acc(_))
@@ -36,7 +57,7 @@ impl Instruction {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     instructions: Vec<Instruction>,
 }
 
@@ -71,63 +92,50 @@ impl ChallengeInput {
         panic!("could not identify switch index")
     }
     fn get_acc(&self, switch_index: i64) -> Result<i64, i64> {
-        let mut index = 0;
+        let mut program = self.instructions.clone();
+        if let Some(instruction) = program.get_mut(switch_index as usize) {
+            *instruction = instruction.switch();
+        }
+        let mut machine = Machine::new(program);
         let mut seen: HashSet<i64> = HashSet::new();
-        let mut acc = 0;
-        while seen.insert(index) {
-            let Some(instruction) = self.instructions.get(index as usize) else {
-                // index out of valid range
-                if switch_index == -1 {
-                    panic!("index out of range. should not happen in part 1.")
-                }
-                return Err(acc);
-            };
-            let instruction = if index == switch_index {
-                instruction.switch()
-            } else {
-                *instruction
-            };
-            match instruction {
-                Instruction::Acc(val) => {
-                    acc += val;
-                    index += 1;
-                }
-                Instruction::Jmp(val) => index += val,
-                Instruction::Nop(_) => index += 1,
-            }
-            if (index as usize) == self.instructions.len() {
-                // reached end of instruction list
-                return Ok(acc);
+        let status = machine.run_traced(u64::MAX, |m| seen.insert(m.pc));
+        let acc = machine.registers[ACCUMULATOR];
+        match status {
+            // reached end of instruction list
+            Status::Halted if machine.pc as usize == self.instructions.len() => Ok(acc),
+            // index out of valid range
+            Status::Halted if switch_index == -1 => {
+                panic!("index out of range. should not happen in part 1.")
             }
+            // loop detected, return acc before next loop
+            _ => Err(acc),
         }
-        // loop detected, return acc before next loop
-        Err(acc)
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_08.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_08 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_782);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_08 part 2: {result_part2}");
-    assert_eq!(result_part2, 797);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_08() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_08_example.txt");
+        let input = read_file(2020, "day_08_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,8 +1,10 @@
 //!day_09.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     numbers: Vec<u64>,
     block_size: usize,
 }
@@ -65,29 +67,30 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_09.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_09 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_930_745_883);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2(result_part1);
-    println!("result day_09 part 2: {result_part2}");
-    assert_eq!(result_part2, 268_878_261);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let invalid_num = self.solution_part_1();
+        Ok(self.solution_part_2(invalid_num))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_09() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_09_example.txt");
+        let input = read_file(2020, "day_09_example.txt")?;
         let mut example = ChallengeInput::from(input);
         example.block_size = 5;
 
@@ -1,9 +1,11 @@
 //!day_10.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     adapters: Vec<u64>,
 }
 
@@ -57,29 +59,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_10.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_10 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_836);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_10 part 2: {result_part2}");
-    assert_eq!(result_part2, 43_406_276_662_336);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_10() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_10_example.txt");
+        let input = read_file(2020, "day_10_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
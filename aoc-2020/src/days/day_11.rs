@@ -1,9 +1,11 @@
 //!day_11.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_two_dim::MyMap2D};
+use std::fmt::Display;
 
-struct ChallengeInput<const X: usize, const Y: usize> {
+pub struct ChallengeInput<const X: usize, const Y: usize> {
     seats: MyMap2D<char, X, Y>,
 }
 
@@ -80,29 +82,29 @@ impl<const X: usize, const Y: usize> ChallengeInput<X, Y> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_11.txt");
-    let challenge = ChallengeInput::<91, 90>::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_11 part 1: {result_part1}");
-    assert_eq!(result_part1, 2_113);
+impl<const X: usize, const Y: usize> Solution for ChallengeInput<X, Y> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_11 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_865);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_11() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_11_example.txt");
+        let input = read_file(2020, "day_11_example.txt")?;
         let example = ChallengeInput::<10, 10>::from(input);
 
         let result_part1 = example.solution_part_1();
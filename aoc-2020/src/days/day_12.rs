@@ -1,10 +1,12 @@
 //!day_12.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{
     my_compass::Compass,
     my_geometry::my_point::{Point, Turns90},
 };
+use std::fmt::Display;
 
 enum Command {
     North(i64),
@@ -121,7 +123,7 @@ impl Command {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     commands: Vec<Command>,
 }
 
@@ -152,29 +154,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_12.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_12 part 1: {result_part1}");
-    assert_eq!(result_part1, 796);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_12 part 2: {result_part2}");
-    assert_eq!(result_part2, 39_446);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_12() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_12_example.txt");
+        let input = read_file(2020, "day_12_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
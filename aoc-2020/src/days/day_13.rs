@@ -1,9 +1,11 @@
 //!day_13.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_algo_collection::{egcd, gcd};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     my_timestamp: i64,
     busses: Vec<(i64, i64)>,
 }
@@ -94,25 +96,25 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_13.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_13 part 1: {result_part1}");
-    assert_eq!(result_part1, 410);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_13 part 2: {result_part2}");
-    assert_eq!(result_part2, 600_691_418_730_595);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     // with a LOOOOOOT of help of ChatGPT
     #[test]
@@ -132,7 +134,7 @@ mod tests {
 
     #[test]
     fn test_example_day_13() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_13_example.txt");
+        let input = read_file(2020, "day_13_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
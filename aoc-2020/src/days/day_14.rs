@@ -1,7 +1,9 @@
 //!day_14.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, Default)]
 struct BitMask {
@@ -120,7 +122,7 @@ impl MemAction {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     mem_actions: Vec<MemAction>,
 }
 
@@ -151,36 +153,36 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_14.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_14 part 1: {result_part1}");
-    assert_eq!(result_part1, 3_059_488_894_985);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_14 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_900_994_392_308);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_14() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_14_example_1.txt");
+        let input = read_file(2020, "day_14_example_1.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
         println!("result day_14 part 1: {result_part1}");
         assert_eq!(result_part1, 165);
 
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_14_example_2.txt");
+        let input = read_file(2020, "day_14_example_2.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part2 = example.solution_part_2();
@@ -1,9 +1,12 @@
 //!day_15.rs
 
 use anyhow::Result;
+use aoc_common::{LONG_RUNTIME, Solution};
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::Duration;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     numbers: Vec<u64>,
 }
 
@@ -19,7 +22,6 @@ impl ChallengeInput {
     fn solution_part_1(&self) -> u64 {
         self.get_nth_num(2020)
     }
-    #[cfg(any(feature = "long-run-time", test))]
     fn solution_part_2(&self) -> u64 {
         // there is probably some clever solution, but we brute force it
         self.get_nth_num(30_000_000)
@@ -61,36 +63,31 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_15.txt");
-    let challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_15 part 1: {result_part1}");
-    assert_eq!(result_part1, 260);
-
-    #[cfg(any(feature = "long-run-time", test))]
-    {
-        let result_part2 = challenge.solution_part_2();
-        println!("result day_15 part 2: {result_part2}");
-        assert_eq!(result_part2, 950);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
     }
-    #[cfg(not(feature = "long-run-time"))]
-    {
-        println!("day 15 part 2 skipped because of long run time")
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
     }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_15() -> Result<()> {
-        let multi_input = include_str!("../../../../aoc_input/aoc-2020/day_15_example.txt");
+        let multi_input = read_file(2020, "day_15_example.txt")?;
         let solutions = [
             (436, 175594),
             (1, 2578),
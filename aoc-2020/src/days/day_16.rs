@@ -1,7 +1,9 @@
 //!day_16.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct ValueRange {
@@ -47,7 +49,7 @@ impl RangePair {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     ticket_rules: HashMap<String, RangePair>,
     my_ticket: Vec<u64>,
     nearby_tickets: Vec<Vec<u64>>,
@@ -164,36 +166,36 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_16.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_16 part 1: {result_part1}");
-    assert_eq!(result_part1, 23_009);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_16 part 2: {result_part2}");
-    assert_eq!(result_part2, 10_458_887_314_153);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_16() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_16_example_1.txt");
+        let input = read_file(2020, "day_16_example_1.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
         println!("result day_16 part 1: {result_part1}");
         assert_eq!(result_part1, 71);
 
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_16_example_2.txt");
+        let input = read_file(2020, "day_16_example_2.txt")?;
         let example = ChallengeInput::from(input);
 
         let position_names = example.get_position_names();
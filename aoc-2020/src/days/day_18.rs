@@ -1,8 +1,10 @@
 //!day_18.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     expression_list: String,
     open: char,
     close: char,
@@ -125,29 +127,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_18.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_18 part 1: {result_part1}");
-    assert_eq!(result_part1, 9_535_936_849_815);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_18 part 2: {result_part2}");
-    assert_eq!(result_part2, 472_171_581_333_710);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_18() -> Result<()> {
-        let input_lines = include_str!("../../../../aoc_input/aoc-2020/day_18_example.txt");
+        let input_lines = read_file(2020, "day_18_example.txt")?;
         let solutions = [
             (71, 231),
             (51, 51),
@@ -1,7 +1,10 @@
 //!day_19.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::{LONG_RUNTIME, Solution};
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::Duration;
 
 enum Rule {
     A,
@@ -29,9 +32,10 @@ impl From<&str> for Rule {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     rules: HashMap<u64, Rule>,
     patterns: Vec<String>,
+    result_part2: Option<usize>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -44,6 +48,7 @@ impl From<&str> for ChallengeInput {
                 .map(|(n, r)| (n.parse().unwrap(), Rule::from(r)))
                 .collect(),
             patterns: patterns.lines().map(|l| l.to_string()).collect(),
+            result_part2: None,
         }
     }
 }
@@ -136,35 +141,42 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_19.txt");
-    let challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
 
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_19 part 1: {result_part1}");
-    assert_eq!(result_part1, 104);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_19 part 2: {result_part2}");
-    assert_eq!(result_part2, 314);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_19() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_19_example_1.txt");
+        let input = read_file(2020, "day_19_example_1.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, _) = example.solution_part_1_and_2();
         println!("result day_19 part 1: {result_part1}");
         assert_eq!(result_part1, 2);
 
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_19_example_2.txt");
+        let input = read_file(2020, "day_19_example_2.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
@@ -1,7 +1,10 @@
 //!day_20.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use aoc_common::read_file;
 use my_lib::{my_map_point::MapPoint, my_map_two_dim::MyMap2D};
+use std::fmt::Display;
 
 #[derive(Debug, Clone)]
 struct SeaMonster<const X: usize, const Y: usize> {
@@ -9,10 +12,10 @@ struct SeaMonster<const X: usize, const Y: usize> {
 }
 
 impl<const X: usize, const Y: usize> SeaMonster<X, Y> {
-    fn new() -> Self {
-        SeaMonster {
+    fn new() -> Result<Self> {
+        Ok(SeaMonster {
             // X: 20, Y: 3: size of initial sea monster, if rotate, X and Y switch values
-            offsets: include_str!("../../../../aoc_input/aoc-2020/day_20_sea_monster.txt")
+            offsets: read_file(2020, "day_20_sea_monster.txt")?
                 .lines()
                 .enumerate()
                 .flat_map(|(y, line)| {
@@ -21,7 +24,7 @@ impl<const X: usize, const Y: usize> SeaMonster<X, Y> {
                         .filter_map(move |(x, c)| (c == '#').then_some((x, y)))
                 })
                 .collect(),
-        }
+        })
     }
     fn rotate_clockwise(&self) -> Self {
         Self {
@@ -179,9 +182,10 @@ impl ImageTile {
     }
 }
 
-struct ChallengeInput<const X: usize> {
+pub struct ChallengeInput<const X: usize> {
     tiles: Vec<ImageTile>,
     image: MyMap2D<char, X, X>,
+    result_part2: Option<usize>,
 }
 
 impl<const X: usize> From<&str> for ChallengeInput<X> {
@@ -189,12 +193,13 @@ impl<const X: usize> From<&str> for ChallengeInput<X> {
         ChallengeInput {
             tiles: value.split("\n\n").map(ImageTile::from).collect(),
             image: MyMap2D::default(),
+            result_part2: None,
         }
     }
 }
 
 impl<const X: usize> ChallengeInput<X> {
-    fn solution_part_1_and_2(&mut self) -> (u64, usize) {
+    fn solution_part_1_and_2(&mut self) -> Result<(u64, usize)> {
         let corner_indices = self.search_corners();
         let result_part_1 = corner_indices
             .iter()
@@ -202,11 +207,11 @@ impl<const X: usize> ChallengeInput<X> {
             .product();
         // corner 1 results in upstanding monster
         self.build_image_from_corner(corner_indices[1]);
-        self.sea_monsters();
-        (
+        self.sea_monsters()?;
+        Ok((
             result_part_1,
             self.image.iter().filter(|(_, v)| **v == '#').count(),
-        )
+        ))
     }
     fn search_corners(&mut self) -> Vec<usize> {
         // set neighbors
@@ -304,8 +309,8 @@ impl<const X: usize> ChallengeInput<X> {
             }
         }
     }
-    fn sea_monsters(&mut self) {
-        let sea_monster = SeaMonster::<20, 3>::new();
+    fn sea_monsters(&mut self) -> Result<()> {
+        let sea_monster = SeaMonster::<20, 3>::new()?;
         // find rotation / flip of sea monster
         let mut sm_offsets: Vec<(usize, usize)> = Vec::new();
         'outer: for rot in 0..3 {
@@ -381,21 +386,26 @@ impl<const X: usize> ChallengeInput<X> {
                     self.image.set(sm_pos, 'O');
                 });
         }
+        Ok(())
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_20.txt");
-    let mut challenge = ChallengeInput::<96>::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_20 part 1: {result_part1}");
-    assert_eq!(result_part1, 64_802_175_715_999);
+impl<const X: usize> Solution for ChallengeInput<X> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_20 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_146);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2()?;
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
@@ -405,10 +415,10 @@ mod tests {
 
     #[test]
     fn test_example_day_20() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_20_example.txt");
+        let input = read_file(2020, "day_20_example.txt")?;
         let mut example = ChallengeInput::<24>::from(input);
 
-        let (result_part1, result_part2) = example.solution_part_1_and_2();
+        let (result_part1, result_part2) = example.solution_part_1_and_2()?;
         println!("result day_20 part 1: {result_part1}");
         assert_eq!(result_part1, 20_8990_4808_3289);
 
@@ -1,10 +1,13 @@
 //!day_21.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     food_list: Vec<(HashSet<String>, HashSet<String>)>,
+    result_part2: Option<String>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -24,6 +27,7 @@ impl From<&str> for ChallengeInput {
                     )
                 })
                 .collect(),
+            result_part2: None,
         }
     }
 }
@@ -79,31 +83,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_21.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_21 part 1: {result_part1}");
-    assert_eq!(result_part1, 2_412);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_21 part 2: {result_part2}");
-    assert_eq!(
-        result_part2,
-        "mfp,mgvfmvp,nhdjth,hcdchl,dvkbjh,dcvrf,bcjz,mhnrqp"
-    );
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_21() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_21_example.txt");
+        let input = read_file(2020, "day_21_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
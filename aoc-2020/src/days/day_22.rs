@@ -1,11 +1,14 @@
 //!day_22.rs
 
 use anyhow::Result;
-#[cfg(any(feature = "long-run-time", test))]
+use aoc_common::{LONG_RUNTIME, Solution};
 use std::collections::HashSet;
 use std::collections::VecDeque;
+use std::fmt::Display;
+use std::time::Duration;
 
-struct ChallengeInput {
+#[derive(Clone)]
+pub struct ChallengeInput {
     player_1: VecDeque<usize>,
     player_2: VecDeque<usize>,
 }
@@ -61,7 +64,6 @@ impl ChallengeInput {
             .map(|(i, num)| (winner.len() - i) * *num)
             .sum()
     }
-    #[cfg(any(feature = "long-run-time", test))]
     fn solution_part_2(&mut self) -> usize {
         let winner = if self.play_recursive() {
             &self.player_1
@@ -74,7 +76,6 @@ impl ChallengeInput {
             .map(|(i, num)| (winner.len() - i) * *num)
             .sum()
     }
-    #[cfg(any(feature = "long-run-time", test))]
     fn play_recursive(&mut self) -> bool {
         let mut cache: HashSet<(VecDeque<usize>, VecDeque<usize>)> = HashSet::new();
         while !self.player_1.is_empty() && !self.player_2.is_empty() {
@@ -108,37 +109,31 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_22.txt");
-    let mut challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_22 part 1: {result_part1}");
-    assert_eq!(result_part1, 33_680);
-
-    #[cfg(any(feature = "long-run-time", test))]
-    {
-        let mut challenge = ChallengeInput::from(input);
-        let result_part2 = challenge.solution_part_2();
-        println!("result day_22 part 2: {result_part2}");
-        assert_eq!(result_part2, 33_683);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
     }
-    #[cfg(not(feature = "long-run-time"))]
-    {
-        println!("day 22 part 2 skipped because of long run time")
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.clone().solution_part_1())
     }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_22() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_22_example.txt");
+        let input = read_file(2020, "day_22_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -4,11 +4,13 @@
 // ############################################################################
 
 use anyhow::Result;
+use aoc_common::Solution;
 use petgraph::{Direction, graphmap::DiGraphMap};
+use std::fmt::Display;
 use std::fmt::Write;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     cup_map: DiGraphMap<usize, ()>,
     current: usize,
     min_val: usize,
@@ -152,37 +154,38 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_23.txt");
-    let mut challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const EXPECTED_RUNTIME: Duration = Duration::from_secs(21);
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_23 part 1: {result_part1}");
-    assert_eq!(result_part1, "65432978");
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let mut challenge = ChallengeInput::from(input);
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_23 part 2: {result_part2}");
-    assert_eq!(result_part2, 287_230_227_046);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_23_petgraph() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_23_example.txt");
+        let input = read_file(2020, "day_23_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
         println!("result day_23 part 1: {result_part1}");
         assert_eq!(result_part1, "67384529");
 
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_23_example.txt");
+        let input = read_file(2020, "day_23_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part2 = example.solution_part_2();
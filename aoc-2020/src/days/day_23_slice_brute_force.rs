@@ -1,14 +1,16 @@
 //!day_23.rs
 // ############################################################################
-// # WARNING: this takes a lot of time to execute, see below in fn solution() #
+// # WARNING: this takes a lot of time to execute, see fn solution_part_2()    #
 // # I keep this stuff just for reference.                                    #
 // ############################################################################
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 use std::fmt::Write;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     cups: Vec<u64>,
     min_cup: u64,
     max_cup: u64,
@@ -42,6 +44,9 @@ impl ChallengeInput {
         });
         cups
     }
+    // part two takes with this "slice brute force" about 30 minutes on my notebook
+    // the reason for this is probably fn get_destination_index(), because it must iter
+    // through a million values to find destination. Do the 10 million times and it takes some time...
     fn solution_part_2(&mut self) -> u64 {
         self.cups.extend(self.max_cup + 1..=1_000_000);
         self.max_cup = 1_000_000;
@@ -122,33 +127,31 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_23.txt");
-    let mut challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const EXPECTED_RUNTIME: Duration = Duration::from_secs(30 * 60);
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_23 part 1: {result_part1}");
-    assert_eq!(result_part1, "65432978");
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    // part two takes with this "slice brute force" about 30 minutes on my notebook
-    // the reason for this is probably fn get_destination_index(), because it must iter
-    // through a million values to find destination. Do the 10 million times and it takes some time...
-    let mut challenge = ChallengeInput::from(input);
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_23 part 2: {result_part2}");
-    assert_eq!(result_part2, 287_230_227_046);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_23_slice_brute_force() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_23_example.txt");
+        let input = read_file(2020, "day_23_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
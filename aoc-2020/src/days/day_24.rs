@@ -1,8 +1,10 @@
 //!day_24.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point3D;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 const E: Point3D = Point3D { x: 1, y: -1, z: 0 };
 const W: Point3D = Point3D { x: -1, y: 1, z: 0 };
@@ -29,14 +31,16 @@ fn neighbors(hex: Point3D) -> impl Iterator<Item = Point3D> {
         .map(move |dir| hex.add(dir))
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     instructions: String,
+    result_part2: Option<usize>,
 }
 
 impl From<&str> for ChallengeInput {
     fn from(value: &str) -> Self {
         ChallengeInput {
             instructions: value.to_string(),
+            result_part2: None,
         }
     }
 }
@@ -104,28 +108,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_24.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_24 part 1: {result_part1}");
-    assert_eq!(result_part1, 354);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_24 part 2: {result_part2}");
-    assert_eq!(result_part2, 3_608);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_24() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_24_example.txt");
+        let input = read_file(2020, "day_24_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
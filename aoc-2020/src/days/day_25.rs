@@ -1,8 +1,10 @@
 //!day_25.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     card_public_key: u64,
     door_public_key: u64,
 }
@@ -49,25 +51,27 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2020/day_25.txt");
-    let challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const HAS_PART_2: bool = false;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_25 part 1: {result_part1}");
-    assert_eq!(result_part1, 297_257);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_25() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2020/day_25_example.txt");
+        let input = read_file(2020, "day_25_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -2,40 +2,42 @@
 
 pub mod days;
 
-use anyhow::Result;
+use aoc_common::Registry;
 
-pub fn run() -> Result<()> {
-    days::day_01::solution()?;
-    days::day_02::solution()?;
-    days::day_03::solution()?;
-    days::day_04::solution()?;
-    days::day_04::solution()?;
-    days::day_05::solution()?;
-    days::day_06::solution()?;
-    days::day_07::solution()?;
-    days::day_08::solution()?;
-    days::day_09::solution()?;
-    days::day_10::solution()?;
-    days::day_11::solution()?;
-    days::day_12::solution()?;
-    days::day_13::solution()?;
-    days::day_14::solution()?;
-    days::day_15::solution()?;
-    days::day_16::solution()?;
-    days::day_17::solution()?;
-    days::day_18::solution()?;
-    #[cfg(any(feature = "long-run-time", test))]
-    days::day_19::solution()?;
-    #[cfg(not(feature = "long-run-time"))]
-    {
-        println!("day 19 part 1 and 2 skipped because of long run time")
-    }
-    days::day_20::solution()?;
-    days::day_21::solution()?;
-    days::day_22::solution()?;
-    days::day_23::solution()?;
-    days::day_24::solution()?;
-    days::day_25::solution()?;
-
-    Ok(())
+/// Solvers of all days of 2020.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<days::day_01::ChallengeInput>(2020, 1)
+        .register::<days::day_02::ChallengeInput>(2020, 2)
+        .register::<days::day_03::ChallengeInput>(2020, 3)
+        .register::<days::day_04::ChallengeInput>(2020, 4)
+        .register::<days::day_05::ChallengeInput>(2020, 5)
+        .register::<days::day_06::ChallengeInput>(2020, 6)
+        .register::<days::day_07::ChallengeInput>(2020, 7)
+        .register::<days::day_08::ChallengeInput>(2020, 8)
+        .register::<days::day_09::ChallengeInput>(2020, 9)
+        .register::<days::day_10::ChallengeInput>(2020, 10)
+        .register::<days::day_11::ChallengeInput<91, 90>>(2020, 11)
+        .register::<days::day_12::ChallengeInput>(2020, 12)
+        .register::<days::day_13::ChallengeInput>(2020, 13)
+        .register::<days::day_14::ChallengeInput>(2020, 14)
+        .register::<days::day_15::ChallengeInput>(2020, 15)
+        .register::<days::day_16::ChallengeInput>(2020, 16)
+        .register::<days::day_17::ChallengeInput>(2020, 17)
+        .register::<days::day_18::ChallengeInput>(2020, 18)
+        .register::<days::day_19::ChallengeInput>(2020, 19)
+        .register::<days::day_20::ChallengeInput<96>>(2020, 20)
+        .register::<days::day_21::ChallengeInput>(2020, 21)
+        .register::<days::day_22::ChallengeInput>(2020, 22)
+        .register::<days::day_23::ChallengeInput>(2020, 23)
+        .register_variant::<days::day_23_petgraph::ChallengeInput>(2020, 23, "petgraph")
+        .register_variant::<days::day_23_slice_brute_force::ChallengeInput>(
+            2020,
+            23,
+            "slice_brute_force",
+        )
+        .register::<days::day_24::ChallengeInput>(2020, 24)
+        .register::<days::day_25::ChallengeInput>(2020, 25);
+    registry
 }
@@ -1,6 +1,8 @@
 //!day_01.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 fn count_increases(input: &str) -> usize {
     let previous = i64::MAX;
@@ -33,28 +35,33 @@ fn count_increases_sliding_window(input: &str, window_size: usize) -> usize {
     count
 }
 
-pub fn day_01() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_01.txt");
+pub struct Day01 {
+    input: &'static str,
+}
 
-    let result_part1 = count_increases(input);
-    println!("result day 01 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_527);
+impl Solution for Day01 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
 
-    let result_part2 = count_increases_sliding_window(input, 3);
-    println!("result day 01 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_575);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(count_increases(self.input))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(count_increases_sliding_window(self.input, 3))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_01_example.txt");
+        let input = read_file(2021, "day_01_example.txt")?;
 
         let result_part1 = count_increases(input);
         println!("result day 01 part 1: {result_part1}");
@@ -1,7 +1,9 @@
 //!day_02.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
+use std::fmt::Display;
 
 fn dive(input: &str) -> i64 {
     let mut position = Point::new(0, 0);
@@ -51,28 +53,33 @@ fn dive_with_aim(input: &str) -> i64 {
     position.x * position.y
 }
 
-pub fn day_02() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_02.txt");
+pub struct Day02 {
+    input: &'static str,
+}
 
-    let result_part1 = dive(input);
-    println!("result day 02 part 1: {result_part1}");
-    assert_eq!(result_part1, 2_117_664);
+impl Solution for Day02 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
 
-    let result_part2 = dive_with_aim(input);
-    println!("result day 02 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_073_416_724);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(dive(self.input))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(dive_with_aim(self.input))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_02_example.txt");
+        let input = read_file(2021, "day_02_example.txt")?;
 
         let result_part1 = dive(input);
         println!("result day 02 part 1: {result_part1}");
@@ -1,6 +1,8 @@
 //!day_03.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 fn binary_diagnostic_power_consumption(input: &str) -> u64 {
     let mut columns: Vec<Vec<bool>> = Vec::new();
@@ -78,28 +80,33 @@ fn filter_for_rate(columns: &[Vec<bool>], check_most_common: bool) -> u64 {
     rate
 }
 
-pub fn day_03() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_03.txt");
+pub struct Day03 {
+    input: &'static str,
+}
 
-    let result_part1 = binary_diagnostic_power_consumption(input);
-    println!("result day_03 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_092_896);
+impl Solution for Day03 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
 
-    let result_part2 = binary_diagnostic_life_support_rating(input);
-    println!("result day_03 part 2: {result_part2}");
-    assert_eq!(result_part2, 4_672_151);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(binary_diagnostic_power_consumption(self.input))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(binary_diagnostic_life_support_rating(self.input))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_03_example.txt");
+        let input = read_file(2021, "day_03_example.txt")?;
 
         let result_part1 = binary_diagnostic_power_consumption(input);
         println!("result day_03 part 1: {result_part1}");
@@ -1,9 +1,11 @@
 //!day_04.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
-struct BingoGame {
+pub struct BingoGame {
     numbers: Vec<u64>,
     boards: Vec<MyMap2D<u64, 5, 5>>,
 }
@@ -86,29 +88,29 @@ impl BingoGame {
     }
 }
 
-pub fn day_04() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_04.txt");
-    let bingo = BingoGame::from(input);
-
-    let result_part1 = bingo.find_first_winner();
-    println!("result day_04 part 1: {result_part1}");
-    assert_eq!(result_part1, 38_594);
+impl Solution for BingoGame {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = bingo.find_last_winner();
-    println!("result day_04 part 2: {result_part2}");
-    assert_eq!(result_part2, 21_184);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.find_first_winner())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.find_last_winner())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_04_example.txt");
+        let input = read_file(2021, "day_04_example.txt")?;
         let bingo = BingoGame::from(input);
 
         let result_part1 = bingo.find_first_winner();
@@ -1,10 +1,12 @@
 //!day_05.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::{my_line::LineSegment, my_point::Point};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct LineGrid {
+pub struct LineGrid {
     lines: Vec<LineSegment>,
 }
 
@@ -38,29 +40,29 @@ impl LineGrid {
     }
 }
 
-pub fn day_05() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_05.txt");
-    let line_grid = LineGrid::from(input);
-
-    let result_part1 = line_grid.overlapping_lines(true);
-    println!("result day_05 part 1: {result_part1}");
-    assert_eq!(result_part1, 4_655);
+impl Solution for LineGrid {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = line_grid.overlapping_lines(false);
-    println!("result day_05 part 2: {result_part2}");
-    assert_eq!(result_part2, 20_500);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.overlapping_lines(true))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.overlapping_lines(false))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_05_example.txt");
+        let input = read_file(2021, "day_05_example.txt")?;
         let line_grid = LineGrid::from(input);
 
         let result_part1 = line_grid.overlapping_lines(true);
@@ -1,9 +1,11 @@
 //!day_06.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Hash, Clone, Eq, PartialEq, Debug)]
-struct Population {
+pub struct Population {
     // index is time to offspring, value at index is number of lantern fish with this timer
     timers: [u64; 9],
 }
@@ -38,31 +40,31 @@ impl Population {
     }
 }
 
-pub fn day_06() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_06.txt");
-    let mut lantern_fish_population = Population::from(input);
-
-    lantern_fish_population.cycle(80);
-    let result_part1 = lantern_fish_population.size();
-    println!("result day_06 part 1: {result_part1}");
-    assert_eq!(result_part1, 380_243);
+impl Solution for Population {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    lantern_fish_population.cycle(256 - 80);
-    let result_part2 = lantern_fish_population.size();
-    println!("result day_06 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_708_791_884_591);
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.cycle(80);
+        Ok(self.size())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.cycle(256 - 80);
+        Ok(self.size())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_06_example.txt");
+        let input = read_file(2021, "day_06_example.txt")?;
         let mut lantern_fish_population = Population::from(input);
 
         lantern_fish_population.cycle(80);
@@ -1,8 +1,10 @@
 //!day_07.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct Crabs {
+pub struct Crabs {
     positions: Vec<i64>,
 }
 
@@ -72,29 +74,29 @@ impl Crabs {
     }
 }
 
-pub fn day_07() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_07.txt");
-    let crabs = Crabs::from(input);
-
-    let result_part1 = crabs.best_pos_fuel(20, false);
-    println!("result day_07 part 1: {result_part1}");
-    assert_eq!(result_part1, 354_129);
+impl Solution for Crabs {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = crabs.best_pos_fuel(100, true);
-    println!("result day_07 part 2: {result_part2}");
-    assert_eq!(result_part2, 98_905_973);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.best_pos_fuel(20, false))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.best_pos_fuel(100, true))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_07_example.txt");
+        let input = read_file(2021, "day_07_example.txt")?;
         let crabs = Crabs::from(input);
 
         let result_part1 = crabs.best_pos_fuel(20, false);
@@ -1,6 +1,8 @@
 //!day_08.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 struct Pattern {
     bit_mask: u8,
@@ -162,7 +164,7 @@ impl DigitDisplay {
     }
 }
 
-struct Day08Input {
+pub struct Day08Input {
     displays: Vec<DigitDisplay>,
 }
 
@@ -188,29 +190,29 @@ impl Day08Input {
     }
 }
 
-pub fn day_08() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_08.txt");
-    let mut input = Day08Input::from(input);
-
-    let result_part1 = input.count_simple_patterns();
-    println!("result day_08 part 1: {result_part1}");
-    assert_eq!(result_part1, 278);
+impl Solution for Day08Input {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = input.sum_values();
-    println!("result day_08 part 2: {result_part2}");
-    assert_eq!(result_part2, 986_179);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.count_simple_patterns())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.sum_values())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_08_example.txt");
+        let input = read_file(2021, "day_08_example.txt")?;
         let mut input = Day08Input::from(input);
 
         let result_part1 = input.count_simple_patterns();
@@ -1,7 +1,9 @@
 //!day_09.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Ord, Eq)]
 struct Height(u64);
@@ -12,7 +14,7 @@ impl From<char> for Height {
     }
 }
 
-struct ChallengeInput<const X: usize, const Y: usize> {
+pub struct ChallengeInput<const X: usize, const Y: usize> {
     height_map: MyMap2D<Height, X, Y>,
 }
 
@@ -60,29 +62,29 @@ impl<const X: usize, const Y: usize> ChallengeInput<X, Y> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_09.txt");
-    let challenge = ChallengeInput::<100, 100>::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_09 part 1: {result_part1}");
-    assert_eq!(result_part1, 558);
+impl<const X: usize, const Y: usize> Solution for ChallengeInput<X, Y> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_09 part 2: {result_part2}");
-    assert_eq!(result_part2, 882_942);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_09_example.txt");
+        let input = read_file(2021, "day_09_example.txt")?;
         let example = ChallengeInput::<10, 5>::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,6 +1,8 @@
 //!day_10.rs
 
 use anyhow::Result as AnyResult;
+use aoc_common::Solution;
+use std::fmt::Display;
 use std::str::Chars;
 
 #[derive(PartialEq, Debug)]
@@ -22,7 +24,7 @@ impl ChunkError {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     chunks: String,
     completion_strings: Vec<String>,
 }
@@ -120,25 +122,25 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> AnyResult<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_10.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_10 part 1: {result_part1}");
-    assert_eq!(result_part1, 392_421);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> AnyResult<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_10 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_769_449_099);
+    fn part_1(&mut self) -> AnyResult<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> AnyResult<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_check_chunk() {
@@ -188,7 +190,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> AnyResult<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_10_example.txt");
+        let input = read_file(2021, "day_10_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
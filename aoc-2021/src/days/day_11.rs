@@ -1,8 +1,10 @@
 //!day_11.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_map_two_dim::MyMap2D;
 use std::collections::VecDeque;
+use std::fmt::Display;
 
 #[derive(Clone, Copy, Default)]
 struct EnergyLevel(u32);
@@ -38,7 +40,7 @@ impl EnergyLevel {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     octopuses: MyMap2D<EnergyLevel, 10, 10>,
     first_full_flash: Option<usize>,
 }
@@ -100,19 +102,18 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_11.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_11 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_649);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_11 part 2: {result_part2}");
-    assert_eq!(result_part2, 256);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
@@ -121,6 +122,7 @@ mod tests {
     use std::fmt::Display;
 
     use super::*;
+    use aoc_common::read_file;
 
     impl Display for EnergyLevel {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -130,7 +132,7 @@ mod tests {
 
     #[test]
     fn test_steps() {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_11_example.txt");
+        let input = read_file(2021, "day_11_example.txt").unwrap();
         let mut example = ChallengeInput::from(input);
 
         println!("{}", example.octopuses);
@@ -155,7 +157,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_11_example.txt");
+        let input = read_file(2021, "day_11_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
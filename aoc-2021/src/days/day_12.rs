@@ -1,14 +1,16 @@
 //!day_12.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use petgraph::graph::{NodeIndex, UnGraph};
 use std::collections::{HashMap, HashSet, hash_map::Entry};
+use std::fmt::Display;
 
 struct Cave {
     size: bool,
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     caves: UnGraph<Cave, ()>,
     start_id: NodeIndex,
     end_id: NodeIndex,
@@ -102,29 +104,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_12.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_12 part 1: {result_part1}");
-    assert_eq!(result_part1, 4_495);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_12 part 2: {result_part2}");
-    assert_eq!(result_part2, 131_254);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_12_example_1.txt");
+        let input = read_file(2021, "day_12_example_1.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1_1 = example.solution_part_1();
@@ -135,7 +137,7 @@ mod tests {
         println!("result day_12 part 2 example 1: {result_part2_1}");
         assert_eq!(result_part2_1, 36);
 
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_12_example_2.txt");
+        let input = read_file(2021, "day_12_example_2.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1_2 = example.solution_part_1();
@@ -146,7 +148,7 @@ mod tests {
         println!("result day_12 part 2 example 2: {result_part2_2}");
         assert_eq!(result_part2_2, 103);
 
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_12_example_3.txt");
+        let input = read_file(2021, "day_12_example_3.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1_3 = example.solution_part_1();
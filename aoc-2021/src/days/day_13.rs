@@ -1,15 +1,17 @@
 //!day_13.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
 enum FoldAlong {
     X(i64),
     Y(i64),
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     dots: HashSet<Point>,
     fold_instructions: VecDeque<FoldAlong>,
 }
@@ -99,36 +101,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_13.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_13 part 1: {result_part1}");
-    assert_eq!(result_part1, 664);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_13 part 2: {result_part2}");
-    let expected = "\n\
-        ####.####...##.#..#.####.#....###..#...\n\
-        #....#.......#.#.#.....#.#....#..#.#...\n\
-        ###..###.....#.##.....#..#....###..#...\n\
-        #....#.......#.#.#...#...#....#..#.#...\n\
-        #....#....#..#.#.#..#....#....#..#.#...\n\
-        ####.#.....##..#..#.####.####.###..####";
-    assert_eq!(result_part2, expected);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_13_example.txt");
+        let input = read_file(2021, "day_13_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
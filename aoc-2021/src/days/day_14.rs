@@ -1,9 +1,11 @@
 //!day_14.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     pairs: HashMap<String, u64>,
     pair_insertion_rules: HashMap<String, String>,
     last_char: char,
@@ -78,29 +80,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_14.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_14 part 1: {result_part1}");
-    assert_eq!(result_part1, 2_233);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_14 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_884_513_602_164);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_one_step() {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_14_example.txt");
+        let input = read_file(2021, "day_14_example.txt").unwrap();
         let mut example = ChallengeInput::from(input);
         println!("{:?}", example.pairs);
         example.one_step();
@@ -110,7 +112,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_14_example.txt");
+        let input = read_file(2021, "day_14_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,10 +1,12 @@
 //!day_15.rs
 
 use anyhow::Result;
+use aoc_common::{LONG_RUNTIME, Solution};
 use my_lib::{my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::collections::{HashMap, VecDeque, hash_map::Entry};
+use std::fmt::Display;
+use std::time::Duration;
 
-#[cfg(any(feature = "long-run-time", test))]
 type BigCave = MapPoint<5, 5>;
 
 #[derive(Clone, Copy, Default)]
@@ -17,7 +19,6 @@ impl From<char> for RiskLevel {
 }
 
 impl RiskLevel {
-    #[cfg(any(feature = "long-run-time", test))]
     fn get_level(&self, big_cave: BigCave) -> u32 {
         let distance = big_cave.distance((0, 0).into()) as u32;
         let mut level = self.0 + distance;
@@ -28,7 +29,7 @@ impl RiskLevel {
     }
 }
 
-struct ChallengeInput<const X: usize, const Y: usize> {
+pub struct ChallengeInput<const X: usize, const Y: usize> {
     chitons: MyMap2D<RiskLevel, X, Y>,
 }
 
@@ -88,7 +89,6 @@ impl<const X: usize, const Y: usize> ChallengeInput<X, Y> {
         }
         lowest_risk.unwrap().1
     }
-    #[cfg(any(feature = "long-run-time", test))]
     fn solution_part_2(&self) -> u32 {
         let mut seen: HashMap<(MapPoint<X, Y>, BigCave), u32> = HashMap::new();
         let mut visit: VecDeque<(usize, MapPoint<X, Y>, BigCave, u32)> = VecDeque::new();
@@ -197,36 +197,31 @@ impl<const X: usize, const Y: usize> ChallengeInput<X, Y> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_15.txt");
-    let challenge = ChallengeInput::<100, 100>::from(input);
+impl<const X: usize, const Y: usize> Solution for ChallengeInput<X, Y> {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_15 part 1: {result_part1}");
-    assert_eq!(result_part1, 447);
-
-    #[cfg(any(feature = "long-run-time", test))]
-    {
-        let result_part2 = challenge.solution_part_2();
-        println!("result day_15 part 2: {result_part2}");
-        assert_eq!(result_part2, 2_825);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
     }
-    #[cfg(not(feature = "long-run-time"))]
-    {
-        println!("day 15 part 2 skipped because of long run time")
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
     }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_15_example.txt");
+        let input = read_file(2021, "day_15_example.txt")?;
         let example = ChallengeInput::<10, 10>::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,9 +1,12 @@
 //!day_16.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     bits: String,
+    result_part2: Option<u64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -34,6 +37,7 @@ impl From<&str> for ChallengeInput {
                     .chars()
                 })
                 .collect(),
+            result_part2: None,
         }
     }
 }
@@ -139,24 +143,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_16.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_16 part 1: {result_part1}");
-    assert_eq!(result_part1, 938);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_16 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_495_959_086_337);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn byte_test() {
@@ -188,7 +197,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_16_example.txt");
+        let input = read_file(2021, "day_16_example.txt")?;
         let expected_results_part_1 = [16, 12, 23, 31];
         let expected_results_part_2 = [15, 46, 46, 54];
         for (index, line) in input.lines().enumerate() {
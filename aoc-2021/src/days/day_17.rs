@@ -1,10 +1,13 @@
 //!day_17.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::{my_point::Point, my_rectangle::Rectangle};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     target_area: Rectangle,
+    result_part2: Option<usize>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -24,6 +27,7 @@ impl From<&str> for ChallengeInput {
         let bottom_right = (x[1], y[0]).into();
         ChallengeInput {
             target_area: Rectangle::new(top_left, bottom_right),
+            result_part2: None,
         }
     }
 }
@@ -83,28 +87,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_17.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2();
-    println!("result day_17 part 1: {result_part1}");
-    assert_eq!(result_part1, 13_203);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_17 part 2: {result_part2}");
-    assert_eq!(result_part2, 5_644);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_17_example.txt");
+        let input = read_file(2021, "day_17_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2();
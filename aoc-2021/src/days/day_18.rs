@@ -3,6 +3,7 @@
 use std::{fmt::Display, str::Chars};
 
 use anyhow::Result;
+use aoc_common::Solution;
 
 #[derive(Clone)]
 enum SnailFishNumber {
@@ -155,7 +156,7 @@ impl SnailFishNumber {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     snailfish_numbers: Vec<SnailFishNumber>,
 }
 
@@ -192,25 +193,25 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_18.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_18 part 1: {result_part1}");
-    assert_eq!(result_part1, 2_541);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_18 part 2: {result_part2}");
-    assert_eq!(result_part2, 4_647);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_reduce() {
@@ -248,7 +249,7 @@ mod tests {
 
     #[test]
     fn test_example_1() {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_18_example_1.txt");
+        let input = read_file(2021, "day_18_example_1.txt").unwrap();
         let lines: Vec<String> = input.lines().map(|l| l.to_string()).collect();
         let mut first_iter = lines[0].chars();
         let mut snail_fish = SnailFishNumber::new(&mut first_iter);
@@ -267,7 +268,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_18_example_2.txt");
+        let input = read_file(2021, "day_18_example_2.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
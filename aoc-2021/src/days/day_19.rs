@@ -1,14 +1,16 @@
 //!day_19.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::{Point3D, Turns90};
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 type RotationComb = (Turns90, Turns90, Turns90);
 // quadratic distance, count
 type Fingerprint = HashMap<i64, usize>;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     beacons: Vec<Vec<Point3D>>,
     fingerprints: Vec<Fingerprint>,
     scanner_positions: Vec<Option<(Point3D, RotationComb)>>,
@@ -164,29 +166,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_19.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_19 part 1: {result_part1}");
-    assert_eq!(result_part1, 394);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_19 part 2: {result_part2}");
-    //assert_eq!(result_part2, YYY);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_day_19() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_19_example.txt");
+        let input = read_file(2021, "day_19_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
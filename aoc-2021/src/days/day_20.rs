@@ -1,9 +1,11 @@
 //!day_20.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use fixedbitset::FixedBitSet;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 #[derive(Clone)]
 struct Image {
@@ -75,7 +77,7 @@ impl Image {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     algo: FixedBitSet,
     image: Image,
 }
@@ -110,19 +112,18 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_20.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_20 part 1: {result_part1}");
-    //assert_eq!(result_part1, XXX);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_20 part 2: {result_part2}");
-    //assert_eq!(result_part2, YYY);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
@@ -131,6 +132,7 @@ mod tests {
     use std::fmt::Display;
 
     use super::*;
+    use aoc_common::read_file;
 
     impl Display for Image {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -161,7 +163,7 @@ mod tests {
 
     #[test]
     fn test_input() {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_20_example.txt");
+        let input = read_file(2021, "day_20_example.txt").unwrap();
         let example = ChallengeInput::from(input);
         assert_eq!(example.image.pixels.len(), 10);
 
@@ -190,7 +192,7 @@ mod tests {
 
     #[test]
     fn test_example_day_20() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_20_example.txt");
+        let input = read_file(2021, "day_20_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,7 +1,9 @@
 //!day_21.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 type DiracCache = HashMap<ChallengeInput, (u64, u64)>;
 
@@ -41,7 +43,7 @@ impl DeterministicDice {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     p1: Player,
     p2: Player,
     active_player: bool,
@@ -126,29 +128,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_21.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_21 part 1: {result_part1}");
-    assert_eq!(result_part1, 925_605);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_21 part 2: {result_part2}");
-    assert_eq!(result_part2, 486_638_407_378_784);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_21() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_21_example.txt");
+        let input = read_file(2021, "day_21_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,8 +1,10 @@
 //!day_22.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_geometry::my_point::Point3D, my_pixels::my_box::Box3D};
 use std::collections::VecDeque;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct Cuboid {
@@ -93,7 +95,7 @@ impl Cuboid {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     cuboid_instructions: Vec<Cuboid>,
 }
 
@@ -161,29 +163,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_22.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_22 part 1: {result_part1}");
-    assert_eq!(result_part1, 553_201);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_22 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_263_946_820_845_866);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn verify_parsing() {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_22_example_2.txt");
+        let input = read_file(2021, "day_22_example_2.txt").unwrap();
         let example = ChallengeInput::from(input);
 
         let (num_changes_of_size, index_of_last_change, _) = example
@@ -205,7 +207,7 @@ mod tests {
         assert_eq!(index_of_last_change, 20);
         assert_eq!(example.cuboid_instructions.len(), 22);
 
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_22.txt");
+        let input = read_file(2021, "day_22.txt").unwrap();
         let challenge = ChallengeInput::from(input);
 
         let (num_changes_of_size, index_of_last_change, _) = challenge
@@ -230,7 +232,7 @@ mod tests {
 
     #[test]
     fn test_example_1_day_22() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_22_example_1.txt");
+        let input = read_file(2021, "day_22_example_1.txt")?;
         let example = ChallengeInput::from(input);
 
         // check first and second instruction
@@ -287,7 +289,7 @@ mod tests {
 
     #[test]
     fn test_example_2_day_22() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_22_example_2.txt");
+        let input = read_file(2021, "day_22_example_2.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -299,7 +301,7 @@ mod tests {
 
     #[test]
     fn test_example_3_day_22() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_22_example_3.txt");
+        let input = read_file(2021, "day_22_example_3.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
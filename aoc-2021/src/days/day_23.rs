@@ -1,6 +1,8 @@
 //!day_23.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use aoc_common::read_file;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::{cmp::Ordering, collections::BTreeSet, fmt::Display};
 
@@ -75,7 +77,7 @@ impl Cell {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     energy: [Option<u64>; 4],
     burrow: MyMap2D<Cell, 11, 5>,
 }
@@ -268,21 +270,19 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_23.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_23 part 1: {result_part1}");
-    assert_eq!(result_part1, 10_411);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_23_part2.txt");
-    let challenge = ChallengeInput::from(input);
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_23 part 2: {result_part2}");
-    assert_eq!(result_part2, 46_721);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let input = read_file(2021, "day_23_part2.txt")?;
+        Ok(ChallengeInput::from(input).solution_part_2())
+    }
 }
 
 #[cfg(test)]
@@ -292,7 +292,7 @@ mod tests {
 
     #[test]
     fn test_steps_of_example() {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_23_example_steps.txt");
+        let input = read_file(2021, "day_23_example_steps.txt").unwrap();
         let example_steps: Vec<ChallengeInput> =
             input.split("\n\n").map(ChallengeInput::from).collect();
 
@@ -340,14 +340,14 @@ mod tests {
 
     #[test]
     fn test_example_day_23() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_23_example.txt");
+        let input = read_file(2021, "day_23_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
         println!("result day_23 part 1: {result_part1}");
         assert_eq!(result_part1, 12_521);
 
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_23_example_part2.txt");
+        let input = read_file(2021, "day_23_example_part2.txt")?;
         let example = ChallengeInput::from(input);
         let result_part2 = example.solution_part_2();
         println!("result day_23 part 2: {result_part2}");
@@ -1,9 +1,11 @@
 //!day_25.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_map_point::MapPoint, my_map_two_dim::MyMap2D};
+use std::fmt::Display;
 
-struct ChallengeInput<const X: usize, const Y: usize> {
+pub struct ChallengeInput<const X: usize, const Y: usize> {
     cucumbers: MyMap2D<char, X, Y>,
 }
 
@@ -84,25 +86,27 @@ impl<const X: usize, const Y: usize> ChallengeInput<X, Y> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2021/day_25.txt");
-    let mut challenge = ChallengeInput::<139, 137>::from(input);
+impl<const X: usize, const Y: usize> Solution for ChallengeInput<X, Y> {
+    const HAS_PART_2: bool = false;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_25 part 1: {result_part1}");
-    assert_eq!(result_part1, 305);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_25() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2021/day_25_example.txt");
+        let input = read_file(2021, "day_25_example.txt")?;
         let mut example = ChallengeInput::<10, 9>::from(input);
 
         let result_part1 = example.solution_part_1();
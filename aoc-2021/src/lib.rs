@@ -2,35 +2,38 @@
 
 pub mod days;
 
-use anyhow::Result;
+use aoc_common::Registry;
 
-pub fn run() -> Result<()> {
-    days::day_01::day_01()?;
-    days::day_02::day_02()?;
-    days::day_03::day_03()?;
-    days::day_04::day_04()?;
-    days::day_05::day_05()?;
-    days::day_06::day_06()?;
-    days::day_07::day_07()?;
-    days::day_08::day_08()?;
-    days::day_09::solution()?;
-    days::day_10::solution()?;
-    days::day_11::solution()?;
-    days::day_12::solution()?;
-    days::day_13::solution()?;
-    days::day_14::solution()?;
-    days::day_15::solution()?;
-    days::day_16::solution()?;
-    days::day_17::solution()?;
-    days::day_18::solution()?;
-    days::day_19::solution()?;
-    days::day_20::solution()?;
-    days::day_21::solution()?;
-    days::day_22::solution()?;
-    days::day_23::solution()?;
-    // day 24 solved in libre office calc, which contains the puzzle input,
-    // which is why it is not included in this repo.
-    days::day_25::solution()?;
-
-    Ok(())
+/// Solvers of all days of 2021.
+///
+/// Day 24 is solved in libre office calc, which contains the puzzle input,
+/// which is why it is not included in this repo.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<days::day_01::Day01>(2021, 1)
+        .register::<days::day_02::Day02>(2021, 2)
+        .register::<days::day_03::Day03>(2021, 3)
+        .register::<days::day_04::BingoGame>(2021, 4)
+        .register::<days::day_05::LineGrid>(2021, 5)
+        .register::<days::day_06::Population>(2021, 6)
+        .register::<days::day_07::Crabs>(2021, 7)
+        .register::<days::day_08::Day08Input>(2021, 8)
+        .register::<days::day_09::ChallengeInput<100, 100>>(2021, 9)
+        .register::<days::day_10::ChallengeInput>(2021, 10)
+        .register::<days::day_11::ChallengeInput>(2021, 11)
+        .register::<days::day_12::ChallengeInput>(2021, 12)
+        .register::<days::day_13::ChallengeInput>(2021, 13)
+        .register::<days::day_14::ChallengeInput>(2021, 14)
+        .register::<days::day_15::ChallengeInput<100, 100>>(2021, 15)
+        .register::<days::day_16::ChallengeInput>(2021, 16)
+        .register::<days::day_17::ChallengeInput>(2021, 17)
+        .register::<days::day_18::ChallengeInput>(2021, 18)
+        .register::<days::day_19::ChallengeInput>(2021, 19)
+        .register::<days::day_20::ChallengeInput>(2021, 20)
+        .register::<days::day_21::ChallengeInput>(2021, 21)
+        .register::<days::day_22::ChallengeInput>(2021, 22)
+        .register::<days::day_23::ChallengeInput>(2021, 23)
+        .register::<days::day_25::ChallengeInput<139, 137>>(2021, 25);
+    registry
 }
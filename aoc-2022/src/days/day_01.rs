@@ -1,9 +1,10 @@
 //!day_01.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
-pub fn day_01() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_01.txt");
+fn solve(input: &str) -> (u64, u64) {
     let mut max_calories: Vec<u64> = Vec::new();
     for calorie_package in input.split("\n\n") {
         let calorie_sum: u64 = calorie_package
@@ -14,13 +15,34 @@ pub fn day_01() -> Result<()> {
     }
     max_calories.sort();
 
-    let result_part1 = max_calories.last().unwrap();
-    println!("result day 01 part 1: {}", result_part1);
-    assert_eq!(*result_part1, 74_711);
+    let result_part1 = *max_calories.last().unwrap();
 
     let result_part2: u64 = max_calories[max_calories.len() - 3..].iter().sum();
-    println!("result day 01 part 2: {}", result_part2);
-    assert_eq!(result_part2, 209_481);
+    (result_part1, result_part2)
+}
+
+pub struct Day01 {
+    input: &'static str,
+    result_part2: Option<u64>,
+}
+
+impl Solution for Day01 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
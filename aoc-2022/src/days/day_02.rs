@@ -1,6 +1,8 @@
 //!day_02.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 enum Rps {
@@ -53,8 +55,7 @@ impl Rps {
     }
 }
 
-pub fn day_02() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_02.txt");
+fn solve(input: &str) -> (u64, u64) {
     let mut result_part1 = 0;
     let mut result_part2: u64 = 0;
     for (opp, me) in input.lines().map(|l| l.split_once(' ').unwrap()) {
@@ -64,12 +65,31 @@ pub fn day_02() -> Result<()> {
         let me_task2 = Rps::from_cheating(me, &opp);
         result_part2 += me_task2.play(&opp) + me_task2.score_shape();
     }
+    (result_part1, result_part2)
+}
+
+pub struct Day02 {
+    input: &'static str,
+    result_part2: Option<u64>,
+}
 
-    println!("result day 02 part 1: {}", result_part1);
-    assert_eq!(result_part1, 14_375);
+impl Solution for Day02 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
 
-    println!("result day 02 part 2: {}", result_part2);
-    assert_eq!(result_part2, 10_274);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
@@ -1,6 +1,8 @@
 //!day_03.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 enum LineState {
     First(String),
@@ -20,8 +22,7 @@ fn calc_priority(c: char) -> u32 {
     panic!("non ascii char");
 }
 
-pub fn day_03() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_03.txt");
+fn solve(input: &str) -> (u32, u32) {
     let mut result_part1 = 0;
     let mut result_part2 = 0;
     let mut line_state = LineState::Last;
@@ -57,23 +58,44 @@ pub fn day_03() -> Result<()> {
             }
         }
     }
-    println!("result day 03 part 1: {}", result_part1);
-    assert_eq!(result_part1, 8_088);
+    (result_part1, result_part2)
+}
+
+pub struct Day03 {
+    input: &'static str,
+    result_part2: Option<u32>,
+}
 
-    println!("result day 03 part 2: {}", result_part2);
-    assert_eq!(result_part2, 2_522);
+impl Solution for Day03 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part_1() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_03.txt");
+        let input = read_file(2022, "day_03.txt")?;
         for line in input.lines() {
             assert_eq!(line.chars().count(), line[..].len());
         }
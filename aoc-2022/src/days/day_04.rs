@@ -1,6 +1,8 @@
 //!day_04.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 struct SectionRange {
     start: u32,
@@ -41,8 +43,7 @@ impl SectionRange {
     }
 }
 
-pub fn day_04() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_04.txt");
+fn solve(input: &str) -> (u32, u32) {
     let mut result_part1 = 0;
     let mut result_part2 = 0;
     for line in input.lines() {
@@ -57,14 +58,33 @@ pub fn day_04() -> Result<()> {
             }
         }
     }
+    (result_part1, result_part2)
+}
+
+pub struct Day04 {
+    input: &'static str,
+    result_part2: Option<u32>,
+}
 
-    println!("result day 04 part 1: {}", result_part1);
-    assert_eq!(result_part1, 582);
+impl Solution for Day04 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
 
-    println!("result day 04 part 2: {}", result_part2);
-    assert_eq!(result_part2, 893);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 //!day_05.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct CrateStacks {
@@ -102,8 +104,7 @@ impl From<&str> for CraneCommand {
     }
 }
 
-pub fn day_05() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_05.txt");
+fn solve(input: &str) -> (String, String) {
     let (crate_stack_str, crane_commands) = input.split_once("\n\n").unwrap();
     let mut crate_stack = CrateStacks::from(crate_stack_str);
     let crane_commands: Vec<CraneCommand> =
@@ -113,8 +114,6 @@ pub fn day_05() -> Result<()> {
     }
 
     let result_part1 = crate_stack.get_top_crates();
-    println!("result day 05 part 1: {}", result_part1);
-    assert_eq!(result_part1, String::from("QNHWJVJZW"));
 
     crate_stack = CrateStacks::from(crate_stack_str);
     for cr in crane_commands.iter() {
@@ -122,10 +121,33 @@ pub fn day_05() -> Result<()> {
     }
 
     let result_part2 = crate_stack.get_top_crates();
-    println!("result day 05 part 2: {}", result_part2);
-    assert_eq!(result_part2, String::from("BPCZJLFJW"));
+    (result_part1, result_part2)
+}
+
+pub struct Day05 {
+    input: &'static str,
+    result_part2: Option<String>,
+}
+
+impl Solution for Day05 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 //!day_06.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::VecDeque;
+use std::fmt::Display;
 
 #[derive(Default)]
 struct MarkerState {
@@ -28,9 +30,7 @@ impl MarkerState {
     }
 }
 
-pub fn day_06() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_06.txt");
-
+fn solve(input: &str) -> (usize, usize) {
     let mut result_part1 = 0;
     let mut result_part2 = 0;
     let mut start_of_paket_marker_state = MarkerState::default();
@@ -48,13 +48,33 @@ pub fn day_06() -> Result<()> {
             break;
         }
     }
-    println!("result day 06 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_920);
+    (result_part1, result_part2)
+}
+
+pub struct Day06 {
+    input: &'static str,
+    result_part2: Option<usize>,
+}
+
+impl Solution for Day06 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
 
-    println!("result day 06 part 2: {}", result_part2);
-    assert_eq!(result_part2, 2_334);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
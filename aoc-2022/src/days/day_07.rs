@@ -1,7 +1,9 @@
 //!day_07.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_tree::TreeNode;
+use std::fmt::Display;
 use std::rc::Rc;
 
 #[derive(PartialEq, Default)]
@@ -109,18 +111,39 @@ impl FileTree {
     }
 }
 
-pub fn day_07() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_07.txt");
+fn solve(input: &str) -> (usize, usize) {
     let file_tree = FileTree::new(5, 70_000_000);
     file_tree.parse(input);
     file_tree.calc_dir_sizes();
     let result_part1 = file_tree.sum_dir_size_bounded(100_000);
-    println!("result day 07 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_644_735);
     let result_part2 = file_tree.find_smallest_dir_to_delete(30_000_000);
-    println!("result day 07 part 2: {}", result_part2);
-    assert_eq!(result_part2, 1_300_850);
-    Ok(())
+    (result_part1, result_part2)
+}
+
+pub struct Day07 {
+    input: &'static str,
+    result_part2: Option<usize>,
+}
+
+impl Solution for Day07 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
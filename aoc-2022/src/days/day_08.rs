@@ -1,7 +1,9 @@
 //!day_08.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::{my_map_point::MapPoint, my_map_two_dim::MyMap2D};
+use std::fmt::Display;
 
 // taken from ../../../../aoc_input/aoc-2022/day_08.txt
 const X: usize = 99;
@@ -76,30 +78,52 @@ impl<const X: usize, const Y: usize> Forest<X, Y> {
     }
 }
 
-pub fn day_08() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_08.txt");
+fn solve(input: &str) -> (usize, u32) {
     let mut forest = Forest::<X, Y>::from(input);
     forest.check_visbility();
     let result_part1 = forest.num_visible_trees();
-    println!("result day 08 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_669);
     forest.calc_scenic_score();
     let result_part2 = forest.max_scenic_score();
-    println!("result day 08 part 2: {}", result_part2);
-    assert_eq!(result_part2, 331_344);
-    Ok(())
+    (result_part1, result_part2)
+}
+
+pub struct Day08 {
+    input: &'static str,
+    result_part2: Option<u32>,
+}
+
+impl Solution for Day08 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     const XT: usize = 5;
     const YT: usize = 5;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_08.txt");
+        let input = read_file(2022, "day_08.txt")?;
         eprintln!("X: {}", input.lines().next().unwrap().chars().count());
         eprintln!("Y: {}", input.lines().count());
         let input = "30373\n\
@@ -1,7 +1,9 @@
 //!day_09.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
+use std::fmt::Display;
 
 struct RopeAction {
     direction: Point,
@@ -65,8 +67,7 @@ impl Rope {
     }
 }
 
-pub fn day_09() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_09.txt");
+fn solve(input: &str) -> (usize, usize) {
     let rope_actions: Vec<RopeAction> = input.lines().map(RopeAction::from).collect();
     let mut rope = Rope::new(2);
     let mut long_rope = Rope::new(10);
@@ -75,13 +76,35 @@ pub fn day_09() -> Result<()> {
         long_rope.apply(ra);
     }
     let result_part1 = rope.tail_seen.len();
-    println!("result day 09 part 1: {}", result_part1);
-    assert_eq!(result_part1, 6_311);
 
     let result_part2 = long_rope.tail_seen.len();
-    println!("result day 09 part 2: {}", result_part2);
-    assert_eq!(result_part2, 2_482);
-    Ok(())
+    (result_part1, result_part2)
+}
+
+pub struct Day09 {
+    input: &'static str,
+    result_part2: Option<usize>,
+}
+
+impl Solution for Day09 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 //!day_10.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
 // size of CRT
 const X: usize = 40;
@@ -82,40 +84,44 @@ impl From<&str> for RegisterCommand {
     }
 }
 
-pub fn day_10() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_10.txt");
+fn run_signal_register(input: &str) -> SignalRegister<X, Y> {
     let register_commands: Vec<RegisterCommand> =
         input.lines().map(RegisterCommand::from).collect();
     let mut signal_register = SignalRegister::<X, Y>::new(20, 40);
     for rc in register_commands.iter() {
         signal_register.apply(rc);
     }
+    signal_register
+}
+
+pub struct Day10 {
+    input: &'static str,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(run_signal_register(self.input).sum_check_cycle_x)
+    }
 
-    let result_part1 = signal_register.sum_check_cycle_x;
-    println!("result day 10 part 1: {}", result_part1);
-    assert_eq!(result_part1, 14_860);
-
-    let result_part2 = format!("{}", signal_register.crt);
-    // result in letters: RGZEHURK
-    let test_part2 = "###...##..####.####.#..#.#..#.###..#..#.\n\
-                            #..#.#..#....#.#....#..#.#..#.#..#.#.#..\n\
-                            #..#.#......#..###..####.#..#.#..#.##...\n\
-                            ###..#.##..#...#....#..#.#..#.###..#.#..\n\
-                            #.#..#..#.#....#....#..#.#..#.#.#..#.#..\n\
-                            #..#..###.####.####.#..#..##..#..#.#..#.";
-    println!("result day 10 part 2:\n{}", result_part2.trim());
-    assert_eq!(result_part2.trim(), test_part2);
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let signal_register = run_signal_register(self.input);
+        Ok(format!("{}", signal_register.crt).trim().to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_10_example.txt");
+        let input = read_file(2022, "day_10_example.txt")?;
         let register_commands: Vec<RegisterCommand> =
             input.lines().map(RegisterCommand::from).collect();
         //eprintln!("{:?}", &register_commands[..10]);
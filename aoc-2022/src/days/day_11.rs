@@ -1,13 +1,15 @@
 //!day_11.rs
 
 use anyhow::Result;
+use aoc_common::{LONG_RUNTIME, Solution};
 use evalexpr::eval_int;
 use std::collections::VecDeque;
+use std::fmt::Display;
+use std::time::Duration;
 
 #[derive(Clone, Copy)]
 enum InspectionMethod {
     Division(i64),
-    #[cfg(feature = "long-run-time")]
     Modulo(i64),
 }
 
@@ -87,7 +89,6 @@ impl Monkey {
                 let new = eval_int(expression.as_str()).expect("bad expression");
                 let new = match inspection_method {
                     InspectionMethod::Division(divisor) => new / divisor,
-                    #[cfg(feature = "long-run-time")]
                     InspectionMethod::Modulo(divisor) => new % divisor,
                 };
                 if new % self.test_divisor == 0 {
@@ -130,39 +131,43 @@ fn play_n_rounds(
     max_inspections * second_max_inspections
 }
 
-pub fn day_11() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_11.txt");
-    let mut monkeys: Vec<Monkey> = input.split("\n\n").map(Monkey::from).collect();
-    let inspection_method = InspectionMethod::Division(3);
-    let result_part1 = play_n_rounds(&mut monkeys, inspection_method, 20);
-    println!("result day 11 part 1: {}", result_part1);
-    assert_eq!(result_part1, 67_830);
+pub struct Day11 {
+    input: &'static str,
+}
 
-    #[cfg(feature = "long-run-time")]
-    {
-        let mut monkeys: Vec<Monkey> = input.split("\n\n").map(Monkey::from).collect();
-        let super_divisor: i64 = monkeys.iter().map(|m| m.test_divisor).product();
-        let inspection_method = InspectionMethod::Modulo(super_divisor);
-        let result_part2 = play_n_rounds(&mut monkeys, inspection_method, 10_000);
-        println!("result day 11 part 2: {}", result_part2);
-        assert_eq!(result_part2, 15_305_381_442);
+impl Solution for Day11 {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
+
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
     }
-    #[cfg(not(feature = "long-run-time"))]
-    {
-        println!("day 11 part 2 skipped because of long run time")
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let mut monkeys: Vec<Monkey> = self.input.split("\n\n").map(Monkey::from).collect();
+        Ok(play_n_rounds(
+            &mut monkeys,
+            InspectionMethod::Division(3),
+            20,
+        ))
     }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let mut monkeys: Vec<Monkey> = self.input.split("\n\n").map(Monkey::from).collect();
+        let super_divisor: i64 = monkeys.iter().map(|m| m.test_divisor).product();
+        let inspection_method = InspectionMethod::Modulo(super_divisor);
+        Ok(play_n_rounds(&mut monkeys, inspection_method, 10_000))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_11_example.txt");
+        let input = read_file(2022, "day_11_example.txt")?;
         let mut monkeys: Vec<Monkey> = input.split("\n\n").map(Monkey::from).collect();
         let inspection_method = InspectionMethod::Division(3);
         let result_part1 = play_n_rounds(&mut monkeys, inspection_method, 20);
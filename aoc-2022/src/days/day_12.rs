@@ -1,13 +1,15 @@
 //!day_12.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
+use std::fmt::Display;
 
 // taken from ../../../../aoc_input/aoc-2022/day_12.txt
 const X: usize = 113;
 const Y: usize = 41;
 
-struct Heightmap<const X: usize, const Y: usize> {
+pub struct Heightmap<const X: usize, const Y: usize> {
     map: MyMap2D<char, X, Y>,
 }
 
@@ -61,29 +63,31 @@ impl<const X: usize, const Y: usize> Heightmap<X, Y> {
     }
 }
 
-pub fn day_12() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_12.txt");
-    let height_map = Heightmap::<X, Y>::from(input);
-    let result_part1 = height_map.travel_shortest_path_from_startpoint();
-    println!("result day 12 part 1: {}", result_part1);
-    assert_eq!(result_part1, 380);
+impl<const X: usize, const Y: usize> Solution for Heightmap<X, Y> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.travel_shortest_path_from_startpoint())
+    }
 
-    let result_part2 = height_map.travel_shortest_path_from_lowest_elevation();
-    println!("result day 12 part 2: {}", result_part2);
-    assert_eq!(result_part2, 375);
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.travel_shortest_path_from_lowest_elevation())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     const XT: usize = 8;
     const YT: usize = 5;
 
     #[test]
     fn test_example() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_12.txt");
+        let input = read_file(2022, "day_12.txt")?;
         let x_input = input.lines().next().unwrap().chars().count();
         let y_input = input.lines().count();
         eprintln!("X: {}, Y: {}", x_input, y_input);
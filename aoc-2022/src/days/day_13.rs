@@ -1,7 +1,9 @@
 //!day_13.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::cmp::Ordering;
+use std::fmt::Display;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum Packet {
@@ -97,42 +99,51 @@ fn sort_packets(packets: &mut Vec<Packet>) -> usize {
     seperator_1_index * seperator_2_index
 }
 
-pub fn day_13() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_13.txt");
-    let packet_pairs: Vec<(Packet, Packet)> = input
-        .split("\n\n")
-        .map(|p| {
-            p.split_once('\n')
-                .map(|(l, r)| (Packet::from(l), Packet::from(r)))
-                .unwrap()
-        })
-        .collect();
-    let result_part1 = compare_packet_pairs(&packet_pairs);
-    println!("result day 13 part 1: {}", result_part1);
-    assert_eq!(result_part1, 4_734);
-
-    let mut packets: Vec<Packet> = input
-        .lines()
-        .filter(|l| !l.is_empty())
-        .map(Packet::from)
-        .collect();
-    let result_part2 = sort_packets(&mut packets);
-    println!("result day 13 part 2: {}", result_part2);
-    assert_eq!(result_part2, 21_836);
-    Ok(())
+pub struct Day13 {
+    input: &'static str,
+}
+
+impl Solution for Day13 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let packet_pairs: Vec<(Packet, Packet)> = self
+            .input
+            .split("\n\n")
+            .map(|p| {
+                p.split_once('\n')
+                    .map(|(l, r)| (Packet::from(l), Packet::from(r)))
+                    .unwrap()
+            })
+            .collect();
+        Ok(compare_packet_pairs(&packet_pairs))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        let mut packets: Vec<Packet> = self
+            .input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(Packet::from)
+            .collect();
+        Ok(sort_packets(&mut packets))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_13.txt");
+        let input = read_file(2022, "day_13.txt")?;
         eprintln!("{:?}", Packet::from(input.lines().next().unwrap()));
 
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_13_example.txt");
+        let input = read_file(2022, "day_13_example.txt")?;
         let packet_pairs: Vec<(Packet, Packet)> = input
             .split("\n\n")
             .map(|p| {
@@ -1,11 +1,13 @@
 //!day_14.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::fmt::Display;
 
-struct RockAndSand {
+pub struct RockAndSand {
     rocks: HashSet<Point>,
     sand: HashSet<Point>,
     start_of_sand: Point,
@@ -128,18 +130,18 @@ impl RockAndSand {
     }
 }
 
-pub fn day_14() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_14.txt");
-    let mut rock_and_sand = RockAndSand::from(input);
-    let result_part1 = rock_and_sand.pouring_sand(false);
-    println!("result day 14 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_068);
+impl Solution for RockAndSand {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = rock_and_sand.pouring_sand(true);
-    println!("result day 14 part 2: {}", result_part2);
-    assert_eq!(result_part2, 27_936);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.pouring_sand(false))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.pouring_sand(true))
+    }
 }
 
 #[cfg(test)]
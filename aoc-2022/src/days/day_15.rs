@@ -1,10 +1,12 @@
 //!day_15.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{
     my_geometry::{my_line::Line, my_point::Point},
     my_pixels::my_diamond::Diamond,
 };
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct SensorBeacon {
@@ -103,31 +105,41 @@ fn find_distress_beacon(sensor_beacons: &[SensorBeacon], max_range: i64, x_facto
     distress_beacons[0].x * x_factor + distress_beacons[0].y
 }
 
-pub fn day_15() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_15.txt");
-    let sensor_beacons: Vec<SensorBeacon> = input.lines().map(SensorBeacon::from).collect();
-    let row = 2_000_000;
-    let result_part1 = calc_scanned_positions_of_row(&sensor_beacons, row);
-    println!("result day 15 part 1: {}", result_part1);
-    assert_eq!(result_part1, 5_112_034);
+pub struct Day15 {
+    sensor_beacons: Vec<SensorBeacon>,
+}
 
-    let max_range = 4_000_000;
-    let x_factor = 4_000_000;
-    let result_part2 = find_distress_beacon(&sensor_beacons, max_range, x_factor);
-    println!("result day 15 part 2: {}", result_part2);
-    assert_eq!(result_part2, 13_172_087_230_812);
+impl Solution for Day15 {
+    fn parse(input: &'static str) -> Result<Self> {
+        let sensor_beacons = input.lines().map(SensorBeacon::from).collect();
+        Ok(Self { sensor_beacons })
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(calc_scanned_positions_of_row(
+            &self.sensor_beacons,
+            2_000_000,
+        ))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(find_distress_beacon(
+            &self.sensor_beacons,
+            4_000_000,
+            4_000_000,
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_15_example.txt");
+        let input = read_file(2022, "day_15_example.txt")?;
         let sensor_beacons: Vec<SensorBeacon> = input.lines().map(SensorBeacon::from).collect();
         let row = 10;
         let result_part1 = calc_scanned_positions_of_row(&sensor_beacons, row);
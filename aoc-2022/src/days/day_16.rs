@@ -1,11 +1,13 @@
 //!day_16.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use petgraph::algo::floyd_warshall;
 use petgraph::graph::{NodeIndex, UnGraph};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-struct ValveNetwork {
+pub struct ValveNetwork {
     valves: UnGraph<u32, u32>,
     initial_node_id: NodeIndex<u32>,
     pair_distance: HashMap<(NodeIndex<u32>, NodeIndex<u32>), u32>,
@@ -149,35 +151,29 @@ impl ValveNetwork {
     }
 }
 
-pub fn day_16() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_16.txt");
-    let valve_network = ValveNetwork::from(input);
-    let minutes = 30;
-    let minimum_valve_value = 3;
-    let result_part1 = *valve_network
-        .best_pressure_release(minutes, minimum_valve_value)
-        .values()
-        .max()
-        .unwrap();
-    println!("result day 16 part 1: {}", result_part1);
-    assert_eq!(result_part1, 2_077);
+impl Solution for ValveNetwork {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let minimum_valve_value = 1;
-    let result_part2 = valve_network.best_pressure_release_pair_working(minimum_valve_value);
-    println!("result day 16 part 2: {}", result_part2);
-    assert_eq!(result_part2, 2_741);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(*self.best_pressure_release(30, 3).values().max().unwrap())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.best_pressure_release_pair_working(1))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn text_bitmask() {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_16_example.txt");
+        let input = read_file(2022, "day_16_example.txt").unwrap();
         let valve_network = ValveNetwork::from(input);
         let node_count = valve_network.valves.node_count();
         let mut bit_mask: u64 = 0;
@@ -191,7 +187,7 @@ mod tests {
 
     #[test]
     fn test_example() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_16_example.txt");
+        let input = read_file(2022, "day_16_example.txt")?;
         let valve_network = ValveNetwork::from(input);
         let minutes = 30;
         let minimum_valve_value = 2;
@@ -1,7 +1,9 @@
 //!day_17.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::{HashMap, hash_map::Entry};
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 struct Point {
@@ -248,20 +250,22 @@ impl Chamber {
     }
 }
 
-pub fn day_17() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_17.txt");
-    let num_rocks = 2_022;
-    let mut chamber = Chamber::new();
-    let result_part1 = chamber.falling_blocks(num_rocks, input);
-    println!("result day 17 part 1: {}", result_part1);
-    assert_eq!(result_part1, 3_193);
-    let num_rocks = 1_000_000_000_000;
-    let mut chamber = Chamber::new();
-    let result_part1 = chamber.falling_blocks(num_rocks, input);
-    println!("result day 17 part 2: {}", result_part1);
-    assert_eq!(result_part1, 1_577_650_429_835);
+pub struct Day17 {
+    input: &'static str,
+}
+
+impl Solution for Day17 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(Chamber::new().falling_blocks(2_022, self.input))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(Chamber::new().falling_blocks(1_000_000_000_000, self.input))
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 //!day_18.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Point3D {
@@ -101,16 +103,31 @@ fn count_surfaces(points: &[Point3D]) -> (usize, usize) {
     (n_surfaces, n_surfaces_outside)
 }
 
-pub fn day_18() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_18.txt");
-    let points: Vec<Point3D> = input.lines().map(Point3D::from).collect();
-    let (result_part1, result_part2) = count_surfaces(&points);
-    println!("result day 18 part 1: {}", result_part1);
-    assert_eq!(result_part1, 4_548);
-    println!("result day 18 part 2: {}", result_part2);
-    assert_eq!(result_part2, 2_588);
+pub struct Day18 {
+    points: Vec<Point3D>,
+    result_part2: Option<usize>,
+}
+
+impl Solution for Day18 {
+    fn parse(input: &'static str) -> Result<Self> {
+        let points = input.lines().map(Point3D::from).collect();
+        Ok(Self {
+            points,
+            result_part2: None,
+        })
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = count_surfaces(&self.points);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
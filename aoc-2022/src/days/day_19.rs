@@ -1,7 +1,10 @@
 //!day_19.rs
 
 use anyhow::Result;
+use aoc_common::{LONG_RUNTIME, Solution};
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::Duration;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 struct BluePrint {
@@ -213,37 +216,38 @@ impl GeodeCollection {
     }
 }
 
-pub fn day_19() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_19.txt");
-    let geode_collectors: Vec<GeodeCollection> = input
-        .lines()
-        .map(BluePrint::from)
-        .map(GeodeCollection::new)
-        .collect();
-    let minutes = 24;
-    let result_part1: u64 = geode_collectors
-        .iter()
-        .map(|gc| gc.execute(minutes) * gc.blue_print.id)
-        .sum();
-    println!("result day 19 part 1: {}", result_part1);
-    assert_eq!(result_part1, 2_341);
+pub struct Day19 {
+    geode_collectors: Vec<GeodeCollection>,
+}
 
-    #[cfg(feature = "long-run-time")]
-    {
-        let minutes = 32;
-        let result_part2: u64 = geode_collectors
+impl Solution for Day19 {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
+
+    fn parse(input: &'static str) -> Result<Self> {
+        let geode_collectors = input
+            .lines()
+            .map(BluePrint::from)
+            .map(GeodeCollection::new)
+            .collect();
+        Ok(Self { geode_collectors })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self
+            .geode_collectors
             .iter()
-            .take(3)
-            .map(|gc| gc.execute(minutes))
-            .product();
-        println!("result day 19 part 2: {}", result_part2);
-        assert_eq!(result_part2, 3_689);
+            .map(|gc| gc.execute(24) * gc.blue_print.id)
+            .sum::<u64>())
     }
-    #[cfg(not(feature = "long-run-time"))]
-    {
-        println!("day 19 part 2 skipped because of long run time")
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self
+            .geode_collectors
+            .iter()
+            .take(3)
+            .map(|gc| gc.execute(32))
+            .product::<u64>())
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 //!day_20.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::cmp::Ordering;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct Code {
@@ -75,26 +77,29 @@ fn get_coordinates_sum(codes: &[Code]) -> i64 {
     sum
 }
 
-pub fn day_20() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_20.txt");
-    let codes: Vec<Code> = input
-        .lines()
-        .enumerate()
-        .map(|(id, c)| Code::from((id, c.parse::<i64>().expect("bad input"))))
-        .collect();
-    let rotated_codes = rotate_codes(&codes, 1, 1);
-    let result_part1 = get_coordinates_sum(&rotated_codes);
-    println!("result day 20 part 1: {}", result_part1);
-    assert_eq!(result_part1, 4_426);
+pub struct Day20 {
+    codes: Vec<Code>,
+}
+
+impl Solution for Day20 {
+    fn parse(input: &'static str) -> Result<Self> {
+        let codes = input
+            .lines()
+            .enumerate()
+            .map(|(id, c)| Code::from((id, c.parse::<i64>().expect("bad input"))))
+            .collect();
+        Ok(Self { codes })
+    }
 
-    let decryption_key = 811_589_153;
-    let cycles = 10;
-    let rotated_codes = rotate_codes(&codes, decryption_key, cycles);
-    let result_part2 = get_coordinates_sum(&rotated_codes);
-    println!("result day 20 part 2: {}", result_part2);
-    assert_eq!(result_part2, 8_119_137_886_612);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let rotated_codes = rotate_codes(&self.codes, 1, 1);
+        Ok(get_coordinates_sum(&rotated_codes))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let rotated_codes = rotate_codes(&self.codes, 811_589_153, 10);
+        Ok(get_coordinates_sum(&rotated_codes))
+    }
 }
 
 #[cfg(test)]
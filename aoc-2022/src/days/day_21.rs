@@ -1,8 +1,10 @@
 //!day_21.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use evalexpr::eval_int;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 fn eval_monkeys(key: String, monkeys: &HashMap<String, String>) -> Result<i64> {
     let value = monkeys.get(&key).ok_or(anyhow!("key not found"))?;
@@ -76,36 +78,41 @@ fn eval_human(monkeys: &HashMap<String, String>) -> Result<i64> {
     Ok(eval_value)
 }
 
-pub fn day_21() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_21.txt");
-    let monkeys: HashMap<String, String> = input
-        .lines()
-        .map(|l| {
-            l.split_once(": ")
-                .map(|(k, v)| (k.to_string(), v.to_string()))
-                .unwrap()
-        })
-        .collect();
+pub struct Day21 {
+    monkeys: HashMap<String, String>,
+}
 
-    let result_part1 = eval_monkeys(String::from("root"), &monkeys)?;
-    println!("result day 21 part 1: {}", result_part1);
-    assert_eq!(result_part1, 232_974_643_455_000);
+impl Solution for Day21 {
+    fn parse(input: &'static str) -> Result<Self> {
+        let monkeys = input
+            .lines()
+            .map(|l| {
+                l.split_once(": ")
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .unwrap()
+            })
+            .collect();
+        Ok(Self { monkeys })
+    }
 
-    let result_part2 = eval_human(&monkeys)?;
-    println!("result day 21 part 2: {}", result_part2);
-    assert_eq!(result_part2, 3_740_214_169_961);
+    fn part_1(&mut self) -> Result<impl Display> {
+        eval_monkeys(String::from("root"), &self.monkeys)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        eval_human(&self.monkeys)
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_21_example.txt");
+        let input = read_file(2022, "day_21_example.txt")?;
         let monkeys: HashMap<String, String> = input
             .lines()
             .map(|l| {
@@ -1,12 +1,14 @@
 //!day_22.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{
     my_compass::Compass,
     my_cube_map::{CubeMap, CubeMapPoint},
     my_map_point::MapPoint,
     my_map_two_dim::MyMap2D,
 };
+use std::fmt::Display;
 
 // values taken from ../../../../aoc_input/aoc-2022/day_22.txt
 const X: usize = 150;
@@ -241,25 +243,29 @@ fn explore_trail(mut jungle: impl JungleTrail) -> usize {
     jungle.calc_trail_end_value()
 }
 
-pub fn day_22() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_22.txt");
-    let flat_jungle_trail = FlatJungleMap::<X, Y>::from(input);
-    let result_part1 = explore_trail(flat_jungle_trail);
-    println!("result day 22 part 1: {}", result_part1);
-    assert_eq!(result_part1, 13_566);
+pub struct Day22 {
+    input: &'static str,
+}
 
-    let cubic_jungle_trail = CubicJungle::<N>::from(input);
-    let result_part2 = explore_trail(cubic_jungle_trail);
-    println!("result day 22 part 2: {}", result_part2);
-    assert_eq!(result_part2, 11_451);
+impl Solution for Day22 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(explore_trail(FlatJungleMap::<X, Y>::from(self.input)))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(explore_trail(CubicJungle::<N>::from(self.input)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     // values taken from ../../../../aoc_input/aoc-2022/day_22_example.txt
     const XD: usize = 16;
     const YD: usize = 12;
@@ -267,7 +273,7 @@ mod tests {
 
     #[test]
     fn test_example() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2022/day_22_example.txt");
+        let input = read_file(2022, "day_22_example.txt")?;
         let flat_ungle_trail = FlatJungleMap::<XD, YD>::from(input);
         let result_part1 = explore_trail(flat_ungle_trail);
         println!("result example day 22 part 1: {}", result_part1);
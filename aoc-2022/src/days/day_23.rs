@@ -1,11 +1,13 @@
 //!day_23.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
+use std::fmt::Display;
 
 use std::collections::{HashMap, HashSet};
 
-struct ElveSwarm {
+pub struct ElveSwarm {
     elves: HashSet<Point>,
     top_left: Point,
     bottom_right: Point,
@@ -98,26 +100,26 @@ impl ElveSwarm {
     }
 }
 
-pub fn day_23() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_23.txt");
-    let mut elve_swarm = ElveSwarm::from(input);
-    let num_rounds: usize = 10;
-    for _ in 0..num_rounds {
-        elve_swarm.one_movement_phase();
+impl Solution for ElveSwarm {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
     }
-    let result_part1 = elve_swarm.count_empty_tiles();
-    println!("result day 23 part 1: {}", result_part1);
-    assert_eq!(result_part1, 4_034);
 
-    let mut num_rounds = num_rounds;
-    while elve_swarm.one_movement_phase() > 0 {
-        num_rounds += 1;
+    fn part_1(&mut self) -> Result<impl Display> {
+        for _ in 0..10 {
+            self.one_movement_phase();
+        }
+        Ok(self.count_empty_tiles())
     }
-    num_rounds += 1;
-    println!("result day 23 part 1: {}", num_rounds);
-    assert_eq!(num_rounds, 960);
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        // part 1 already moved the elves for 10 rounds
+        let mut num_rounds = 11;
+        while self.one_movement_phase() > 0 {
+            num_rounds += 1;
+        }
+        Ok(num_rounds)
+    }
 }
 
 #[cfg(test)]
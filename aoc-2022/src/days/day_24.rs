@@ -1,10 +1,12 @@
 //!day_24.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_geometry::my_point::Point};
 use num::integer::lcm;
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashSet};
+use std::fmt::Display;
 
 #[derive(Default)]
 struct BlizzardVale {
@@ -216,18 +218,30 @@ impl ExpeditionState {
 // So inspired (again) by HyperNeutrino
 // https://www.youtube.com/watch?v=R_QWG-cPp_k&list=PLnNm9syGLD3yf-YW-a5XNh1CJN07xr0Kz&index=24
 
-pub fn day_24() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_24.txt");
-    let blizzard_vale = BlizzardVale::from(input);
-    let mut expedition = ExpeditionState::new();
-    let result_part1 = expedition.shortest_path_expedition(&blizzard_vale, 1);
-    println!("result day 24 part 1: {}", result_part1);
-    assert_eq!(result_part1, 274);
-    let result_part2 = expedition.shortest_path_expedition(&blizzard_vale, 3);
-    println!("result day 24 part 2: {}", result_part2);
-    assert_eq!(result_part2, 839);
+pub struct Day24 {
+    blizzard_vale: BlizzardVale,
+    expedition: ExpeditionState,
+}
+
+impl Solution for Day24 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            blizzard_vale: BlizzardVale::from(input),
+            expedition: ExpeditionState::new(),
+        })
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self
+            .expedition
+            .shortest_path_expedition(&self.blizzard_vale, 1))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self
+            .expedition
+            .shortest_path_expedition(&self.blizzard_vale, 3))
+    }
 }
 
 #[cfg(test)]
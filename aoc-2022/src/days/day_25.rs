@@ -3,6 +3,7 @@
 use std::fmt::Display;
 
 use anyhow::Result;
+use aoc_common::Solution;
 
 #[derive(Debug, Clone, Copy)]
 struct Base5Place {
@@ -145,13 +146,25 @@ impl Base5Num {
     }
 }
 
-pub fn day_25() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2022/day_25.txt");
-    let result_part1: i64 = input.lines().map(|l| Base5Num::from(l).to_dezimal()).sum();
-    let result_part1 = format!("{}", Base5Num::from_dezimal(result_part1));
-    println!("result day 25 part 1: {}", result_part1);
-    assert_eq!(result_part1, String::from("20=02=120-=-2110-0=1"));
-    Ok(())
+pub struct Day25 {
+    input: &'static str,
+}
+
+impl Solution for Day25 {
+    const HAS_PART_2: bool = false;
+
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let sum: i64 = self
+            .input
+            .lines()
+            .map(|l| Base5Num::from(l).to_dezimal())
+            .sum();
+        Ok(Base5Num::from_dezimal(sum))
+    }
 }
 
 #[cfg(test)]
@@ -2,34 +2,36 @@
 
 pub mod days;
 
-use anyhow::Result;
+use aoc_common::Registry;
 
-pub fn run() -> Result<()> {
-    days::day_01::day_01()?;
-    days::day_02::day_02()?;
-    days::day_03::day_03()?;
-    days::day_04::day_04()?;
-    days::day_05::day_05()?;
-    days::day_06::day_06()?;
-    days::day_07::day_07()?;
-    days::day_08::day_08()?;
-    days::day_09::day_09()?;
-    days::day_10::day_10()?;
-    days::day_11::day_11()?;
-    days::day_12::day_12()?;
-    days::day_13::day_13()?;
-    days::day_14::day_14()?;
-    days::day_15::day_15()?;
-    days::day_16::day_16()?;
-    days::day_17::day_17()?;
-    days::day_18::day_18()?;
-    days::day_19::day_19()?;
-    days::day_20::day_20()?;
-    days::day_21::day_21()?;
-    days::day_22::day_22()?;
-    days::day_23::day_23()?;
-    days::day_24::day_24()?;
-    days::day_25::day_25()?;
-
-    Ok(())
+/// Solvers of all days of 2022.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<days::day_01::Day01>(2022, 1)
+        .register::<days::day_02::Day02>(2022, 2)
+        .register::<days::day_03::Day03>(2022, 3)
+        .register::<days::day_04::Day04>(2022, 4)
+        .register::<days::day_05::Day05>(2022, 5)
+        .register::<days::day_06::Day06>(2022, 6)
+        .register::<days::day_07::Day07>(2022, 7)
+        .register::<days::day_08::Day08>(2022, 8)
+        .register::<days::day_09::Day09>(2022, 9)
+        .register::<days::day_10::Day10>(2022, 10)
+        .register::<days::day_11::Day11>(2022, 11)
+        .register::<days::day_12::Heightmap<113, 41>>(2022, 12)
+        .register::<days::day_13::Day13>(2022, 13)
+        .register::<days::day_14::RockAndSand>(2022, 14)
+        .register::<days::day_15::Day15>(2022, 15)
+        .register::<days::day_16::ValveNetwork>(2022, 16)
+        .register::<days::day_17::Day17>(2022, 17)
+        .register::<days::day_18::Day18>(2022, 18)
+        .register::<days::day_19::Day19>(2022, 19)
+        .register::<days::day_20::Day20>(2022, 20)
+        .register::<days::day_21::Day21>(2022, 21)
+        .register::<days::day_22::Day22>(2022, 22)
+        .register::<days::day_23::ElveSwarm>(2022, 23)
+        .register::<days::day_24::Day24>(2022, 24)
+        .register::<days::day_25::Day25>(2022, 25);
+    registry
 }
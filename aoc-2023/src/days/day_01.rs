@@ -1,15 +1,29 @@
 //!day_01.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
-pub fn day_01() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_01.txt");
-    part_1(input)?;
-    part_2(input)
+pub struct Day01 {
+    input: &'static str,
 }
 
-fn part_1(input: &str) -> Result<()> {
-    let sum: u32 = input
+impl Solution for Day01 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(part_1(self.input))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(part_2(self.input))
+    }
+}
+
+fn part_1(input: &str) -> u32 {
+    input
         .lines()
         .map(|l| match l.chars().find(|c| c.is_ascii_digit()) {
             Some(left) => {
@@ -21,10 +35,7 @@ fn part_1(input: &str) -> Result<()> {
             }
             None => 0,
         })
-        .sum();
-    println!("result day 01 part 1: {}", sum);
-    assert_eq!(sum, 56_465);
-    Ok(())
+        .sum()
 }
 
 const DIGITS: [&str; 9] = [
@@ -32,8 +43,8 @@ const DIGITS: [&str; 9] = [
 ];
 const CHARS: [char; 9] = ['1', '2', '3', '4', '5', '6', '7', '8', '9'];
 
-pub fn part_2(input: &str) -> Result<()> {
-    let sum: u32 = input
+fn part_2(input: &str) -> u32 {
+    input
         .lines()
         .map(|l| {
             let mut index = 0;
@@ -75,8 +86,5 @@ pub fn part_2(input: &str) -> Result<()> {
                 .parse::<u32>()
                 .unwrap_or_else(|_| panic!("could not parse {}{}", left, right))
         })
-        .sum();
-    println!("result day 01 part 2: {}", sum);
-    assert_eq!(sum, 55_902);
-    Ok(())
+        .sum()
 }
@@ -1,6 +1,8 @@
 //!day_02.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Default)]
 struct Cubes {
@@ -37,8 +39,7 @@ impl Cubes {
     }
 }
 
-pub fn day_02() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_02.txt");
+fn solve(input: &str) -> Result<(u32, u32)> {
     let max_cubes = Cubes::new(12, 13, 14);
     let mut result = 0;
     let mut power = 0;
@@ -54,9 +55,31 @@ pub fn day_02() -> Result<()> {
         }
         power += cubes.power();
     }
-    println!("result day 02 part 1: {}", result);
-    assert_eq!(result, 2_416);
-    println!("result day 02 part 2: {}", power);
-    assert_eq!(power, 63_307);
-    Ok(())
+    Ok((result, power))
+}
+
+pub struct Day02 {
+    input: &'static str,
+    result_part2: Option<u32>,
+}
+
+impl Solution for Day02 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input)?;
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
@@ -1,8 +1,10 @@
 //!day_03.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_map_point::MapPoint;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
 // number of chars in one line of day_03.txt
 const X: usize = 140;
@@ -15,8 +17,7 @@ struct Cell {
     id: u32,
 }
 
-pub fn day_03() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_03.txt");
+fn solve(input: &str) -> Result<(u32, u32)> {
     let mut char_map: MyMap2D<Cell, X, Y> = MyMap2D::default();
     let mut id = 1;
     let mut last_is_digit = false;
@@ -100,10 +101,31 @@ pub fn day_03() -> Result<()> {
             }
         }
     }
+    Ok((result_part1, result_part2))
+}
+
+pub struct Day03 {
+    input: &'static str,
+    result_part2: Option<u32>,
+}
+
+impl Solution for Day03 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input)?;
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    println!("result day 03 part 1: {}", result_part1);
-    assert_eq!(result_part1, 535_235);
-    println!("result day 03 part 2: {}", result_part2);
-    assert_eq!(result_part2, 79_844_424);
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
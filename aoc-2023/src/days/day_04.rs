@@ -1,12 +1,13 @@
 //!day_04.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_array::MyArray;
+use std::fmt::Display;
 
 const NUM_CARDS: usize = 201;
 
-pub fn day_04() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_04.txt");
+fn solve(input: &str) -> (u32, u32) {
     let mut result_part1 = 0;
     let mut num_per_card: MyArray<u32, NUM_CARDS> = MyArray::init(1, NUM_CARDS);
     for (current_card, line) in input
@@ -40,10 +41,32 @@ pub fn day_04() -> Result<()> {
         }
     }
 
-    println!("result day 04 part 1: {}", result_part1);
-    assert_eq!(result_part1, 21_485);
     let result_part2 = num_per_card.iter().sum::<u32>();
-    println!("result day 04 part 2: {}", result_part2);
-    assert_eq!(result_part2, 11_024_379);
-    Ok(())
+    (result_part1, result_part2)
+}
+
+pub struct Day04 {
+    input: &'static str,
+    result_part2: Option<u32>,
+}
+
+impl Solution for Day04 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
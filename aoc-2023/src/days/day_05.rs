@@ -1,6 +1,8 @@
 //!day_05.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Clone, Copy)]
 struct CategoryRange {
@@ -178,8 +180,7 @@ impl TransferMapSet {
 // www.youtube.com/@hyper-neutrino
 // github.com/hyper-neutrino
 
-pub fn day_05() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_05.txt");
+fn solve(input: &str) -> (u64, u64) {
     let mut transfer_maps = TransferMapSet::default();
     let mut seed_input: Vec<u64> = Vec::new();
     let mut transfer_map_type: Option<TransferMapType> = None;
@@ -208,9 +209,7 @@ pub fn day_05() -> Result<()> {
         .map(|s| CategoryRange::single(*s))
         .collect();
 
-    let lowest_location = transfer_maps.get_min_location_from_seed_ranges(seeds);
-    println!("result day 05 part 1: {}", lowest_location);
-    assert_eq!(lowest_location, 261_668_924);
+    let result_part1 = transfer_maps.get_min_location_from_seed_ranges(seeds);
 
     // Part 2
     let mut seeds: Vec<CategoryRange> = Vec::new();
@@ -219,9 +218,32 @@ pub fn day_05() -> Result<()> {
         let &seed_range = seed_iter.next().expect("bad input");
         seeds.push(CategoryRange::new(start_seed, seed_range));
     }
-    let lowest_location = transfer_maps.get_min_location_from_seed_ranges(seeds);
-    println!("result day 05 part 2: {}", lowest_location);
-    assert_eq!(lowest_location, 24_261_545);
+    let result_part2 = transfer_maps.get_min_location_from_seed_ranges(seeds);
+    (result_part1, result_part2)
+}
+
+pub struct Day05 {
+    input: &'static str,
+    result_part2: Option<u64>,
+}
+
+impl Solution for Day05 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
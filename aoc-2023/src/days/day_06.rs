@@ -1,6 +1,8 @@
 //!day_06.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 struct RaceParam {
     time: f64,
@@ -20,8 +22,7 @@ impl RaceParam {
     }
 }
 
-pub fn day_06() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_06.txt");
+fn solve(input: &str) -> Result<(u64, u64)> {
     let mut input_iter = input.lines();
     let times = input_iter
         .next()
@@ -55,8 +56,6 @@ pub fn day_06() -> Result<()> {
         let (time_1, time_2) = race.calc_times();
         result_part1 *= time_2 - time_1 + 1;
     }
-    println!("result day 06 part 1: {}", result_part1);
-    assert_eq!(result_part1, 345_015);
 
     // part 2
     let mut input_iter = input.lines();
@@ -85,8 +84,31 @@ pub fn day_06() -> Result<()> {
     let race = RaceParam::new(time, distance);
     let (time_1, time_2) = race.calc_times();
     let result_part2 = time_2 - time_1 + 1;
-    println!("result day 06 part 2: {}", result_part2);
-    assert_eq!(result_part2, 42_588_603);
+    Ok((result_part1, result_part2))
+}
+
+pub struct Day06 {
+    input: &'static str,
+    result_part2: Option<u64>,
+}
+
+impl Solution for Day06 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input)?;
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
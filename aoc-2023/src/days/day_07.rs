@@ -1,7 +1,9 @@
 //!day_07.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::cmp::Ordering;
+use std::fmt::Display;
 
 // Playing Card definitions
 trait PlayingCard {
@@ -272,8 +274,7 @@ impl<C: PlayingCard + PartialEq + Eq + PartialOrd + Ord + Default + From<char>>
     }
 }
 
-pub fn day_07() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_07.txt");
+fn solve(input: &str) -> (u64, u64) {
     // part 1
     let mut card_hands: Vec<CardHand<NoJokers>> = input
         .lines()
@@ -292,8 +293,6 @@ pub fn day_07() -> Result<()> {
     {
         result_part1 += rank * bid;
     }
-    println!("result day 07 part 1: {}", result_part1);
-    assert_eq!(result_part1, 251_927_063);
 
     // part 2
     let mut card_hands: Vec<CardHand<HasJokers>> = input
@@ -313,8 +312,31 @@ pub fn day_07() -> Result<()> {
     {
         result_part2 += rank * bid;
     }
-    println!("result day 07 part 2: {}", result_part2);
-    assert_eq!(result_part2, 255_632_664);
+    (result_part1, result_part2)
+}
+
+pub struct Day07 {
+    input: &'static str,
+    result_part2: Option<u64>,
+}
 
-    Ok(())
+impl Solution for Day07 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
@@ -1,8 +1,10 @@
 //!day_08.RS
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use num::integer::lcm;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 struct Instructions {
     left: String,
@@ -18,8 +20,7 @@ impl Instructions {
     }
 }
 
-pub fn day_08() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_08.txt");
+fn solve(input: &str) -> (u64, u64) {
     let mut lines = input.lines().filter(|l| !l.is_empty());
     let directions = lines.next().unwrap();
     let mut map: HashMap<String, Instructions> = HashMap::new();
@@ -48,8 +49,7 @@ pub fn day_08() -> Result<()> {
             break;
         }
     }
-    println!("result day 08 part 1: {}", steps);
-    assert_eq!(steps, 13_301);
+    let result_part1 = steps;
 
     // part 2
     // hint for solution: every path from each starting node to it's corresponding ending node
@@ -86,8 +86,31 @@ pub fn day_08() -> Result<()> {
     for next_key in step_iter {
         lcm_step = lcm(lcm_step, *next_key);
     }
-    println!("result day 08 part 2: {}", lcm_step);
-    assert_eq!(lcm_step, 7_309_459_565_207);
+    (result_part1, lcm_step)
+}
+
+pub struct Day08 {
+    input: &'static str,
+    result_part2: Option<u64>,
+}
+
+impl Solution for Day08 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
@@ -1,6 +1,8 @@
 //!day_09.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 // solution hint: use a recursive algorithmen
 fn calc_next_in_sequence(sequence: &mut Vec<i64>) {
@@ -30,9 +32,7 @@ fn calc_next_in_sequence(sequence: &mut Vec<i64>) {
     sequence.insert(0, first - first_delta);
 }
 
-pub fn day_09() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_09.txt");
-
+fn solve(input: &str) -> (i64, i64) {
     let mut result_part1: i64 = 0;
     let mut result_part2: i64 = 0;
     for line in input.lines() {
@@ -44,10 +44,31 @@ pub fn day_09() -> Result<()> {
         result_part1 += *sequence.last().unwrap();
         result_part2 += sequence[0];
     }
-    println!("result day 09 part 1: {}", result_part1);
-    assert_eq!(result_part1, 2_038_472_161);
-    println!("result day 09 part 2: {}", result_part2);
-    assert_eq!(result_part2, 1_091);
+    (result_part1, result_part2)
+}
 
-    Ok(())
+pub struct Day09 {
+    input: &'static str,
+    result_part2: Option<i64>,
+}
+
+impl Solution for Day09 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
@@ -1,9 +1,11 @@
 //!day_10.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_compass::Compass;
 use my_lib::my_map_point::MapPoint;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
 // values taken from ../../../../aoc_input/aoc-2023/day_10.txt
 // number of chars in one line
@@ -500,9 +502,7 @@ impl<const X: usize, const Y: usize> TileMap<X, Y> {
     }
 }
 
-pub fn day_10() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_10.txt");
-
+fn solve(input: &str) -> Result<(usize, usize)> {
     // part 1: build pipe map and count pipe tiles
     let mut tile_map = TileMap::<X, Y>::from(input);
     // flow_direction points toward next tile with pipe
@@ -523,11 +523,6 @@ pub fn day_10() -> Result<()> {
 
     let farthest_distance_from_start_tile_trough_pipe =
         tile_map.get_number_of_pipe_tiles() / 2 + tile_map.get_number_of_pipe_tiles() % 2;
-    println!(
-        "result day 10 part 1: {}",
-        farthest_distance_from_start_tile_trough_pipe
-    );
-    assert_eq!(farthest_distance_from_start_tile_trough_pipe, 6_697);
 
     // part 2: identify left and right side tiles of pipe and check, which one is outside
     // count outside tiles as result
@@ -546,8 +541,34 @@ pub fn day_10() -> Result<()> {
     tile_map.extend_side_tiles();
     // identify outside and count inside
     let number_of_inside_tiles = tile_map.identify_outside_count_inside()?;
-    println!("result day 10 part 2: {}", number_of_inside_tiles);
-    assert_eq!(number_of_inside_tiles, 423);
+    Ok((
+        farthest_distance_from_start_tile_trough_pipe,
+        number_of_inside_tiles,
+    ))
+}
 
-    Ok(())
+pub struct Day10 {
+    input: &'static str,
+    result_part2: Option<usize>,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input)?;
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
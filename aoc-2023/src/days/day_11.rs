@@ -3,6 +3,7 @@
 use std::fmt::Display;
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_map_point::MapPoint;
 
 // values taken from ../../../../aoc_input/aoc-2023/day_11.txt
@@ -12,7 +13,7 @@ const X: usize = 140;
 const Y: usize = 140;
 
 #[derive(Default)]
-struct Cosmos<const X: usize, const Y: usize> {
+pub struct Cosmos<const X: usize, const Y: usize> {
     galaxies: Vec<MapPoint<X, Y>>,
     empty_space_columns: Vec<usize>,
     empty_space_rows: Vec<usize>,
@@ -105,17 +106,18 @@ impl<const X: usize, const Y: usize> Cosmos<X, Y> {
     }
 }
 
-pub fn day_11() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_11.txt");
-    let cosmos = Cosmos::<X, Y>::new(input);
-    let sum_distance = cosmos.calc_sum_galaxy_distances(2);
-    println!("result day 11 part 1: {}", sum_distance);
-    assert_eq!(sum_distance, 9_799_681);
-    let sum_distance = cosmos.calc_sum_galaxy_distances(1_000_000);
-    println!("result day 11 part 2: {}", sum_distance);
-    assert_eq!(sum_distance, 513_171_773_355);
+impl<const X: usize, const Y: usize> Solution for Cosmos<X, Y> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::new(input))
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.calc_sum_galaxy_distances(2))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.calc_sum_galaxy_distances(1_000_000))
+    }
 }
 
 #[cfg(test)]
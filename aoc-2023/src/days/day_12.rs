@@ -2,7 +2,9 @@
 
 use std::collections::HashMap;
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 fn springs_and_damaged_clusters(input: &str) -> (&str, Vec<usize>) {
     input
@@ -106,8 +108,7 @@ fn different_arrangements(
     num_different_arrangements
 }
 
-pub fn day_12() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_12.txt");
+fn solve(input: &str) -> (usize, usize) {
     let mut sum_different_arrangements = 0;
     let mut sum_different_arrangements_unfolded = 0;
     let mut cache: HashMap<(String, Vec<usize>), usize> = HashMap::new();
@@ -123,16 +124,36 @@ pub fn day_12() -> Result<()> {
             &mut cache,
         );
     }
+    (
+        sum_different_arrangements,
+        sum_different_arrangements_unfolded,
+    )
+}
 
-    println!("result day 12 part 1: {}", sum_different_arrangements);
-    assert_eq!(sum_different_arrangements, 7_460);
-    println!(
-        "result day 12 part 2: {}",
-        sum_different_arrangements_unfolded
-    );
-    assert_eq!(sum_different_arrangements_unfolded, 6_720_660_274_964);
+pub struct Day12 {
+    input: &'static str,
+    result_part2: Option<usize>,
+}
 
-    Ok(())
+impl Solution for Day12 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 //!day_13.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
 // max values for X and Y over all patterns taken from ../../../../aoc_input/aoc-2023/day_13.txt
 const X: usize = 17;
@@ -190,8 +192,7 @@ impl<const X: usize, const Y: usize> Pattern<X, Y> {
     }
 }
 
-pub fn day_13() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_13.txt");
+fn solve(input: &str) -> (usize, usize) {
     let mut result_part1 = 0;
     let mut result_part2 = 0;
     for pat in input.split("\n\n") {
@@ -199,11 +200,31 @@ pub fn day_13() -> Result<()> {
         result_part1 += pattern.mirror_value;
         result_part2 += pattern.smudge_mirror_value;
     }
+    (result_part1, result_part2)
+}
+
+pub struct Day13 {
+    input: &'static str,
+    result_part2: Option<usize>,
+}
 
-    println!("result day 13 part 1: {}", result_part1);
-    assert_eq!(result_part1, 33_735);
-    println!("result day 13 part 2: {}", result_part2);
-    assert_eq!(result_part2, 38_063);
+impl Solution for Day13 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
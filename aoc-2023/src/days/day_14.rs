@@ -1,6 +1,7 @@
 //!day_14.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_two_dim::MyMap2D};
 use std::{
     collections::HashMap,
@@ -200,33 +201,38 @@ impl<const X: usize, const Y: usize> Parabolic<X, Y> {
     }
 }
 
-pub fn day_14() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_14.txt");
-    let mut parabolic_platform: Parabolic<X, Y> = Parabolic::new(input);
-    parabolic_platform.tilt_direction(Compass::N)?;
-    let result_part1 = parabolic_platform.calc_total_load_north();
-    println!("result day 14 part 1: {}", result_part1);
-    assert_eq!(result_part1, 108_144);
+pub struct Day14 {
+    input: &'static str,
+}
 
-    // part 2: reset platform
-    let mut cache: HashMap<Parabolic<X, Y>, Parabolic<X, Y>> = HashMap::new();
-    parabolic_platform = Parabolic::new(input);
-    parabolic_platform.tilt_num_cycles(1_000_000_000, &mut cache)?;
-    let result_part2 = parabolic_platform.calc_total_load_north();
-    println!("result day 14 part 2: {}", result_part2);
-    assert_eq!(result_part2, 108_404);
+impl Solution for Day14 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        let mut parabolic_platform: Parabolic<X, Y> = Parabolic::new(self.input);
+        parabolic_platform.tilt_direction(Compass::N)?;
+        Ok(parabolic_platform.calc_total_load_north())
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        let mut cache: HashMap<Parabolic<X, Y>, Parabolic<X, Y>> = HashMap::new();
+        let mut parabolic_platform: Parabolic<X, Y> = Parabolic::new(self.input);
+        parabolic_platform.tilt_num_cycles(1_000_000_000, &mut cache)?;
+        Ok(parabolic_platform.calc_total_load_north())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_slice_rotation_with_column() {
-        let input = include_str!("../../../../aoc_input/aoc-2023/day_14.txt");
+        let input = read_file(2023, "day_14.txt").unwrap();
         let mut parabolic_platform: Parabolic<X, Y> = Parabolic::new(input);
         let mut col_0 = parabolic_platform.platform.get_column(0);
         eprintln!("before rotation: {:?}", col_0);
@@ -253,7 +259,7 @@ mod tests {
 
     #[test]
     fn test_tilting() {
-        let input = include_str!("../../../../aoc_input/aoc-2023/day_14.txt");
+        let input = read_file(2023, "day_14.txt").unwrap();
         let mut parabolic_platform: Parabolic<X, Y> = Parabolic::new(input);
         let col_index = 20;
         // for column only N or S
@@ -1,7 +1,9 @@
 //!day_15.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 struct AoCHash {
     // use u64 even if hash is always 0 to 255, because you want to ad upp hash results
@@ -40,11 +42,8 @@ impl From<(String, u64)> for Lens {
     }
 }
 
-pub fn day_15() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_15.txt");
+fn solve(input: &str) -> (u64, u64) {
     let result_part1: u64 = input.split(',').map(|c| AoCHash::from(c).hash).sum();
-    println!("result day 15 part 1: {}", result_part1);
-    assert_eq!(result_part1, 512_283);
 
     // part 2
     let mut lens_label_box_cache: HashMap<u64, Vec<Lens>> = HashMap::new();
@@ -89,10 +88,33 @@ pub fn day_15() -> Result<()> {
                 .map(|(i, l)| ((i + 1) as u64) * l.focal_length)
                 .sum::<u64>();
     }
-    println!("result day 15 part 2: {}", result_part2);
-    assert_eq!(result_part2, 215_827);
+    (result_part1, result_part2)
+}
+
+pub struct Day15 {
+    input: &'static str,
+    result_part2: Option<u64>,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
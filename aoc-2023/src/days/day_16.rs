@@ -1,7 +1,9 @@
 //!day_16.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
+use std::fmt::Display;
 
 // values taken from ../../../../aoc_input/aoc-2023/day_16.txt
 const X: usize = 110;
@@ -121,7 +123,7 @@ impl Cell {
 }
 
 #[derive(Default)]
-struct MirrorChamber<const X: usize, const Y: usize> {
+pub struct MirrorChamber<const X: usize, const Y: usize> {
     map: MyMap2D<Cell, X, Y>,
 }
 
@@ -187,17 +189,18 @@ impl<const X: usize, const Y: usize> MirrorChamber<X, Y> {
     }
 }
 
-pub fn day_16() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_16.txt");
-    let mut mirror_chamber = MirrorChamber::<X, Y>::from(input);
-    let result_part1 = mirror_chamber.part1_beam_movement();
-    println!("result day 16 part 1: {}", result_part1);
-    assert_eq!(result_part1, 7_498);
-    let result_part2 = mirror_chamber.part2_beam_movement();
-    println!("result day 16 part 2: {}", result_part2);
-    assert_eq!(result_part2, 7_846);
-
-    Ok(())
+impl<const X: usize, const Y: usize> Solution for MirrorChamber<X, Y> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.part1_beam_movement())
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.part2_beam_movement())
+    }
 }
 
 #[cfg(test)]
@@ -1,28 +1,19 @@
 //!day_17.rs
 
-use anyhow::Result;
-#[cfg(feature = "long-run-time")]
+use anyhow::{Result, anyhow};
+use aoc_common::{LONG_RUNTIME, Solution};
 use my_lib::my_compass::Compass;
-#[cfg(feature = "long-run-time")]
 use my_lib::my_map_point::MapPoint;
-
-#[cfg(feature = "long-run-time")]
-use anyhow::anyhow;
-#[cfg(feature = "long-run-time")]
 use my_lib::my_map_two_dim::MyMap2D;
-
-#[cfg(feature = "long-run-time")]
 use std::cmp::Ordering;
-#[cfg(feature = "long-run-time")]
 use std::collections::BinaryHeap;
+use std::fmt::Display;
+use std::time::Duration;
 
 // values taken from ../../../../aoc_input/aoc-2023/day_17.txt
-#[cfg(feature = "long-run-time")]
 const X: usize = 141;
-#[cfg(feature = "long-run-time")]
 const Y: usize = 141;
 
-#[cfg(feature = "long-run-time")]
 trait PathNode<const X: usize, const Y: usize>: Default + PartialEq + Eq + Copy + Clone {
     fn step_forward(&self) -> Option<Self>;
     fn step_left(&self) -> Option<Self>;
@@ -30,7 +21,6 @@ trait PathNode<const X: usize, const Y: usize>: Default + PartialEq + Eq + Copy
     fn get_city_block(&self) -> MapPoint<X, Y>;
 }
 
-#[cfg(feature = "long-run-time")]
 #[derive(Default, PartialEq, Eq, Copy, Clone)]
 struct NormalCrucible<const X: usize, const Y: usize> {
     city_block: MapPoint<X, Y>,
@@ -38,7 +28,6 @@ struct NormalCrucible<const X: usize, const Y: usize> {
     n_steps: u8,
 }
 
-#[cfg(feature = "long-run-time")]
 impl<const X: usize, const Y: usize> PathNode<X, Y> for NormalCrucible<X, Y> {
     fn step_forward(&self) -> Option<Self> {
         if self.direction.is_center() || self.n_steps == 3 {
@@ -96,7 +85,6 @@ impl<const X: usize, const Y: usize> PathNode<X, Y> for NormalCrucible<X, Y> {
     }
 }
 
-#[cfg(feature = "long-run-time")]
 #[derive(Default, PartialEq, Eq, Copy, Clone)]
 struct UltraCrucible<const X: usize, const Y: usize> {
     city_block: MapPoint<X, Y>,
@@ -104,7 +92,6 @@ struct UltraCrucible<const X: usize, const Y: usize> {
     n_steps: u8,
 }
 
-#[cfg(feature = "long-run-time")]
 impl<const X: usize, const Y: usize> PathNode<X, Y> for UltraCrucible<X, Y> {
     fn step_forward(&self) -> Option<Self> {
         if self.direction.is_center() || self.n_steps == 10 {
@@ -168,21 +155,18 @@ impl<const X: usize, const Y: usize> PathNode<X, Y> for UltraCrucible<X, Y> {
     }
 }
 
-#[cfg(feature = "long-run-time")]
 #[derive(Default, Eq, Copy, Clone)]
 struct HeatPathNode<N: PathNode<X, Y>, const X: usize, const Y: usize> {
     accumulated_heat_loss: u64,
     path_node: N,
 }
 
-#[cfg(feature = "long-run-time")]
 impl<N: PathNode<X, Y>, const X: usize, const Y: usize> PartialOrd for HeatPathNode<N, X, Y> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-#[cfg(feature = "long-run-time")]
 impl<N: PathNode<X, Y>, const X: usize, const Y: usize> Ord for HeatPathNode<N, X, Y> {
     fn cmp(&self, other: &Self) -> Ordering {
         // reverse order to use std::collections::BinaryHeap as Min-heap by switching position of self and other
@@ -191,14 +175,12 @@ impl<N: PathNode<X, Y>, const X: usize, const Y: usize> Ord for HeatPathNode<N,
     }
 }
 
-#[cfg(feature = "long-run-time")]
 impl<N: PathNode<X, Y>, const X: usize, const Y: usize> PartialEq for HeatPathNode<N, X, Y> {
     fn eq(&self, other: &Self) -> bool {
         self.accumulated_heat_loss == other.accumulated_heat_loss
     }
 }
 
-#[cfg(feature = "long-run-time")]
 impl<N: PathNode<X, Y>, const X: usize, const Y: usize> HeatPathNode<N, X, Y> {
     fn step_forward(&self, map: &MyMap2D<u64, X, Y>) -> Option<Self> {
         if let Some(next_patch_node) = self.path_node.step_forward() {
@@ -232,14 +214,12 @@ impl<N: PathNode<X, Y>, const X: usize, const Y: usize> HeatPathNode<N, X, Y> {
     }
 }
 
-#[cfg(feature = "long-run-time")]
 struct CityMap<N: PathNode<X, Y>, const X: usize, const Y: usize> {
     map: MyMap2D<u64, X, Y>,
     seen_cache: Vec<N>,
     b_heap: BinaryHeap<HeatPathNode<N, X, Y>>,
 }
 
-#[cfg(feature = "long-run-time")]
 impl<N: PathNode<X, Y>, const X: usize, const Y: usize> CityMap<N, X, Y> {
     fn new(input: &str) -> Self {
         let mut map: MyMap2D<u64, X, Y> = MyMap2D::default();
@@ -295,25 +275,26 @@ impl<N: PathNode<X, Y>, const X: usize, const Y: usize> CityMap<N, X, Y> {
 // solution is again inspired by HyperNeutrino
 // see https://www.youtube.com/watch?v=2pDSooPLLkI
 
-pub fn day_17() -> Result<()> {
-    #[cfg(feature = "long-run-time")]
-    {
-        let input = include_str!("../../../../aoc_input/aoc-2023/day_17.txt");
-        let mut city_map = CityMap::<NormalCrucible<X, Y>, X, Y>::new(input);
-        let result_part1 = city_map.get_minimum_heat_loss()?;
-        println!("result day 17 part 1: {}", result_part1);
-        assert_eq!(result_part1, 1099);
-        let mut city_map = CityMap::<UltraCrucible<X, Y>, X, Y>::new(input);
-        let result_part2 = city_map.get_minimum_heat_loss().unwrap();
-        println!("result day 17 part 2: {}", result_part2);
-        assert_eq!(result_part2, 1266);
+pub struct Day17 {
+    input: &'static str,
+}
+
+impl Solution for Day17 {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
+
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
     }
-    #[cfg(feature = "short-run-time")]
-    {
-        println!("day 17 skipped because of long run time")
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let mut city_map = CityMap::<NormalCrucible<X, Y>, X, Y>::new(self.input);
+        city_map.get_minimum_heat_loss()
     }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let mut city_map = CityMap::<UltraCrucible<X, Y>, X, Y>::new(self.input);
+        city_map.get_minimum_heat_loss()
+    }
 }
 
 #[cfg(all(test, feature = "long-run-time"))]
@@ -1,13 +1,15 @@
 //!day_18.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 // https://www.youtube.com/watch?v=bGWK76_e-LM
 // https://de.wikipedia.org/wiki/Gau%C3%9Fsche_Trapezformel
 // https://de.wikipedia.org/wiki/Satz_von_Pick
 
 #[derive(Default)]
-struct LavaLagoon {
+pub struct LavaLagoon {
     points_task_1: Vec<(i64, i64)>,
     boundary_points_task1: i64,
     points_task_2: Vec<(i64, i64)>,
@@ -102,16 +104,18 @@ impl LavaLagoon {
     }
 }
 
-pub fn day_18() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_18.txt");
-    let lava_lagoon = LavaLagoon::from_input(input)?;
-    let cubics_task1 = lava_lagoon.calc_cubics(false);
-    println!("result day 18 part 1: {}", cubics_task1);
-    assert_eq!(cubics_task1, 42_317);
-    let cubics_task2 = lava_lagoon.calc_cubics(true);
-    println!("result day 18 part 2: {}", cubics_task2);
-    assert_eq!(cubics_task2, 83_605_563_360_288);
-    Ok(())
+impl Solution for LavaLagoon {
+    fn parse(input: &'static str) -> Result<Self> {
+        Self::from_input(input)
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.calc_cubics(false))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.calc_cubics(true))
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 //!day_19.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 type RuleSet = HashMap<String, Vec<Rule>>;
 
@@ -384,23 +386,32 @@ impl MachinePartRange {
     }
 }
 
-pub fn day_19() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_19.txt");
-    let (rules, machine_parts) = input.split_once("\n\n").unwrap();
-    let rule_set = rule_set_from_str(rules)?;
-    let machine_parts: Vec<MachinePart> = machine_parts.lines().map(MachinePart::from).collect();
-    let mut result_part1 = 0;
-    for machine_part in machine_parts.iter() {
-        result_part1 += machine_part.calc_machine_part_rating(&rule_set)?;
+pub struct Day19 {
+    rule_set: RuleSet,
+    machine_parts: Vec<MachinePart>,
+}
+
+impl Solution for Day19 {
+    fn parse(input: &'static str) -> Result<Self> {
+        let (rules, machine_parts) = input.split_once("\n\n").ok_or(anyhow!("bad input"))?;
+        Ok(Self {
+            rule_set: rule_set_from_str(rules)?,
+            machine_parts: machine_parts.lines().map(MachinePart::from).collect(),
+        })
     }
-    println!("result day 19 part 1: {}", result_part1);
-    assert_eq!(result_part1, 383_682);
 
-    let mut machine_part_range = MachinePartRange::new();
-    let result_part2 = machine_part_range.calc_machine_part_combinations(&rule_set)?;
-    eprintln!("result day 19 part 2: {}", result_part2);
-    assert_eq!(result_part2, 117_954_800_808_317);
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        let mut result = 0;
+        for machine_part in self.machine_parts.iter() {
+            result += machine_part.calc_machine_part_rating(&self.rule_set)?;
+        }
+        Ok(result)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        let mut machine_part_range = MachinePartRange::new();
+        machine_part_range.calc_machine_part_combinations(&self.rule_set)
+    }
 }
 
 #[cfg(test)]
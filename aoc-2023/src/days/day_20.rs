@@ -1,8 +1,10 @@
 //!day_20.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use num::integer::lcm;
 use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
 
 #[derive(Default)]
 struct FlipFlop {
@@ -270,15 +272,31 @@ impl Server {
     }
 }
 
-pub fn day_20() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_20.txt");
-    let mut server = Server::from_str(input)?;
-    let (result_part1, result_part2) = server.run()?;
-    println!("result day 20 part 1: {}", result_part1);
-    assert_eq!(result_part1, 825_167_435);
-    println!("result day 20 part 2: {}", result_part2);
-    assert_eq!(result_part2, 225_514_321_828_633);
-    Ok(())
+pub struct Day20 {
+    input: &'static str,
+    result_part2: Option<u64>,
+}
+
+impl Solution for Day20 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let mut server = Server::from_str(self.input)?;
+        let (result_part1, result_part2) = server.run()?;
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
@@ -1,15 +1,17 @@
 //!day_21.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_compass::Compass;
 use my_lib::my_map_point::MapPoint;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
 // values taken from ../../../../aoc_input/aoc-2023/day_21.txt
 const X: usize = 131;
 const Y: usize = 131;
 
-struct Garden<const X: usize, const Y: usize> {
+pub struct Garden<const X: usize, const Y: usize> {
     map: MyMap2D<char, X, Y>,
 }
 
@@ -108,24 +110,24 @@ impl<const X: usize, const Y: usize> Garden<X, Y> {
     }
 }
 
-pub fn day_21() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_21.txt");
-    let garden = Garden::<X, Y>::from_str(input);
-    let steps_to_take = 64;
-    let start_point = garden
-        .map
-        .iter()
-        .find(|(_, c)| **c == 'S')
-        .map(|(p, _)| p)
-        .unwrap();
-    let result_part1 = garden.count_steps(start_point, steps_to_take, true);
-    println!("result day 21 part 1: {}", result_part1);
-    assert_eq!(result_part1, 3_697);
-    let steps_to_take = 26_501_365;
-    let result_part2 = garden.count_steps_infinite_garden(steps_to_take);
-    println!("result day 21 part 2: {}", result_part2);
-    assert_eq!(result_part2, 608_152_828_731_262);
-    Ok(())
+impl<const X: usize, const Y: usize> Solution for Garden<X, Y> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from_str(input))
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let start_point = self
+            .map
+            .iter()
+            .find(|(_, c)| **c == 'S')
+            .map(|(p, _)| p)
+            .unwrap();
+        Ok(self.count_steps(start_point, 64, true))
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.count_steps_infinite_garden(26_501_365))
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 //!day_22.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 use std::{
     cmp::Ordering,
     collections::{HashMap, VecDeque},
@@ -230,8 +232,7 @@ fn bricks_to_disintegrate_with_consequence(
     falling_bricks.values().map(|fb| fb.len()).sum()
 }
 
-pub fn day_22() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_22.txt");
+fn solve(input: &str) -> (usize, usize) {
     let mut supporting_bricks: HashMap<Brick, Vec<Brick>> = HashMap::new();
     let mut supported_bricks: HashMap<Brick, Vec<Brick>> = HashMap::new();
     let mut falling_bricks: HashMap<Brick, Vec<Brick>> = HashMap::new();
@@ -242,11 +243,7 @@ pub fn day_22() -> Result<()> {
 
     let bricks_save_to_disintegrate =
         bricks_to_disintegrate_without_consequence(&bricks, &supporting_bricks, &supported_bricks);
-    println!(
-        "result day 22 part 1: {}",
-        bricks_save_to_disintegrate.len()
-    );
-    assert_eq!(bricks_save_to_disintegrate.len(), 471);
+    let result_part1 = bricks_save_to_disintegrate.len();
 
     let result_part2 = bricks_to_disintegrate_with_consequence(
         &bricks,
@@ -255,10 +252,33 @@ pub fn day_22() -> Result<()> {
         &supported_bricks,
         &mut falling_bricks,
     );
-    println!("result day 22 part 2: {}", result_part2);
-    assert_eq!(result_part2, 68_525);
+    (result_part1, result_part2)
+}
 
-    Ok(())
+pub struct Day22 {
+    input: &'static str,
+    result_part2: Option<usize>,
+}
+
+impl Solution for Day22 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            input,
+            result_part2: None,
+        })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = solve(self.input);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
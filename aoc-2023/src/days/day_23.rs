@@ -1,9 +1,12 @@
 //!day_23.rs
 
 use anyhow::Result;
+use aoc_common::{LONG_RUNTIME, Solution};
 use my_lib::{
     my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D, my_tree::TreeNode,
 };
+use std::fmt::Display;
+use std::time::Duration;
 use std::{collections::HashMap, rc::Rc};
 
 // values taken from ../../../../aoc_input/aoc-2023/day_23.txt
@@ -70,7 +73,7 @@ impl<const X: usize, const Y: usize> Node<X, Y> {
     }
 }
 
-struct Maze<const X: usize, const Y: usize> {
+pub struct Maze<const X: usize, const Y: usize> {
     maze: MyMap2D<char, X, Y>,
     start_point: MapPoint<X, Y>,
     end_point: MapPoint<X, Y>,
@@ -186,23 +189,21 @@ impl<'a, const X: usize, const Y: usize> Iterator for MazeRunner<'a, X, Y> {
     }
 }
 
-pub fn day_23() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_23.txt");
-    let mut maze = Maze::<X, Y>::from(input);
-    let result_part1 = maze.go_hiking();
-    println!("result day 23 part 1: {}", result_part1);
-    maze.climbing_is_possible = true;
-    #[cfg(feature = "long-run-time")]
-    {
-        let result_part2 = maze.go_hiking();
-        println!("result day 23 part 2: {}", result_part2);
-        assert_eq!(result_part2, 6322);
+impl<const X: usize, const Y: usize> Solution for Maze<X, Y> {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
+
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
     }
-    #[cfg(feature = "short-run-time")]
-    {
-        println!("day 23 part 2 skipped because of long run time")
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.go_hiking())
+    }
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.climbing_is_possible = true;
+        Ok(self.go_hiking())
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 //!day_24.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
+use std::fmt::Display;
 
 // for part 2 I use an equation solver
 use eqsolver::{
@@ -277,39 +279,40 @@ fn solve_task_2(hailstones: &[HailStone]) -> Result<u64> {
     Ok(solution as u64)
 }
 
-pub fn day_24() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_24.txt");
-    let hailstones: Vec<HailStone> = input.lines().map(HailStone::from).collect();
-    assert!(hailstones.iter().all(|h| h.vx.abs() > f64::EPSILON
-        && h.vy.abs() > f64::EPSILON
-        && h.vz.abs() > f64::EPSILON));
-
-    let min = 200_000_000_000_000.0;
-    let max = 400_000_000_000_000.0;
-
-    let mut result_part1 = 0;
-    for (i, hailstone_1) in hailstones.iter().enumerate() {
-        for hailstone_2 in hailstones.iter().skip(i + 1) {
-            if hailstone_1.check_intersection_in_x_y_boundaries(hailstone_2, min, max) {
-                result_part1 += 1;
+pub struct Day24 {
+    hailstones: Vec<HailStone>,
+}
+
+impl Solution for Day24 {
+    fn parse(input: &'static str) -> Result<Self> {
+        let hailstones = input.lines().map(HailStone::from).collect();
+        Ok(Self { hailstones })
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        let min = 200_000_000_000_000.0;
+        let max = 400_000_000_000_000.0;
+        let mut result = 0;
+        for (i, hailstone_1) in self.hailstones.iter().enumerate() {
+            for hailstone_2 in self.hailstones.iter().skip(i + 1) {
+                if hailstone_1.check_intersection_in_x_y_boundaries(hailstone_2, min, max) {
+                    result += 1;
+                }
             }
         }
+        Ok(result)
     }
-    eprintln!("result day 24 part 1: {}", result_part1);
-    assert_eq!(result_part1, 17_776);
 
-    // task 2
-    let result_part2 = solve_task_2(&hailstones)?;
-    eprintln!("result day 24 part 2: {}", result_part2);
-    assert_eq!(result_part2, 948_978_092_202_212);
-
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        solve_task_2(&self.hailstones)
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part_1() -> Result<()> {
@@ -342,7 +345,7 @@ mod tests {
 
     #[test]
     fn test_matrix_inversion() {
-        let input = include_str!("../../../../aoc_input/aoc-2023/day_24.txt");
+        let input = read_file(2023, "day_24.txt").unwrap();
         let hailstones: Vec<HailStone> = input.lines().map(HailStone::from).collect();
 
         let (x1, y1, z1, vx1, vy1, vz1) = hailstones[0].as_tuple();
@@ -2,11 +2,13 @@
 
 use anyhow::Result as AnyResult;
 use anyhow::anyhow;
+use aoc_common::Solution;
 use rustworkx_core::Result;
 use rustworkx_core::connectivity::stoer_wagner_min_cut;
 use rustworkx_core::petgraph::graph::{NodeIndex, UnGraph};
+use std::fmt::Display;
 
-struct WiringDiagramm<'a> {
+pub struct WiringDiagramm<'a> {
     graph: UnGraph<&'a str, u32>,
 }
 
@@ -57,13 +59,16 @@ impl<'a> WiringDiagramm<'a> {
     }
 }
 
-pub fn day_25() -> AnyResult<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2023/day_25.txt");
-    let wd = WiringDiagramm::from(input);
-    let result_part1 = wd.calc_min_set()?;
-    eprintln!("result day 24 part 1: {}", result_part1);
-    assert_eq!(result_part1, 562_978);
-    Ok(())
+impl Solution for WiringDiagramm<'static> {
+    const HAS_PART_2: bool = false;
+
+    fn parse(input: &'static str) -> AnyResult<Self> {
+        Ok(Self::from(input))
+    }
+
+    fn part_1(&mut self) -> AnyResult<impl Display> {
+        self.calc_min_set()
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,11 @@
 //!day_01.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day01Data {
+pub struct Day01Data {
     list_1: Vec<i128>,
     list_2: Vec<i128>,
 }
@@ -50,30 +52,31 @@ impl Day01Data {
     }
 }
 
-pub fn day_01() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_01.txt");
-    let mut challenge = Day01Data::from(input);
-    challenge.sort();
-
-    let result_part1 = challenge.add_up_delta();
-    println!("result day 01 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_660_292);
+impl Solution for Day01Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        let mut challenge = Self::from(input);
+        challenge.sort();
+        Ok(challenge)
+    }
 
-    let result_part2 = challenge.calc_similarity_score();
-    println!("result day 01 part 2: {}", result_part2);
-    assert_eq!(result_part2, 22_776_016);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.add_up_delta())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.calc_similarity_score())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_01_example.txt");
+        let input = read_file(2024, "day_01_example.txt")?;
         let mut challenge = Day01Data::from(input);
         challenge.sort();
 
@@ -1,9 +1,11 @@
 //!day_02.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day02Data {
+pub struct Day02Data {
     levels: Vec<Vec<i128>>,
 }
 
@@ -82,29 +84,29 @@ impl Day02Data {
     }
 }
 
-pub fn day_02() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_02.txt");
-    let challenge = Day02Data::from(input);
-
-    let result_part1 = challenge.count_save_reports(false);
-    println!("result day 02 part 1: {}", result_part1);
-    assert_eq!(result_part1, 287);
+impl Solution for Day02Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.count_save_reports(true);
-    println!("result day 02 part 2: {}", result_part2);
-    assert_eq!(result_part2, 354);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.count_save_reports(false))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.count_save_reports(true))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_02_example.txt");
+        let input = read_file(2024, "day_02_example.txt")?;
         let challenge = Day02Data::from(input);
 
         let result_part1 = challenge.count_save_reports(false);
@@ -1,7 +1,9 @@
 //!day_03.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use regex::Regex;
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct Day03Data {
@@ -69,37 +71,40 @@ impl Day03DataWithFlags {
     }
 }
 
-pub fn day_03() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_03.txt");
-    let challenge = Day03Data::from(input);
+pub struct Day03 {
+    input: &'static str,
+}
 
-    let result_part1 = challenge.add_up_multiplied_factors();
-    println!("result day 03 part 1: {}", result_part1);
-    assert_eq!(result_part1, 171_183_089);
+impl Solution for Day03 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
 
-    let challenge = Day03DataWithFlags::from(input);
-    let result_part2 = challenge.add_up_multiplied_factors();
-    println!("result day 03 part 2: {}", result_part2);
-    assert_eq!(result_part2, 63_866_497);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(Day03Data::from(self.input).add_up_multiplied_factors())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(Day03DataWithFlags::from(self.input).add_up_multiplied_factors())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_03_example_01.txt");
+        let input = read_file(2024, "day_03_example_01.txt")?;
         let challenge = Day03Data::from(input);
 
         let result_part1 = challenge.add_up_multiplied_factors();
         println!("result day 03 part 1: {}", result_part1);
         assert_eq!(result_part1, 161);
 
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_03_example_02.txt");
+        let input = read_file(2024, "day_03_example_02.txt")?;
         let challenge = Day03DataWithFlags::from(input);
         let result_part2 = challenge.add_up_multiplied_factors();
         println!("result day 03 part 2: {}", result_part2);
@@ -1,10 +1,12 @@
 //!day_04.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day04Data<const N: usize> {
+pub struct Day04Data<const N: usize> {
     puzzle: MyMap2D<char, N, N>,
 }
 
@@ -56,34 +58,34 @@ impl<const N: usize> Day04Data<N> {
 }
 
 // taken from ../../../../aoc_input/aoc-2024/day_04.txt
-const N: usize = 140;
-
-pub fn day_04() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_04.txt");
-    let challenge = Day04Data::<N>::from(input);
-
-    let result_part1 = challenge.count_xmas();
-    println!("result day 04 part 1: {}", result_part1);
-    assert_eq!(result_part1, 2_427);
+impl<const N: usize> Solution for Day04Data<N> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.count_cross_mas();
-    println!("result day 04 part 2: {}", result_part2);
-    assert_eq!(result_part2, 1900);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.count_xmas())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.count_cross_mas())
+    }
 }
 
+const N: usize = 140;
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     // taken from ../../../../aoc_input/aoc-2024/day_04_example.txt
     const E: usize = 10;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_04_example.txt");
+        let input = read_file(2024, "day_04_example.txt")?;
         let challenge = Day04Data::<E>::from(input);
 
         let result_part1 = challenge.count_xmas();
@@ -1,11 +1,13 @@
 //!day_05.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day05Data {
+pub struct Day05Data {
     rules: HashMap<u32, Vec<u32>>,
     updates: Vec<Vec<u32>>,
 }
@@ -95,29 +97,29 @@ impl Day05Data {
     }
 }
 
-pub fn day_05() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_05.txt");
-    let challenge = Day05Data::from(input);
-
-    let result_part1 = challenge.add_middle_numbers_of_correct_order_updates();
-    println!("result day 05 part 1: {}", result_part1);
-    assert_eq!(result_part1, 5_732);
+impl Solution for Day05Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.add_middle_numbers_of_incorrect_order_updates();
-    println!("result day 05 part 2: {}", result_part2);
-    assert_eq!(result_part2, 4_716);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.add_middle_numbers_of_correct_order_updates())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.add_middle_numbers_of_incorrect_order_updates())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_05_example.txt");
+        let input = read_file(2024, "day_05_example.txt")?;
         let challenge = Day05Data::from(input);
 
         let result_part1 = challenge.add_middle_numbers_of_correct_order_updates();
@@ -1,10 +1,12 @@
 //!day_06.rs
 
-#[cfg(feature = "long-run-time")]
 use crate::utilities::SnapshotHashSet;
 use anyhow::Result;
+use aoc_common::{Solution, LONG_RUNTIME};
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::collections::HashSet;
+use std::fmt::Display;
+use std::time::Duration;
 
 struct IterMap<'a, const N: usize> {
     map: &'a MyMap2D<char, N, N>,
@@ -38,7 +40,7 @@ impl<'a, const N: usize> Iterator for IterMap<'a, N> {
 }
 
 #[derive(Debug)]
-struct Day06Data<const N: usize> {
+pub struct Day06Data<const N: usize> {
     map: MyMap2D<char, N, N>,
     start_tile: MapPoint<N, N>,
 }
@@ -65,7 +67,6 @@ impl<const N: usize> Day06Data<N> {
         (visited_tiles.len(), path)
     }
 
-    #[cfg(feature = "long-run-time")]
     fn count_possible_loop_blocks(&mut self, path: Vec<(MapPoint<N, N>, Compass)>) -> usize {
         let mut visited_tiles: SnapshotHashSet<(MapPoint<N, N>, Compass)> =
             SnapshotHashSet::with_capacity(N);
@@ -105,59 +106,46 @@ impl<const N: usize> Day06Data<N> {
 }
 
 // taken from ../../../../aoc_input/aoc-2024/day_06.txt
-const N: usize = 130;
-
-pub fn day_06() -> Result<()> {
-    println!("Happy Nikolaus!");
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_06.txt");
-
-    #[cfg(feature = "long-run-time")]
-    let mut challenge = Day06Data::<N>::from(input);
-
-    #[cfg(not(feature = "long-run-time"))]
-    let challenge = Day06Data::<N>::from(input);
-
-    #[cfg(feature = "long-run-time")]
-    let (result_part1, path) = challenge.count_visited_map_tiles();
-
-    #[cfg(not(feature = "long-run-time"))]
-    let (result_part1, _) = challenge.count_visited_map_tiles();
+impl<const N: usize> Solution for Day06Data<N> {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
 
-    println!("result day 06 part 1: {}", result_part1);
-    assert_eq!(result_part1, 4_826);
-
-    #[cfg(feature = "long-run-time")]
-    {
-        let result_part2 = challenge.count_possible_loop_blocks(path);
-        println!("result day 06 part 2: {}", result_part2);
-        assert_eq!(result_part2, 1_721);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
     }
-    #[cfg(not(feature = "long-run-time"))]
-    {
-        println!("day 06 part 2 skipped because of long run time");
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.count_visited_map_tiles().0)
     }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let (_, path) = self.count_visited_map_tiles();
+        Ok(self.count_possible_loop_blocks(path))
+    }
 }
 
+const N: usize = 130;
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     // taken from ../../../../aoc_input/aoc-2024/day_06_example.txt
     const E: usize = 10;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_06_example.txt");
-        let mut challenge = Day06Data::<E>::from(input);
+        let input = read_file(2024, "day_06_example.txt")?;
+        let challenge = Day06Data::<E>::from(input);
 
-        let (result_part1, path) = challenge.count_visited_map_tiles();
+        let (result_part1, _) = challenge.count_visited_map_tiles();
         println!("result day 06 part 1: {}", result_part1);
         assert_eq!(result_part1, 41);
 
         #[cfg(feature = "long-run-time")]
         {
+            let mut challenge = Day06Data::<E>::from(input);
+            let (_, path) = challenge.count_visited_map_tiles();
             let result_part2 = challenge.count_possible_loop_blocks(path);
             println!("result day 06 part 2: {}", result_part2);
             assert_eq!(result_part2, 6);
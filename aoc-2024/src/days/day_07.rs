@@ -2,6 +2,8 @@
 
 use crate::utilities::digit_count;
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct TestData {
@@ -52,7 +54,7 @@ impl TestData {
 }
 
 #[derive(Debug)]
-struct Day07Data {
+pub struct Day07Data {
     samples: Vec<TestData>,
 }
 
@@ -81,29 +83,29 @@ impl Day07Data {
     }
 }
 
-pub fn day_07() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_07.txt");
-    let challenge = Day07Data::from(input);
-
-    let result_part1 = challenge.test_samples();
-    println!("result day 07 part 1: {}", result_part1);
-    assert_eq!(result_part1, 20_665_830_408_335);
+impl Solution for Day07Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.test_samples_with_concatenation();
-    println!("result day 07 part 2: {}", result_part2);
-    assert_eq!(result_part2, 354_060_705_047_464);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.test_samples())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.test_samples_with_concatenation())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_07_example.txt");
+        let input = read_file(2024, "day_07_example.txt")?;
         let challenge = Day07Data::from(input);
 
         let result_part1 = challenge.test_samples();
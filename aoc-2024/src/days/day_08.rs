@@ -1,11 +1,13 @@
 //!day_08.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day08Data {
+pub struct Day08Data {
     antennas: HashMap<char, Vec<Point>>,
     max_x: i64,
     max_y: i64,
@@ -110,25 +112,25 @@ impl Day08Data {
     }
 }
 
-pub fn day_08() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_08.txt");
-    let challenge = Day08Data::from(input);
-
-    let result_part1 = challenge.count_anti_nodes().len();
-    println!("result day 08 part 1: {}", result_part1);
-    assert_eq!(result_part1, 361);
+impl Solution for Day08Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.count_anti_nodes_extended().len();
-    println!("result day 08 part 2: {}", result_part2);
-    assert_eq!(result_part2, 1_249);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.count_anti_nodes().len())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.count_anti_nodes_extended().len())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     use my_lib::my_map_point::MapPoint;
     use my_lib::my_map_two_dim::MyMap2D;
 
@@ -136,7 +138,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_08_example.txt");
+        let input = read_file(2024, "day_08_example.txt")?;
         let challenge = Day08Data::from(input);
 
         let result_part1 = challenge.count_anti_nodes();
@@ -1,9 +1,11 @@
 //!day_09.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Debug, Clone)]
-struct Day09Data {
+pub struct Day09Data {
     blocks: Vec<Option<usize>>,
     max_block_id: usize,
 }
@@ -161,28 +163,28 @@ impl Day09Data {
     }
 }
 
-pub fn day_09() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_09.txt");
-    let mut challenge = Day09Data::from(input);
-    let mut challenge_part2 = challenge.clone();
-    challenge.compacting_blocks();
-
-    let result_part1 = challenge.calc_check_sum();
-    println!("result day 09 part 1: {}", result_part1);
-    assert_eq!(result_part1, 6_307_275_788_409);
+impl Solution for Day09Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    challenge_part2.compacting_files();
-    let result_part2 = challenge_part2.calc_check_sum();
-    println!("result day 09 part 2: {}", result_part2);
-    assert_eq!(result_part2, 6_327_174_563_252);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let mut challenge = self.clone();
+        challenge.compacting_blocks();
+        Ok(challenge.calc_check_sum())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.compacting_files();
+        Ok(self.calc_check_sum())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     use std::fmt::Display;
 
     impl Display for Day09Data {
@@ -199,7 +201,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_09_example.txt");
+        let input = read_file(2024, "day_09_example.txt")?;
         let mut challenge = Day09Data::from(input);
         let mut challenge_part2 = challenge.clone();
         assert_eq!(
@@ -1,12 +1,15 @@
 //!day_10.rs
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc_common::Solution;
 use my_lib::{my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day10Data<const N: usize> {
+pub struct Day10Data<const N: usize> {
     map: MyMap2D<u8, N, N>,
+    result_part2: Option<usize>,
 }
 
 impl<const N: usize> From<&str> for Day10Data<N> {
@@ -17,7 +20,10 @@ impl<const N: usize> From<&str> for Day10Data<N> {
                 map.set((x, y).into(), height.to_digit(10).unwrap() as u8);
             }
         }
-        Self { map }
+        Self {
+            map,
+            result_part2: None,
+        }
     }
 }
 
@@ -59,31 +65,36 @@ impl<const N: usize> Day10Data<N> {
     }
 }
 
-const N: usize = 43;
-
-pub fn day_10() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_10.txt");
-    let challenge = Day10Data::<N>::from(input);
-
-    let (result_part1, result_part2) = challenge.walk_trails_to_peaks();
-    println!("result day 10 part 1: {}", result_part1);
-    assert_eq!(result_part1, 501);
+impl<const N: usize> Solution for Day10Data<N> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day 10 part 2: {}", result_part2);
-    assert_eq!(result_part2, 1_017);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.walk_trails_to_peaks();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
+const N: usize = 43;
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     const E: usize = 8;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_10_example.txt");
+        let input = read_file(2024, "day_10_example.txt")?;
         let challenge = Day10Data::<E>::from(input);
 
         let (result_part1, result_part2) = challenge.walk_trails_to_peaks();
@@ -2,10 +2,12 @@
 
 use crate::utilities::digit_count;
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day11Data {
+pub struct Day11Data {
     challenge: Vec<u128>,
     blinks: u32,
 }
@@ -67,31 +69,31 @@ fn calc_stones_recursive(
     stones
 }
 
-pub fn day_11() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_11.txt");
-    let mut challenge = Day11Data::from(input);
-    challenge.set_blinks(25);
-
-    let result_part1 = challenge.calc_stones();
-    println!("result day 11 part 1: {}", result_part1);
-    assert_eq!(result_part1, 217_812);
+impl Solution for Day11Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    challenge.set_blinks(75);
-    let result_part2 = challenge.calc_stones();
-    println!("result day 11 part 2: {}", result_part2);
-    assert_eq!(result_part2, 259_112_729_857_522);
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.set_blinks(25);
+        Ok(self.calc_stones())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.set_blinks(75);
+        Ok(self.calc_stones())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_11_example.txt");
+        let input = read_file(2024, "day_11_example.txt")?;
         let mut challenge = Day11Data::from(input);
         challenge.set_blinks(25);
 
@@ -1,11 +1,13 @@
 //!day_12.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day12Data<const N: usize> {
+pub struct Day12Data<const N: usize> {
     garden: MyMap2D<char, N, N>,
 }
 
@@ -122,41 +124,41 @@ impl<const N: usize> Day12Data<N> {
 }
 
 // value taken from ../../../../aoc_input/aoc-2024/day_12.txt
-const N: usize = 140;
-
-pub fn day_12() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_12.txt");
-    let challenge = Day12Data::<N>::from(input);
-
-    let result_part1 = challenge.get_fence_price();
-    println!("result day 12 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_485_656);
+impl<const N: usize> Solution for Day12Data<N> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.get_fence_price_with_discount();
-    println!("result day 12 part 2: {}", result_part2);
-    assert_eq!(result_part2, 899_196);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.get_fence_price())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.get_fence_price_with_discount())
+    }
 }
 
+const N: usize = 140;
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     // value taken from ../../../../aoc_input/aoc-2024/day_12_example.txt
     const E: usize = 10;
     const S: usize = 6;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_12_example_01.txt");
+        let input = read_file(2024, "day_12_example_01.txt")?;
         let challenge = Day12Data::<E>::from(input);
 
         let result_part1 = challenge.get_fence_price();
         println!("result day 12 part 1: {}", result_part1);
         assert_eq!(result_part1, 1_930);
 
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_12_example_02.txt");
+        let input = read_file(2024, "day_12_example_02.txt")?;
         let challenge_02 = Day12Data::<S>::from(input);
 
         let result_part2_2 = challenge_02.get_fence_price_with_discount();
@@ -1,8 +1,10 @@
 //!day_13.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::{my_line::Line, my_point::Point};
 use regex::Regex;
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct ClawMachine {
@@ -54,7 +56,7 @@ impl ClawMachine {
 }
 
 #[derive(Debug)]
-struct Day13Data {
+pub struct Day13Data {
     arcade: Vec<ClawMachine>,
 }
 
@@ -75,29 +77,29 @@ impl Day13Data {
     }
 }
 
-pub fn day_13() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_13.txt");
-    let challenge = Day13Data::from(input);
-
-    let result_part1 = challenge.calc_tokens(0);
-    println!("result day 13 part 1: {}", result_part1);
-    assert_eq!(result_part1, 25_629);
+impl Solution for Day13Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.calc_tokens(10_000_000_000_000);
-    println!("result day 13 part 2: {}", result_part2);
-    assert_eq!(result_part2, 107_487_112_929_999);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.calc_tokens(0))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.calc_tokens(10_000_000_000_000))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_13_example.txt");
+        let input = read_file(2024, "day_13_example.txt")?;
         let challenge = Day13Data::from(input);
 
         let result_part1 = challenge.calc_tokens(0);
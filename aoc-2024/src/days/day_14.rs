@@ -1,9 +1,11 @@
 //!day_14.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use regex::Regex;
 use std::cmp::Ordering;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct Robot {
@@ -26,7 +28,7 @@ impl Robot {
 }
 
 #[derive(Debug, Clone)]
-struct Day14Data {
+pub struct Day14Data {
     robots: Vec<Robot>,
     width: i64,
     height: i64,
@@ -111,26 +113,25 @@ impl Day14Data {
     }
 }
 
-pub fn day_14() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_14.txt");
-    let mut challenge = Day14Data::from(input);
-    let mut tree_search = challenge.clone();
-
-    let result_part1 = challenge.move_seconds(100);
-    println!("result day 14 part 1: {}", result_part1);
-    assert_eq!(result_part1, 215_987_200);
+impl Solution for Day14Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = tree_search.search_the_tree();
-    println!("result day 14 part 2: {}", result_part2);
-    assert_eq!(result_part2, 8050);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.clone().move_seconds(100))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.search_the_tree())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     use std::fmt::Display;
 
     impl Display for Day14Data {
@@ -157,7 +158,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_14_example.txt");
+        let input = read_file(2024, "day_14_example.txt")?;
         let mut challenge = Day14Data::from(input);
         challenge.width = 11;
         challenge.height = 7;
@@ -1,8 +1,10 @@
 //!day_15.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct Day15Data<const N: usize> {
@@ -222,28 +224,37 @@ impl<const N: usize, const M: usize> TwiceAsWide<N, M> {
 const N: usize = 50;
 const M: usize = 100;
 
-pub fn day_15() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_15.txt");
-    let mut challenge = Day15Data::<N>::from(input);
-    let mut twice_as_wide = TwiceAsWide::<N, M>::from(&challenge);
-    challenge.execute_robot_moves();
+pub struct Day15 {
+    challenge: Day15Data<N>,
+    twice_as_wide: TwiceAsWide<N, M>,
+}
 
-    let result_part1 = challenge.calc_gps_sum();
-    println!("result day 15 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_438_161);
+impl Solution for Day15 {
+    fn parse(input: &'static str) -> Result<Self> {
+        let challenge = Day15Data::<N>::from(input);
+        let twice_as_wide = TwiceAsWide::<N, M>::from(&challenge);
+        Ok(Self {
+            challenge,
+            twice_as_wide,
+        })
+    }
 
-    twice_as_wide.execute_robot_moves();
-    let result_part2 = twice_as_wide.calc_gps_sum();
-    println!("result day 15 part 2: {}", result_part2);
-    assert_eq!(result_part2, 1_437_981);
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.challenge.execute_robot_moves();
+        Ok(self.challenge.calc_gps_sum())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.twice_as_wide.execute_robot_moves();
+        Ok(self.twice_as_wide.calc_gps_sum())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     // taken from ../../../../aoc_input/aoc-2024/day_15_example_01.txt
     const S: usize = 8;
@@ -253,8 +264,8 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let example_01 = include_str!("../../../../aoc_input/aoc-2024/day_15_example_01.txt");
-        let example_02 = include_str!("../../../../aoc_input/aoc-2024/day_15_example_02.txt");
+        let example_01 = read_file(2024, "day_15_example_01.txt")?;
+        let example_02 = read_file(2024, "day_15_example_02.txt")?;
         let mut challenge_01 = Day15Data::<S>::from(example_01);
         let mut challenge_02 = Day15Data::<B>::from(example_02);
         let mut twice_as_wide = TwiceAsWide::<B, T>::from(&challenge_02);
@@ -269,7 +280,7 @@ mod tests {
         println!("result day 15 part 1, example 1: {}", result_part1_2);
         assert_eq!(result_part1_2, 10_092);
 
-        let example_03 = include_str!("../../../../aoc_input/aoc-2024/day_15_example_03.txt");
+        let example_03 = read_file(2024, "day_15_example_03.txt")?;
         let example_03 = Day15Data::<7>::from(example_03);
         let mut example_03_twice_as_wide = TwiceAsWide::<7, 14>::from(&example_03);
         println!("{}", example_03_twice_as_wide.map);
@@ -1,14 +1,17 @@
 //!day_16.rs
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day16Data<const N: usize> {
+pub struct Day16Data<const N: usize> {
     map: MyMap2D<char, N, N>,
     start: MapPoint<N, N>,
     end: MapPoint<N, N>,
+    result_part2: Option<usize>,
 }
 
 impl<const N: usize> From<&str> for Day16Data<N> {
@@ -24,7 +27,12 @@ impl<const N: usize> From<&str> for Day16Data<N> {
             .find(|(_, c)| **c == 'E')
             .map(|(s, _)| s)
             .unwrap();
-        Self { map, start, end }
+        Self {
+            map,
+            start,
+            end,
+            result_part2: None,
+        }
     }
 }
 
@@ -108,26 +116,31 @@ impl<const N: usize> Day16Data<N> {
 }
 
 // taken from ../../../../aoc_input/aoc-2024/day_16.txt
-const N: usize = 141;
-
-pub fn day_16() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_16.txt");
-    let challenge = Day16Data::<N>::from(input);
-
-    let (result_part1, result_part2) = challenge.get_min_score_and_num_best_path_tiles();
-    println!("result day 16 part 1: {}", result_part1);
-    assert_eq!(result_part1, 83_432);
+impl<const N: usize> Solution for Day16Data<N> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day 16 part 2: {}", result_part2);
-    assert_eq!(result_part2, 467);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.get_min_score_and_num_best_path_tiles();
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
+const N: usize = 141;
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     // taken from ../../../../aoc_input/aoc-2024/day_16_example_01.txt
     const E01: usize = 15;
     // taken from ../../../../aoc_input/aoc-2024/day_16_example_02.txt
@@ -135,7 +148,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input_01 = include_str!("../../../../aoc_input/aoc-2024/day_16_example_01.txt");
+        let input_01 = read_file(2024, "day_16_example_01.txt")?;
         let challenge_01 = Day16Data::<E01>::from(input_01);
         let (result_part1_1, result_part2_1) = challenge_01.get_min_score_and_num_best_path_tiles();
         println!("result day 16 part 1_1: {}", result_part1_1);
@@ -143,7 +156,7 @@ mod tests {
         println!("result day 16 part 2_1: {}", result_part2_1);
         assert_eq!(result_part2_1, 45);
 
-        let input_02 = include_str!("../../../../aoc_input/aoc-2024/day_16_example_02.txt");
+        let input_02 = read_file(2024, "day_16_example_02.txt")?;
         let challenge_02 = Day16Data::<E02>::from(input_02);
         let (result_part1_2, result_part2_2) = challenge_02.get_min_score_and_num_best_path_tiles();
         println!("result day 16 part 1_2: {}", result_part1_2);
@@ -1,9 +1,11 @@
 //!day_17.rs
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
+use aoc_common::Solution;
 use regex::Regex;
+use std::fmt::Display;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 enum Instruction {
     Adv(u128),
     Bxl(u128),
@@ -68,7 +70,7 @@ impl From<(u128, u128)> for Instruction {
 }
 
 impl Instruction {
-    fn apply(&self, reg: &mut Registers, out: &mut Vec<String>) -> Option<usize> {
+    fn apply(&self, reg: &mut Registers, out: &mut Vec<u128>) -> Option<usize> {
         match self {
             Instruction::Adv(op) => {
                 // is equal to: reg.a /= 2_u128.pow(reg.combo_operator(*op));
@@ -92,7 +94,7 @@ impl Instruction {
             Instruction::Out(op) => {
                 // is equal to: op_mod = reg.combo_operator(*op) % 8;
                 let op_mod = reg.combo_operator(*op) & 7;
-                out.push(op_mod.to_string());
+                out.push(op_mod);
             }
             Instruction::Bdv(op) => {
                 // is equal to: reg.b = reg.a / 2_u128.pow(reg.combo_operator(*op));
@@ -107,7 +109,7 @@ impl Instruction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Registers {
     a: u128,
     b: u128,
@@ -150,7 +152,7 @@ impl Registers {
 }
 
 #[derive(Debug)]
-struct Day17Data {
+pub struct Day17Data {
     reg: Registers,
     ins: Vec<Instruction>,
     part_2: Vec<u128>,
@@ -175,99 +177,150 @@ impl From<&str> for Day17Data {
 }
 
 impl Day17Data {
-    fn execute(&mut self) -> String {
+    // Output of program with initial registers, which are kept for part 2.
+    fn execute(&self) -> String {
+        let mut reg = self.reg.clone();
+        let out = Self::run(&self.ins, &mut reg);
+        let out: Vec<String> = out.iter().map(|o| o.to_string()).collect();
+        out.join(",")
+    }
+    fn run(ins: &[Instruction], reg: &mut Registers) -> Vec<u128> {
         let mut index = 0;
-        let mut out: Vec<String> = Vec::new();
-        while index < self.ins.len() {
-            if let Some(jump_index) = self.ins[index].apply(&mut self.reg, &mut out) {
+        let mut out: Vec<u128> = Vec::new();
+        while index < ins.len() {
+            if let Some(jump_index) = ins[index].apply(reg, &mut out) {
                 index = jump_index;
             } else {
                 index += 1;
             }
         }
-        out.join(",")
+        out
+    }
+    // Output of program with register a set to `a` and initial registers b and c.
+    fn run_with_a(&self, a: u128) -> Vec<u128> {
+        let mut reg = self.reg.clone();
+        reg.a = a;
+        Self::run(&self.ins, &mut reg)
     }
-    // some reverse engineering, what is really happening
-    // Bst(4) -> b = a & 7 (grab first 3 bits of a; 7 = 111)
-    // Bxl(6) -> b = b ^ 6 (6 = 110)
-    // Cdv(5) -> c = a >> b (shift b bits to the right; only first 3 bits are relevant because of Out(5), s. below)
-    // Bxc    -> b = b ^ c
-    // Bxl(7) -> b = b ^ 7 (7 = 111)
-    // Adv(3) -> a = a >> 3 (shift 3 bits to the right)
-    // Out(5) -> out = b & 7 (grab first 3 bits of b and collect them for output; 7 = 111)
-    // Jnz(0) -> end program if a == 0
-    // To find the minimum value for a, which results in an output similar to program input, we start from
-    // the back and reverse the process above
-    fn reverse_solution(&self) -> u128 {
-        let min_a = u128::MAX;
-        if let Some(final_a) = self.recursive_solver(self.part_2.len() - 1, 0, min_a) {
-            final_a
-        } else {
-            panic!("No Solution!");
+    // Programs of part 2 loop until a == 0 and shift a by 3 bits in each loop. Therefore
+    // each loop outputs one value and the last outputs depend only on the highest bits of a.
+    fn check_loop_shape(&self) -> Result<()> {
+        if self.ins.last() != Some(&Instruction::Jnz(0)) {
+            bail!("program does not end with jump to start (3,0)");
+        }
+        if self
+            .ins
+            .iter()
+            .filter(|i| matches!(i, Instruction::Jnz(_)))
+            .count()
+            != 1
+        {
+            bail!("program has more than one jump");
+        }
+        if self
+            .ins
+            .iter()
+            .filter(|i| matches!(i, Instruction::Adv(_)))
+            .count()
+            != 1
+            || !self.ins.contains(&Instruction::Adv(3))
+        {
+            bail!("program does not shift register a by 3 bits (0,3) once per loop");
         }
+        if self
+            .ins
+            .iter()
+            .filter(|i| matches!(i, Instruction::Out(_)))
+            .count()
+            != 1
+        {
+            bail!("program does not output exactly one value per loop");
+        }
+        Ok(())
     }
-    fn recursive_solver(&self, index: usize, a: u128, mut min_a: u128) -> Option<u128> {
-        let out = self.part_2[index];
-        let new_a = a << 3;
-        let mut is_solved = false;
-        for b in 0..8_u128 {
-            let new_a = new_a + b;
-            let b_bxl_6 = b ^ 6;
-            let c = (new_a >> b_bxl_6) & 7;
-            let check_out = b_bxl_6 ^ c ^ 7;
-            if out == check_out {
-                // possible solution
-                if index == 0 {
-                    // end of recursive call
-                    if new_a < min_a {
-                        is_solved = true;
-                        min_a = new_a;
-                    }
-                } else if let Some(final_a) = self.recursive_solver(index - 1, new_a, min_a) {
-                    if final_a < min_a {
-                        is_solved = true;
-                        min_a = final_a;
-                    }
-                }
-            }
+    // Finds lowest a, which outputs the program itself. Starting with the last output, a is
+    // build from the most significant end by appending 3 bits per output. Each candidate is
+    // verified by executing the program.
+    fn reverse_solution(&self) -> Result<u128> {
+        self.check_loop_shape()?;
+        self.search_a(self.part_2.len(), 0)
+            .ok_or(anyhow!("no value of register a outputs the program"))
+    }
+    fn search_a(&self, index: usize, a: u128) -> Option<u128> {
+        if index == 0 {
+            return Some(a);
         }
-        is_solved.then_some(min_a)
+        // trying chunks in ascending order finds lowest a first
+        (0..8_u128)
+            .map(|chunk| (a << 3) | chunk)
+            .filter(|new_a| self.run_with_a(*new_a) == self.part_2[index - 1..])
+            .find_map(|new_a| self.search_a(index - 1, new_a))
     }
 }
 
-pub fn day_17() -> Result<()> {
-    // a part 1: 37293246
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_17.txt");
-    let mut challenge = Day17Data::from(input);
-
-    let result_part1 = challenge.execute();
-    println!("result day 17 part 1: {}", result_part1);
-    assert_eq!(result_part1, "1,5,0,1,7,4,1,0,3");
+impl Solution for Day17Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.reverse_solution();
-    println!("result day 17 part 2: {}", result_part2);
-    assert_eq!(result_part2, 47_910_079_998_866);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.execute())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.reverse_solution()
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_17_example.txt");
-        let mut challenge = Day17Data::from(input);
+        let input = read_file(2024, "day_17_example.txt")?;
+        let challenge = Day17Data::from(input);
 
         let result_part1 = challenge.execute();
         println!("result day 17 part 1: {}", result_part1);
         assert_eq!(result_part1, "4,6,3,5,6,3,5,2,1,0");
-        /*
-        Example of part two does not really help to solve this challenge.
-        Therefore I skipped it.
-        */
+        // program of part 1 example shifts a only by 1 bit
+        assert!(challenge.reverse_solution().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_example_part_2() -> Result<()> {
+        let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0";
+        let challenge = Day17Data::from(input);
+        let result_part2 = challenge.reverse_solution()?;
+        println!("result day 17 part 2: {}", result_part2);
+        assert_eq!(result_part2, 117440);
+        assert_eq!(challenge.run_with_a(result_part2), challenge.part_2);
+
+        // program of the shape of real inputs
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,3,7,5,4,1,1,3,0,3,5,5,3,0";
+        let challenge = Day17Data::from(input);
+        let a = challenge.reverse_solution()?;
+        assert_eq!(challenge.run_with_a(a), challenge.part_2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_2_after_part_1() -> Result<()> {
+        // program reads registers b and c (4,0) before writing them (7,1 and 4,0)
+        let input =
+            "Register A: 729\nRegister B: 0\nRegister C: 2\n\nProgram: 4,0,7,1,0,3,4,0,5,5,3,0";
+        let result_part2 = Day17Data::from(input).reverse_solution()?;
+        assert_eq!(result_part2, 38632771244);
+
+        let mut challenge = Day17Data::from(input);
+        assert_eq!(challenge.part_1()?.to_string(), "6,7,7,2");
+        // part 1 does not change initial registers
+        assert_eq!(challenge.part_1()?.to_string(), "6,7,7,2");
+        assert_eq!(challenge.reverse_solution()?, result_part2);
         Ok(())
     }
 }
@@ -1,11 +1,13 @@
 //!day_18.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint};
 use std::collections::{hash_map::Entry, HashMap, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day18Data<const N: usize> {
+pub struct Day18Data<const N: usize> {
     bytes: Vec<MapPoint<N, N>>,
     start: MapPoint<N, N>,
     end: MapPoint<N, N>,
@@ -98,37 +100,34 @@ impl<const N: usize> Day18Data<N> {
 }
 
 // give by challenge
-const N: usize = 71;
-
-pub fn day_18() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_18.txt");
-    let challenge = Day18Data::<N>::from(input);
-
-    let (result_part1, _) = challenge.shortest_path(1024).unwrap();
-    println!("result day 18 part 1: {}", result_part1);
-    assert_eq!(result_part1, 506);
+impl<const N: usize> Solution for Day18Data<N> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.first_block(1024);
-    println!(
-        "result day 18 part 2: {},{}",
-        result_part2.x(),
-        result_part2.y()
-    );
-    assert_eq!(result_part2, (62, 6).into());
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.shortest_path(1024).unwrap().0)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        let first_block = self.first_block(1024);
+        Ok(format!("{},{}", first_block.x(), first_block.y()))
+    }
 }
 
+const N: usize = 71;
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     // give by challenge
     const E: usize = 7;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_18_example.txt");
+        let input = read_file(2024, "day_18_example.txt")?;
         let challenge = Day18Data::<E>::from(input);
 
         let (result_part1, _) = challenge.shortest_path(12).unwrap();
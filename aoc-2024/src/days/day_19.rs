@@ -1,10 +1,12 @@
 //!day_19.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::{hash_map::Entry, HashMap};
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day19Data {
+pub struct Day19Data {
     towels: Vec<String>,
     designs: Vec<String>,
 }
@@ -65,29 +67,29 @@ impl Day19Data {
     }
 }
 
-pub fn day_19() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_19.txt");
-    let challenge = Day19Data::from(input);
-
-    let result_part1 = challenge.count_possible_designs();
-    println!("result day 19 part 1: {}", result_part1.len());
-    assert_eq!(result_part1.len(), 322);
+impl Solution for Day19Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2: usize = result_part1.iter().sum();
-    println!("result day 19 part 2: {}", result_part2);
-    assert_eq!(result_part2, 715_514_563_508_258);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.count_possible_designs().len())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.count_possible_designs().iter().sum::<usize>())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_19_example.txt");
+        let input = read_file(2024, "day_19_example.txt")?;
         let challenge = Day19Data::from(input);
 
         let result_part1 = challenge.count_possible_designs();
@@ -1,11 +1,13 @@
 //!day_20.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{my_compass::Compass, my_map_point::MapPoint, my_map_two_dim::MyMap2D};
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day20Data<const N: usize> {
+pub struct Day20Data<const N: usize> {
     map: MyMap2D<char, N, N>,
     start: MapPoint<N, N>,
     end: MapPoint<N, N>,
@@ -75,32 +77,32 @@ impl<const N: usize> Day20Data<N> {
     }
 }
 
-const N: usize = 141;
-
-pub fn day_20() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_20.txt");
-    let challenge = Day20Data::<N>::from(input);
-
-    let result_part1 = challenge.find_cheats(100, 2);
-    println!("result day 20 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_393);
+impl<const N: usize> Solution for Day20Data<N> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.find_cheats(100, 20);
-    println!("result day 20 part 2: {}", result_part2);
-    assert_eq!(result_part2, 990_096);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.find_cheats(100, 2))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.find_cheats(100, 20))
+    }
 }
 
+const N: usize = 141;
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
     const E: usize = 15;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_20_example.txt");
+        let input = read_file(2024, "day_20_example.txt")?;
         let challenge = Day20Data::<E>::from(input);
 
         let result_part1 = challenge.find_cheats(6, 2);
@@ -1,8 +1,10 @@
 //!day_21.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct KeyPad {
@@ -78,7 +80,7 @@ impl KeyPad {
 }
 
 #[derive(Debug)]
-struct Day21Data {
+pub struct Day21Data {
     codes: Vec<String>,
     dir_robots: usize,
     num_pad: KeyPad,
@@ -151,25 +153,25 @@ impl Day21Data {
     }
 }
 
-pub fn day_21() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_21.txt");
-    let mut challenge = Day21Data::from(input);
-
-    let result_part1 = challenge.calc_complexities(2);
-    println!("result day 21 part 1: {}", result_part1);
-    assert_eq!(result_part1, 197_560);
+impl Solution for Day21Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.calc_complexities(25);
-    println!("result day 21 part 2: {}", result_part2);
-    assert_eq!(result_part2, 242_337_182_910_752);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.calc_complexities(2))
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.calc_complexities(25))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_key_strokes() {
@@ -190,7 +192,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_21_example.txt");
+        let input = read_file(2024, "day_21_example.txt")?;
         let mut challenge = Day21Data::from(input);
 
         let result_part1 = challenge.calc_complexities(2);
@@ -1,17 +1,22 @@
 //!day_22.rs
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc_common::{Solution, LONG_RUNTIME};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::time::Duration;
 
 #[derive(Debug)]
-struct Day22Data {
+pub struct Day22Data {
     secrets: Vec<u128>,
+    result_part2: Option<u128>,
 }
 
 impl From<&str> for Day22Data {
     fn from(value: &str) -> Self {
         Self {
             secrets: value.lines().map(|v| v.parse::<u128>().unwrap()).collect(),
+            result_part2: None,
         }
     }
 }
@@ -101,24 +106,31 @@ fn calc_secret(
     )
 }
 
-pub fn day_22() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_22.txt");
-    let challenge = Day22Data::from(input);
+impl Solution for Day22Data {
+    const EXPECTED_RUNTIME: Duration = LONG_RUNTIME;
 
-    let (result_part1, result_part2) = challenge.sum_up_new_secrets(2000);
-    println!("result day 22 part 1: {}", result_part1);
-    assert_eq!(result_part1, 13_753_970_725);
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day 22 part 2: {}", result_part2);
-    assert_eq!(result_part2, 1_570);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.sum_up_new_secrets(2000);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_calc_secret() {
@@ -145,7 +157,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_22_example.txt");
+        let input = read_file(2024, "day_22_example.txt")?;
         let challenge = Day22Data::from(input);
 
         let (result_part1, _) = challenge.sum_up_new_secrets(2000);
@@ -154,6 +166,7 @@ mod tests {
 
         let challenge = Day22Data {
             secrets: vec![1, 2, 3, 2024],
+            result_part2: None,
         };
         let (_, result_part2) = challenge.sum_up_new_secrets(2000);
         println!("result day 22 part 2: {}", result_part2);
@@ -1,14 +1,16 @@
 //!day_23.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use petgraph::{
     graph::{NodeIndex, UnGraph},
     visit::GetAdjacencyMatrix,
 };
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day23Data<'a> {
+pub struct Day23Data<'a> {
     graph: UnGraph<&'a str, ()>,
 }
 
@@ -113,29 +115,29 @@ impl<'a> Day23Data<'a> {
     }
 }
 
-pub fn day_23() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_23.txt");
-    let challenge = Day23Data::from(input);
-
-    let result_part1 = challenge.find_triangles().len();
-    println!("result day 23 part 1: {}", result_part1);
-    assert_eq!(result_part1, 1_075);
+impl Solution for Day23Data<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.find_lan_party();
-    println!("result day 23 part 2: {}", result_part2);
-    assert_eq!(result_part2, "az,cg,ei,hz,jc,km,kt,mv,sv,sx,wc,wq,xy");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.find_triangles().len())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.find_lan_party())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_23_example.txt");
+        let input = read_file(2024, "day_23_example.txt")?;
         let challenge = Day23Data::from(input);
 
         let result_part1 = challenge.find_triangles().len();
@@ -1,8 +1,10 @@
 //!day_24.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use regex::Regex;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Operator {
@@ -55,7 +57,7 @@ impl Gate {
 }
 
 #[derive(Debug)]
-struct Day24Data {
+pub struct Day24Data {
     gates: HashMap<String, Gate>,
     values: HashMap<String, bool>,
 }
@@ -257,30 +259,30 @@ impl Day24Data {
     }
 }
 
-pub fn day_24() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_24.txt");
-    let mut challenge = Day24Data::from(input);
-
-    let result_part1 = challenge.calc_z_values();
-    println!("result day 24 part 1: {}", result_part1);
-    assert_eq!(result_part1, 56_620_966_442_854);
+impl Solution for Day24Data {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.check_add_operator_tree();
-    println!("result day 24 part 2: {}", result_part2);
-    assert_eq!(result_part2, "chv,jpj,kgj,rts,vvw,z07,z12,z26");
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.calc_z_values())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.check_add_operator_tree())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn some_experimenting() {
         // I used these tests to manually get nearer to the solution
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_24.txt");
+        let input = read_file(2024, "day_24.txt").unwrap();
         let mut challenge = Day24Data::from(input);
         challenge.set_x(1);
         assert_eq!(challenge.values.get("x00"), Some(&true));
@@ -328,7 +330,7 @@ mod tests {
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_24_example.txt");
+        let input = read_file(2024, "day_24_example.txt")?;
         let mut challenge = Day24Data::from(input);
 
         let result_part1 = challenge.calc_z_values();
@@ -1,10 +1,12 @@
 //!day_25.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_map_two_dim::MyMap2D;
+use std::fmt::Display;
 
 #[derive(Debug)]
-struct Day25Data {
+pub struct Day25Data {
     keys: Vec<Vec<u8>>,
     locks: Vec<Vec<u8>>,
 }
@@ -61,27 +63,27 @@ impl Day25Data {
     }
 }
 
-pub fn day_25() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2024/day_25.txt");
-    let challenge = Day25Data::from(input);
+impl Solution for Day25Data {
+    const HAS_PART_2: bool = false;
 
-    let result_part1 = challenge.try_locks_and_keys();
-    println!("result day 25 part 1: {}", result_part1);
-    assert_eq!(result_part1, 3_307);
-    /*
-    No part 2 on last day :)
-    */
-    Ok(())
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
+
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.try_locks_and_keys())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_part() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2024/day_25_example.txt");
+        let input = read_file(2024, "day_25_example.txt")?;
         let challenge = Day25Data::from(input);
 
         let result_part1 = challenge.try_locks_and_keys();
@@ -3,36 +3,36 @@
 pub mod days;
 pub mod utilities;
 
-use anyhow::Result;
+use aoc_common::Registry;
 
-pub fn run() -> Result<()> {
-    days::day_01::day_01()?;
-    days::day_02::day_02()?;
-    days::day_03::day_03()?;
-    days::day_04::day_04()?;
-    days::day_05::day_05()?;
-    days::day_06::day_06()?;
-    days::day_07::day_07()?;
-    days::day_08::day_08()?;
-    days::day_09::day_09()?;
-    days::day_10::day_10()?;
-    days::day_11::day_11()?;
-    days::day_12::day_12()?;
-    days::day_13::day_13()?;
-    days::day_14::day_14()?;
-    days::day_15::day_15()?;
-    days::day_16::day_16()?;
-    days::day_17::day_17()?;
-    days::day_18::day_18()?;
-    days::day_19::day_19()?;
-    days::day_20::day_20()?;
-    days::day_21::day_21()?;
-    #[cfg(feature = "long-run-time")]
-    days::day_22::day_22()?;
-    #[cfg(not(feature = "long-run-time"))]
-    println!("day 22 skipped because of long run time");
-    days::day_23::day_23()?;
-    days::day_24::day_24()?;
-    days::day_25::day_25()?;
-    Ok(())
+/// Solvers of all days of 2024.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<days::day_01::Day01Data>(2024, 1)
+        .register::<days::day_02::Day02Data>(2024, 2)
+        .register::<days::day_03::Day03>(2024, 3)
+        .register::<days::day_04::Day04Data<140>>(2024, 4)
+        .register::<days::day_05::Day05Data>(2024, 5)
+        .register::<days::day_06::Day06Data<130>>(2024, 6)
+        .register::<days::day_07::Day07Data>(2024, 7)
+        .register::<days::day_08::Day08Data>(2024, 8)
+        .register::<days::day_09::Day09Data>(2024, 9)
+        .register::<days::day_10::Day10Data<43>>(2024, 10)
+        .register::<days::day_11::Day11Data>(2024, 11)
+        .register::<days::day_12::Day12Data<140>>(2024, 12)
+        .register::<days::day_13::Day13Data>(2024, 13)
+        .register::<days::day_14::Day14Data>(2024, 14)
+        .register::<days::day_15::Day15>(2024, 15)
+        .register::<days::day_16::Day16Data<141>>(2024, 16)
+        .register::<days::day_17::Day17Data>(2024, 17)
+        .register::<days::day_18::Day18Data<71>>(2024, 18)
+        .register::<days::day_19::Day19Data>(2024, 19)
+        .register::<days::day_20::Day20Data<141>>(2024, 20)
+        .register::<days::day_21::Day21Data>(2024, 21)
+        .register::<days::day_22::Day22Data>(2024, 22)
+        .register::<days::day_23::Day23Data<'static>>(2024, 23)
+        .register::<days::day_24::Day24Data>(2024, 24)
+        .register::<days::day_25::Day25Data>(2024, 25);
+    registry
 }
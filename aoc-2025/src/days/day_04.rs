@@ -1,8 +1,10 @@
 //!day_04.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 struct ChallengeInput {
     paper_rolls: HashSet<Point>,
@@ -66,29 +68,38 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2025/day_04.txt");
-    let mut challenge = ChallengeInput::from(input);
+pub struct Day04 {
+    challenge: ChallengeInput,
+    removed_paper_rolls: usize,
+}
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_04 part 1: {result_part1}");
-    assert_eq!(result_part1, 1_433);
+impl Solution for Day04 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self {
+            challenge: ChallengeInput::from(input),
+            removed_paper_rolls: 0,
+        })
+    }
 
-    let result_part2 = challenge.solution_part_2(result_part1);
-    println!("result day_04 part 2: {result_part2}");
-    assert_eq!(result_part2, 8_616);
+    fn part_1(&mut self) -> Result<impl Display> {
+        self.removed_paper_rolls = self.challenge.solution_part_1();
+        Ok(self.removed_paper_rolls)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.challenge.solution_part_2(self.removed_paper_rolls))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_04() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_04_example.txt");
+        let input = read_file(2025, "day_04_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
@@ -1,9 +1,11 @@
 //!day_05.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use std::collections::VecDeque;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     fresh_id_ranges: Vec<(u64, u64)>,
     fruit_ids: Vec<u64>,
 }
@@ -65,29 +67,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2025/day_05.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_05 part 1: {result_part1}");
-    assert_eq!(result_part1, 643);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_05 part 2: {result_part2}");
-    assert_eq!(result_part2, 342_018_167_474_526);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_05() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_05_example.txt");
+        let input = read_file(2025, "day_05_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
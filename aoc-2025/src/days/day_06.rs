@@ -1,6 +1,8 @@
 //!day_06.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 enum Operator {
@@ -94,28 +96,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2025/day_06.txt");
+pub struct Day06 {
+    input: &'static str,
+}
 
-    let result_part1 = ChallengeInput::parse_input_for_part_1(input).solution();
-    println!("result day_06 part 1: {result_part1}");
-    assert_eq!(result_part1, 5_322_004_718_681);
+impl Solution for Day06 {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self { input })
+    }
 
-    let result_part2 = ChallengeInput::parse_input_for_part_2(input).solution();
-    println!("result day_06 part 2: {result_part2}");
-    assert_eq!(result_part2, 9_876_636_978_528);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(ChallengeInput::parse_input_for_part_1(self.input).solution())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(ChallengeInput::parse_input_for_part_2(self.input).solution())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_06() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_06_example.txt");
+        let input = read_file(2025, "day_06_example.txt")?;
 
         let result_part1 = ChallengeInput::parse_input_for_part_1(input).solution();
         println!("result day_06 part 1: {result_part1}");
@@ -1,12 +1,15 @@
 //!day_08.rs
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use my_lib::my_geometry::my_point::Point3D;
 use petgraph::{graphmap::UnGraphMap, visit::Dfs};
 use std::collections::HashSet;
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     circuits: UnGraphMap<Point3D, ()>,
+    result_part2: Option<i64>,
 }
 
 impl From<&str> for ChallengeInput {
@@ -17,7 +20,10 @@ impl From<&str> for ChallengeInput {
             let point = Point3D::new(coordinates[0], coordinates[1], coordinates[2]);
             circuits.add_node(point);
         }
-        ChallengeInput { circuits }
+        ChallengeInput {
+            circuits,
+            result_part2: None,
+        }
     }
 }
 
@@ -74,28 +80,33 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2025/day_08.txt");
-    let mut challenge = ChallengeInput::from(input);
-
-    let (result_part1, result_part2) = challenge.solution_part_1_and_2(1_000);
-    println!("result day_08 part 1: {result_part1}");
-    assert_eq!(result_part1, 127_551);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    println!("result day_08 part 2: {result_part2}");
-    assert_eq!(result_part2, 2_347_225_200);
+    fn part_1(&mut self) -> Result<impl Display> {
+        let (result_part1, result_part2) = self.solution_part_1_and_2(1_000);
+        self.result_part2 = Some(result_part2);
+        Ok(result_part1)
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.result_part2
+            .take()
+            .ok_or(anyhow!("part 1 has not been solved"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_08() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_08_example.txt");
+        let input = read_file(2025, "day_08_example.txt")?;
         let mut example = ChallengeInput::from(input);
 
         let (result_part1, result_part2) = example.solution_part_1_and_2(10);
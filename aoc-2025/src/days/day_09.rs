@@ -1,9 +1,11 @@
 //!day_09.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::my_geometry::{my_line::LineSegment, my_point::Point, my_rectangle::Rectangle};
+use std::fmt::Display;
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     tiles: Vec<Point>,
 }
 
@@ -128,29 +130,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2025/day_09.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_09 part 1: {result_part1}");
-    assert_eq!(result_part1, 4_759_531_084);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_09 part 2: {result_part2}");
-    assert_eq!(result_part2, 1_539_238_860);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_09() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_09_example.txt");
+        let input = read_file(2025, "day_09_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
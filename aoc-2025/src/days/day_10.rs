@@ -1,8 +1,10 @@
 //!day_10.rs
 
 use anyhow::{Result, anyhow};
+use aoc_common::Solution;
 use good_lp::*;
 use std::collections::BTreeSet;
+use std::fmt::Display;
 
 #[derive(Debug)]
 struct Machine {
@@ -148,7 +150,7 @@ impl Machine {
 }
 
 #[derive(Debug)]
-struct ChallengeInput {
+pub struct ChallengeInput {
     machines: Vec<Machine>,
 }
 
@@ -176,29 +178,29 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2025/day_10.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_10 part 1: {result_part1}");
-    assert_eq!(result_part1, 488);
+impl Solution for ChallengeInput {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2()?;
-    println!("result day_10 part 2: {result_part2}");
-    assert_eq!(result_part2, 18_771);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        self.solution_part_2()
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_10() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_10_example.txt");
+        let input = read_file(2025, "day_10_example.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
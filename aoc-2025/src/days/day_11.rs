@@ -1,14 +1,16 @@
 //!day_11.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use petgraph::{
     algo::simple_paths::all_simple_paths,
     graph::{DiGraph, NodeIndex},
     visit::EdgeRef,
 };
 use std::collections::{HashMap, HashSet, hash_map::RandomState};
+use std::fmt::Display;
 
-struct ChallengeInput<'a> {
+pub struct ChallengeInput<'a> {
     servers: DiGraph<&'a str, ()>,
     node_map: HashMap<&'a str, NodeIndex>,
 }
@@ -89,36 +91,36 @@ impl<'a> ChallengeInput<'a> {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2025/day_11.txt");
-    let challenge = ChallengeInput::from(input);
-
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_11 part 1: {result_part1}");
-    assert_eq!(result_part1, 500);
+impl Solution for ChallengeInput<'static> {
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    let result_part2 = challenge.solution_part_2();
-    println!("result day_11 part 2: {result_part2}");
-    assert_eq!(result_part2, 287_039_700_129_600);
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 
-    Ok(())
+    fn part_2(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_2())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_11() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_11_example_part_1.txt");
+        let input = read_file(2025, "day_11_example_part_1.txt")?;
         let example = ChallengeInput::from(input);
 
         let result_part1 = example.solution_part_1();
         println!("result day_11 part 1: {result_part1}");
         assert_eq!(result_part1, 5);
 
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_11_example_part_2.txt");
+        let input = read_file(2025, "day_11_example_part_2.txt")?;
         let example = ChallengeInput::from(input);
         let result_part2 = example.solution_part_2();
         println!("result day_11 part 2: {result_part2}");
@@ -1,10 +1,12 @@
 //!day_12.rs
 
 use anyhow::Result;
+use aoc_common::Solution;
 use my_lib::{
     my_array::MyArray,
     my_geometry::{my_point::Point, my_rectangle::Rectangle},
 };
+use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy)]
 struct Shape {
@@ -61,7 +63,7 @@ impl Region {
     }
 }
 
-struct ChallengeInput {
+pub struct ChallengeInput {
     _shapes: Vec<Shape>,
     regions: Vec<Region>,
 }
@@ -96,27 +98,27 @@ impl ChallengeInput {
     }
 }
 
-pub fn solution() -> Result<()> {
-    let input = include_str!("../../../../aoc_input/aoc-2025/day_12.txt");
-    let challenge = ChallengeInput::from(input);
+impl Solution for ChallengeInput {
+    const HAS_PART_2: bool = false;
 
-    let result_part1 = challenge.solution_part_1();
-    println!("result day_12 part 1: {result_part1}");
-    assert_eq!(result_part1, 469);
-
-    // as always there is no part 2 at the last day.
+    fn parse(input: &'static str) -> Result<Self> {
+        Ok(Self::from(input))
+    }
 
-    Ok(())
+    fn part_1(&mut self) -> Result<impl Display> {
+        Ok(self.solution_part_1())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::read_file;
 
     #[test]
     fn test_example_day_12() -> Result<()> {
-        let input = include_str!("../../../../aoc_input/aoc-2025/day_12_example.txt");
+        let input = read_file(2025, "day_12_example.txt")?;
         let example = ChallengeInput::from(input);
 
         // the example is irrelevant for the solution
@@ -2,21 +2,23 @@
 
 pub mod days;
 
-use anyhow::Result;
+use aoc_common::Registry;
 
-pub fn run() -> Result<()> {
-    days::day_01::solution()?;
-    days::day_02::solution()?;
-    days::day_03::solution()?;
-    days::day_04::solution()?;
-    days::day_05::solution()?;
-    days::day_06::solution()?;
-    days::day_07::solution()?;
-    days::day_08::solution()?;
-    days::day_09::solution()?;
-    days::day_10::solution()?;
-    days::day_11::solution()?;
-    days::day_12::solution()?;
-
-    Ok(())
+/// Solvers of all days of 2025.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<days::day_01::ChallengeInput>(2025, 1)
+        .register::<days::day_02::ChallengeInput>(2025, 2)
+        .register::<days::day_03::ChallengeInput>(2025, 3)
+        .register::<days::day_04::Day04>(2025, 4)
+        .register::<days::day_05::ChallengeInput>(2025, 5)
+        .register::<days::day_06::Day06>(2025, 6)
+        .register::<days::day_07::ChallengeInput>(2025, 7)
+        .register::<days::day_08::ChallengeInput>(2025, 8)
+        .register::<days::day_09::ChallengeInput>(2025, 9)
+        .register::<days::day_10::ChallengeInput>(2025, 10)
+        .register::<days::day_11::ChallengeInput<'static>>(2025, 11)
+        .register::<days::day_12::ChallengeInput>(2025, 12);
+    registry
 }
@@ -0,0 +1,151 @@
+//!answers.rs
+
+// Verified answers are stored in a json file keyed by year, day, input id and part:
+// { "2019": { "05": { "default": { "part_1": "7692125", "part_2": "14340395" } } } }
+// The input id keeps answers of several inputs (e.g. of different accounts) side by side.
+// Inputs of the input directory use id "default".
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+pub const DEFAULT_ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.json");
+pub const DEFAULT_INPUT_ID: &str = "default";
+
+/// Result of comparing an answer with the answer database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// Contains expected answer.
+    Fail(String),
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(_) => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part_1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part_2: Option<String>,
+}
+
+impl Answers {
+    fn part(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part_1.as_ref(),
+            _ => self.part_2.as_ref(),
+        }
+    }
+
+    fn part_mut(&mut self, part: u8) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part_1,
+            _ => &mut self.part_2,
+        }
+    }
+}
+
+/// year -> day -> input id -> answers
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerDb {
+    years: BTreeMap<String, BTreeMap<String, BTreeMap<String, Answers>>>,
+}
+
+impl AnswerDb {
+    /// Loads answer database. A missing file results in an empty database.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read answers file {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse answers file {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut content = serde_json::to_string_pretty(self)?;
+        content.push('\n');
+        std::fs::write(path, content)
+            .with_context(|| format!("failed to write answers file {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, input_id: &str, part: u8) -> Option<&str> {
+        self.years
+            .get(&year.to_string())?
+            .get(&format!("{day:02}"))?
+            .get(input_id)?
+            .part(part)
+            .map(|a| a.as_str())
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, input_id: &str, part: u8, answer: &str) {
+        *self
+            .years
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("{day:02}"))
+            .or_default()
+            .entry(input_id.to_string())
+            .or_default()
+            .part_mut(part) = Some(answer.to_string());
+    }
+
+    pub fn verify(&self, year: u16, day: u8, input_id: &str, part: u8, answer: &str) -> Verdict {
+        match self.get(year, day, input_id, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_db() -> Result<()> {
+        let mut db: AnswerDb = serde_json::from_str(
+            r#"{ "2019": { "05": { "default": { "part_1": "7692125" } } } }"#,
+        )?;
+        assert_eq!(db.get(2019, 5, DEFAULT_INPUT_ID, 1), Some("7692125"));
+        assert_eq!(db.verify(2019, 5, "default", 1, "7692125"), Verdict::Pass);
+        assert_eq!(
+            db.verify(2019, 5, "default", 1, "42"),
+            Verdict::Fail("7692125".into())
+        );
+        assert_eq!(db.verify(2019, 5, "default", 2, "42"), Verdict::Unknown);
+        assert_eq!(db.verify(2019, 5, "other", 1, "42"), Verdict::Unknown);
+
+        db.set(2019, 5, "default", 2, "14340395");
+        db.set(2019, 5, "other", 1, "42");
+        assert_eq!(db.verify(2019, 5, "default", 2, "14340395"), Verdict::Pass);
+        assert_eq!(db.verify(2019, 5, "other", 1, "42"), Verdict::Pass);
+
+        let json = serde_json::to_string(&db)?;
+        assert_eq!(serde_json::from_str::<AnswerDb>(&json)?, db);
+        Ok(())
+    }
+}
@@ -0,0 +1,412 @@
+//!bench.rs
+
+use crate::cli::BenchArgs;
+use crate::run::panic_message;
+use crate::table::print_table;
+use anyhow::{Context, Result, anyhow};
+use aoc_common::{Registry, SolverFactory, read_input};
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Phases, which are shorter in baseline and report, are not flagged. Their timings are
+/// dominated by noise.
+const MIN_COMPARED: Duration = Duration::from_millis(1);
+
+const PHASES: [&str; 3] = ["parse", "part_1", "part_2"];
+
+/// Statistics of all timed iterations of one phase in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Timing {
+    fn new(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let total: Duration = samples.iter().sum();
+        Some(Timing {
+            min_ns: samples[0].as_nanos() as u64,
+            median_ns: samples[samples.len() / 2].as_nanos() as u64,
+            mean_ns: (total / samples.len() as u32).as_nanos() as u64,
+        })
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchEntry {
+    pub year: u16,
+    pub day: u8,
+    /// None for the main solver of a day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse: Option<Timing>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<Timing>,
+    /// None for days without part 2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<Timing>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BenchEntry {
+    fn name(&self) -> String {
+        match &self.variant {
+            Some(variant) => format!("{} day {:02} ({variant})", self.year, self.day),
+            None => format!("{} day {:02}", self.year, self.day),
+        }
+    }
+
+    fn timings(&self) -> [(&'static str, Option<&Timing>); 3] {
+        [
+            (PHASES[0], self.parse.as_ref()),
+            (PHASES[1], self.part_1.as_ref()),
+            (PHASES[2], self.part_2.as_ref()),
+        ]
+    }
+
+    fn total(&self) -> Duration {
+        self.timings()
+            .iter()
+            .filter_map(|(_, timing)| timing.map(|t| t.median()))
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub warmup: u32,
+    pub iterations: u32,
+    pub entries: Vec<BenchEntry>,
+}
+
+impl BenchReport {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read bench report {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse bench report {}", path.display()))
+    }
+
+    /// Saves report as csv, if extension of path is `csv`, otherwise as json.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let content = if path.extension().is_some_and(|ext| ext == "csv") {
+            self.to_csv()
+        } else {
+            let mut json = serde_json::to_string_pretty(self)?;
+            json.push('\n');
+            json
+        };
+        std::fs::write(path, content)
+            .with_context(|| format!("failed to write bench report {}", path.display()))
+    }
+
+    /// One line per phase of each entry. Failed entries have one line with an error.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("year,day,variant,phase,min_ns,median_ns,mean_ns,error\n");
+        for entry in self.entries.iter() {
+            let variant = entry.variant.as_deref().unwrap_or_default();
+            if let Some(error) = &entry.error {
+                let error = error.replace('"', "\"\"");
+                csv += &format!("{},{},{variant},,,,,\"{error}\"\n", entry.year, entry.day);
+                continue;
+            }
+            for (phase, timing) in entry.timings() {
+                if let Some(t) = timing {
+                    csv += &format!(
+                        "{},{},{variant},{phase},{},{},{},\n",
+                        entry.year, entry.day, t.min_ns, t.median_ns, t.mean_ns
+                    );
+                }
+            }
+        }
+        csv
+    }
+}
+
+/// Median of a phase, which is slower than in baseline by more than the threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slowdown {
+    pub name: String,
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Slowdown {
+    pub fn percent(&self) -> f64 {
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+/// Compares medians of all phases, which exist in both reports.
+pub fn compare(baseline: &BenchReport, report: &BenchReport, threshold: f64) -> Vec<Slowdown> {
+    let mut slowdowns = Vec::new();
+    for entry in report.entries.iter() {
+        let Some(old) = baseline
+            .entries
+            .iter()
+            .find(|b| b.year == entry.year && b.day == entry.day && b.variant == entry.variant)
+        else {
+            continue;
+        };
+        for ((phase, current), (_, previous)) in entry.timings().into_iter().zip(old.timings()) {
+            let (Some(current), Some(previous)) = (current, previous) else {
+                continue;
+            };
+            let slowdown = Slowdown {
+                name: entry.name(),
+                phase,
+                baseline: previous.median(),
+                current: current.median(),
+            };
+            if slowdown.current.max(slowdown.baseline) >= MIN_COMPARED
+                && slowdown.percent() > threshold
+            {
+                slowdowns.push(slowdown);
+            }
+        }
+    }
+    slowdowns
+}
+
+/// Benchmarks all selected days and prints a table of medians. Returns false, if any day
+/// failed or any slowdown compared to baseline has been flagged.
+pub fn bench(registry: &Registry, args: &BenchArgs) -> Result<bool> {
+    let mut selected: Vec<(u16, u8, Option<&'static str>, SolverFactory)> = registry
+        .iter()
+        .map(|(year, day, factory)| (year, day, None, factory))
+        .collect();
+    if args.variants {
+        selected.extend(
+            registry
+                .iter_variants()
+                .map(|(year, day, variant, factory)| (year, day, Some(variant), factory)),
+        );
+        selected.sort_by_key(|(year, day, variant, _)| (*year, *day, *variant));
+    }
+    selected.retain(|(year, day, _, _)| args.selection.contains(*year, *day));
+    if selected.is_empty() {
+        return Err(anyhow!("no solver found for selection"));
+    }
+    let baseline = args.baseline.as_ref().map(BenchReport::load).transpose()?;
+    let max_runtime = args.runtime_limit.max_runtime(&args.selection);
+
+    let mut report = BenchReport {
+        warmup: args.warmup,
+        iterations: args.iterations,
+        entries: Vec::with_capacity(selected.len()),
+    };
+    let mut skipped = Vec::new();
+    for (year, day, variant, factory) in selected {
+        let mut entry = BenchEntry {
+            year,
+            day,
+            variant: variant.map(String::from),
+            parse: None,
+            part_1: None,
+            part_2: None,
+            error: None,
+        };
+        if max_runtime.is_some_and(|max| factory().expected_runtime() > max) {
+            skipped.push(entry.name());
+            continue;
+        }
+        let timings = read_input(year, day)
+            .and_then(|input| bench_day(factory, input, args.warmup, args.iterations));
+        match timings {
+            Ok([parse, part_1, part_2]) => {
+                entry.parse = parse;
+                entry.part_1 = part_1;
+                entry.part_2 = part_2;
+            }
+            Err(err) => entry.error = Some(format!("{err:#}")),
+        }
+        report.entries.push(entry);
+    }
+
+    print_report(&report);
+    if let Some(max) = max_runtime
+        && !skipped.is_empty()
+    {
+        println!(
+            "skipped {} days with expected run time above {max:?}: {}",
+            skipped.len(),
+            skipped.join(", ")
+        );
+    }
+    if let Some(path) = &args.output {
+        report.save(path)?;
+        println!("bench report written to {}", path.display());
+    }
+    let failed = report.entries.iter().filter(|e| e.error.is_some()).count();
+    if failed > 0 {
+        println!("{failed} of {} days failed", report.entries.len());
+    }
+
+    let mut slowdowns = Vec::new();
+    if let Some(baseline) = baseline {
+        slowdowns = compare(&baseline, &report, args.threshold);
+        if slowdowns.is_empty() {
+            println!(
+                "no slowdowns above {}% compared to baseline",
+                args.threshold
+            );
+        }
+        for slowdown in slowdowns.iter() {
+            println!(
+                "SLOWDOWN {} {}: {:.2?} -> {:.2?} (+{:.1}%)",
+                slowdown.name,
+                slowdown.phase,
+                slowdown.baseline,
+                slowdown.current,
+                slowdown.percent()
+            );
+        }
+    }
+    Ok(failed == 0 && slowdowns.is_empty())
+}
+
+/// Times parse, part 1 and part 2 of a fresh solver in each iteration, since parts may
+/// change the state of a solver. Panics of solvers are caught and reported as errors.
+fn bench_day(
+    factory: SolverFactory,
+    input: &'static str,
+    warmup: u32,
+    iterations: u32,
+) -> Result<[Option<Timing>; 3]> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for iteration in 0..warmup + iterations {
+        let timed = catch_unwind(AssertUnwindSafe(|| -> Result<[Option<Duration>; 3]> {
+            let mut solver = factory();
+            let start = Instant::now();
+            solver.parse(input)?;
+            let parse = start.elapsed();
+            let start = Instant::now();
+            black_box(solver.part_1()?);
+            let part_1 = start.elapsed();
+            let start = Instant::now();
+            let part_2 = black_box(solver.part_2().transpose()?).map(|_| start.elapsed());
+            Ok([Some(parse), Some(part_1), part_2])
+        }))
+        .map_err(|payload| anyhow!(panic_message(payload)))??;
+        if iteration >= warmup {
+            for (phase, duration) in samples.iter_mut().zip(timed) {
+                phase.extend(duration);
+            }
+        }
+    }
+    Ok(samples.map(|mut phase| Timing::new(&mut phase)))
+}
+
+fn print_report(report: &BenchReport) {
+    let rows: Vec<[String; 5]> = report
+        .entries
+        .iter()
+        .map(|entry| {
+            let [parse, part_1, part_2] = entry.timings().map(|(_, timing)| match timing {
+                Some(t) => format!("{:.2?}", t.median()),
+                None => "-".into(),
+            });
+            let total = match &entry.error {
+                Some(err) => format!("ERROR: {err}"),
+                None => format!("{:.2?}", entry.total()),
+            };
+            [entry.name(), parse, part_1, part_2, total]
+        })
+        .collect();
+    print_table(["day", "parse", "part 1", "part 2", "total"], &rows);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(year: u16, day: u8, part_1_ms: u64) -> BenchEntry {
+        let timing = |ms: u64| Timing {
+            min_ns: ms * 1_000_000,
+            median_ns: ms * 1_000_000,
+            mean_ns: ms * 1_000_000,
+        };
+        BenchEntry {
+            year,
+            day,
+            variant: None,
+            parse: Some(timing(0)),
+            part_1: Some(timing(part_1_ms)),
+            part_2: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_timing() {
+        let mut samples = [5, 1, 3, 2, 4].map(Duration::from_nanos);
+        let timing = Timing::new(&mut samples).unwrap();
+        assert_eq!(
+            timing,
+            Timing {
+                min_ns: 1,
+                median_ns: 3,
+                mean_ns: 3
+            }
+        );
+        assert_eq!(Timing::new(&mut []), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = BenchReport {
+            warmup: 1,
+            iterations: 5,
+            entries: vec![entry(2020, 23, 100), entry(2020, 24, 100)],
+        };
+        let report = BenchReport {
+            warmup: 1,
+            iterations: 5,
+            entries: vec![
+                entry(2020, 23, 105),
+                entry(2020, 24, 150),
+                entry(2020, 25, 1),
+            ],
+        };
+        let slowdowns = compare(&baseline, &report, 10.0);
+        assert_eq!(slowdowns.len(), 1);
+        assert_eq!(slowdowns[0].name, "2020 day 24");
+        assert_eq!(slowdowns[0].phase, "part_1");
+        assert!((slowdowns[0].percent() - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut failed = entry(2019, 18, 0);
+        failed.error = Some("panicked: \"boom\"".into());
+        let report = BenchReport {
+            warmup: 0,
+            iterations: 1,
+            entries: vec![entry(2020, 23, 2), failed],
+        };
+        assert_eq!(
+            report.to_csv(),
+            "year,day,variant,phase,min_ns,median_ns,mean_ns,error\n\
+             2020,23,,parse,0,0,0,\n\
+             2020,23,,part_1,2000000,2000000,2000000,\n\
+             2019,18,,,,,,\"panicked: \"\"boom\"\"\"\n"
+        );
+    }
+}
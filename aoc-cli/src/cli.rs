@@ -0,0 +1,339 @@
+//!cli.rs
+
+use crate::answers::{DEFAULT_ANSWERS_FILE, DEFAULT_INPUT_ID};
+use anyhow::{Context, Error, Result, anyhow};
+use clap::{Args, Parser, Subcommand};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Days with a longer expected run time are skipped by default.
+pub const DEFAULT_MAX_RUNTIME: Duration = Duration::from_secs(10);
+
+/// Advent of Code solutions of all years.
+#[derive(Parser, Debug)]
+#[command(name = "aoc", version)]
+pub struct Cli {
+    /// Directory with puzzle inputs, overrides AOC_INPUT_DIR.
+    #[arg(long, global = true)]
+    pub input_dir: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run selected days and print a result table.
+    Run(RunArgs),
+    /// Time parse, part 1 and part 2 of selected days.
+    Bench(BenchArgs),
+    /// Tools for Intcode programs of 2019.
+    Intcode(IntcodeArgs),
+    /// Tools for elfcode programs of 2018.
+    Elfcode(ElfcodeArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Only show this part. Part 1 is always executed, since part 2 may depend on it.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Puzzle input file of a single day instead of the input directory.
+    #[arg(long, requires = "day", conflicts_with = "all")]
+    pub input: Option<PathBuf>,
+
+    /// Json file with verified answers.
+    #[arg(long, default_value = DEFAULT_ANSWERS_FILE)]
+    pub answers: PathBuf,
+
+    /// Id of input in answers file. Defaults to "default" for the input directory
+    /// and to the file name for --input.
+    #[arg(long)]
+    pub input_id: Option<String>,
+
+    /// Store answers, which are not yet in the answers file.
+    #[arg(long)]
+    pub record: bool,
+
+    #[command(flatten)]
+    pub runtime_limit: RuntimeLimit,
+}
+
+impl RunArgs {
+    pub fn input_id(&self) -> String {
+        match (&self.input_id, &self.input) {
+            (Some(id), _) => id.clone(),
+            (None, Some(path)) => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(|| DEFAULT_INPUT_ID.into()),
+            (None, None) => DEFAULT_INPUT_ID.into(),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    #[command(flatten)]
+    pub runtime_limit: RuntimeLimit,
+
+    /// Untimed iterations before measuring.
+    #[arg(long, default_value_t = 1)]
+    pub warmup: u32,
+
+    /// Timed iterations.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+
+    /// Also benchmark alternative implementations of selected days.
+    #[arg(long)]
+    pub variants: bool,
+
+    /// Write report to this file, as csv if extension is `.csv`, otherwise as json.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+
+    /// Previous json report to compare with.
+    #[arg(long)]
+    pub baseline: Option<PathBuf>,
+
+    /// Slowdown in percent compared to baseline, which is flagged.
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    pub threshold: f64,
+}
+
+#[derive(Args, Debug)]
+pub struct IntcodeArgs {
+    #[command(subcommand)]
+    pub command: IntcodeCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum IntcodeCommand {
+    /// Print annotated listing of an Intcode program.
+    Disasm(DisasmArgs),
+    /// Assemble mnemonics (as printed by disasm) into a comma separated Intcode program.
+    Asm(AsmArgs),
+    /// Debug an Intcode program step by step with breakpoints and watchpoints.
+    Debug(DebugArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct DisasmArgs {
+    /// File with comma separated Intcode program.
+    pub file: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct AsmArgs {
+    /// File with Intcode assembly.
+    pub file: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct DebugArgs {
+    /// File with comma separated Intcode program.
+    pub file: PathBuf,
+
+    /// Comma separated values of initial pending input.
+    #[arg(long, value_delimiter = ',', allow_negative_numbers = true)]
+    pub input: Vec<i64>,
+}
+
+#[derive(Args, Debug)]
+pub struct ElfcodeArgs {
+    #[command(subcommand)]
+    pub command: ElfcodeCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ElfcodeCommand {
+    /// Print structured pseudo-code of an elfcode program.
+    Decompile(DecompileArgs),
+    /// Run an elfcode program and print the most executed instructions.
+    Profile(ProfileArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct DecompileArgs {
+    /// File with elfcode program starting with `#ip`.
+    pub file: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct ProfileArgs {
+    /// File with elfcode program starting with `#ip`.
+    pub file: PathBuf,
+
+    /// Initial value of register 0.
+    #[arg(long, default_value_t = 0)]
+    pub register_0: usize,
+
+    /// Maximum number of executed instructions.
+    #[arg(long, default_value_t = 100_000_000)]
+    pub budget: u64,
+
+    /// Number of shown instructions.
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct RuntimeLimit {
+    /// Skip days with a longer expected run time, e.g. `500ms`, `1s` or `2m`.
+    #[arg(long, value_parser = parse_duration, conflicts_with = "include_slow")]
+    pub max_runtime: Option<Duration>,
+
+    /// Run slow days, which are skipped by default. A single selected --day is never skipped.
+    #[arg(long)]
+    pub include_slow: bool,
+}
+
+impl RuntimeLimit {
+    /// Returns None, if no day should be skipped. Feature `long-run-time` includes slow days
+    /// as a fallback for builds with this feature.
+    pub fn max_runtime(&self, selection: &Selection) -> Option<Duration> {
+        if self.max_runtime.is_some() {
+            return self.max_runtime;
+        }
+        if self.include_slow || selection.day.is_some() || cfg!(feature = "long-run-time") {
+            return None;
+        }
+        Some(DEFAULT_MAX_RUNTIME)
+    }
+}
+
+/// Parses durations like `500ms`, `1.5s`, `2m` or `1h`. Plain numbers are seconds.
+pub fn parse_duration(s: &str) -> Result<Duration> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value
+        .parse()
+        .with_context(|| format!("invalid duration '{s}'"))?;
+    let factor = match unit {
+        "ms" => 0.001,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        "h" => 3_600.0,
+        _ => return Err(anyhow!("invalid unit '{unit}' of duration '{s}'")),
+    };
+    Duration::try_from_secs_f64(value * factor).with_context(|| format!("invalid duration '{s}'"))
+}
+
+#[derive(Args, Debug)]
+pub struct Selection {
+    /// Year to run.
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    pub year: Option<u16>,
+
+    /// Single day to run.
+    #[arg(long, conflicts_with = "days")]
+    pub day: Option<u8>,
+
+    /// Range of days to run, e.g. `10..=15`, `10..16` or `10`.
+    #[arg(long)]
+    pub days: Option<DayRange>,
+
+    /// Run all years.
+    #[arg(long)]
+    pub all: bool,
+}
+
+impl Selection {
+    pub fn contains(&self, year: u16, day: u8) -> bool {
+        if self.year.is_some_and(|y| y != year) {
+            return false;
+        }
+        match (self.day, &self.days) {
+            (Some(d), _) => d == day,
+            (None, Some(days)) => days.contains(day),
+            (None, None) => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayRange(RangeInclusive<u8>);
+
+impl DayRange {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parse = |day: &str| {
+            day.trim()
+                .parse::<u8>()
+                .with_context(|| format!("invalid day '{day}'"))
+        };
+        let range = if let Some((start, end)) = s.split_once("..=") {
+            parse(start)?..=parse(end)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            let end = parse(end)?;
+            parse(start)?..=end.checked_sub(1).context("empty range of days")?
+        } else {
+            let day = parse(s)?;
+            day..=day
+        };
+        if range.is_empty() {
+            return Err(anyhow!("empty range of days '{s}'"));
+        }
+        Ok(DayRange(range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_range() -> Result<()> {
+        assert_eq!(DayRange::from_str("10..=15")?, DayRange(10..=15));
+        assert_eq!(DayRange::from_str("10..16")?, DayRange(10..=15));
+        assert_eq!(DayRange::from_str("7")?, DayRange(7..=7));
+        assert!(DayRange::from_str("15..10").is_err());
+        assert!(DayRange::from_str("1..1").is_err());
+        assert!(DayRange::from_str("x..=3").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_duration() -> Result<()> {
+        assert_eq!(parse_duration("500ms")?, Duration::from_millis(500));
+        assert_eq!(parse_duration("1s")?, Duration::from_secs(1));
+        assert_eq!(parse_duration("1.5")?, Duration::from_millis(1_500));
+        assert_eq!(parse_duration("2m")?, Duration::from_secs(120));
+        assert!(parse_duration("2d").is_err());
+        assert!(parse_duration("s").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_selection() {
+        let selection = Selection {
+            year: Some(2023),
+            day: None,
+            days: Some(DayRange(10..=15)),
+            all: false,
+        };
+        assert!(selection.contains(2023, 10));
+        assert!(!selection.contains(2023, 16));
+        assert!(!selection.contains(2022, 10));
+    }
+}
@@ -0,0 +1,331 @@
+//!debug.rs
+
+// Line based frontend of the Intcode debugger. An empty line repeats the last command.
+
+use anyhow::{Error, Result, anyhow};
+use aoc_2019::intcode::{Breakpoint, Debugger, Opcode};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+const HELP: &str = "\
+step [n]           (s)  execute n instructions (default 1), ignoring breakpoints
+continue           (c)  run until breakpoint, watchpoint, missing input or halt
+break <addr|op>    (b)  stop at address or at opcode mnemonic, e.g. `b out`
+delete <addr|op>   (d)  remove breakpoint
+watch <addr>       (w)  stop after memory cell at addr changed
+unwatch <addr>          remove watchpoint
+info               (i)  show pc, relative base, pending input, breakpoints and watchpoints
+list [addr] [n]    (l)  disassemble n instructions (default 10) from addr (default pc)
+mem <addr> [n]     (x)  show n memory cells (default 8) from addr
+set <addr> <value>      write value to memory cell at addr
+pc <addr>               set pc
+rb <value>              set relative base
+input <value>...   (in) append values to pending input
+ascii <text>            append text and a newline as ascii to pending input
+clear                   clear pending input
+help               (h)  show this help
+quit               (q)  leave debugger";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DebugCommand {
+    Step(usize),
+    Continue,
+    Break(Breakpoint),
+    Delete(Breakpoint),
+    Watch(i64),
+    Unwatch(i64),
+    Info,
+    List(Option<i64>, usize),
+    Memory(i64, usize),
+    Set(i64, i64),
+    Pc(i64),
+    RelativeBase(i64),
+    Input(Vec<i64>),
+    Ascii(String),
+    Clear,
+    Help,
+    Quit,
+}
+
+impl FromStr for DebugCommand {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let line = line.trim();
+        let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let args: Vec<&str> = rest.split([' ', ',']).filter(|a| !a.is_empty()).collect();
+        let command = match command {
+            "step" | "s" => DebugCommand::Step(optional(&args, 0)?.unwrap_or(1)),
+            "continue" | "c" => DebugCommand::Continue,
+            "break" | "b" => DebugCommand::Break(breakpoint(&args)?),
+            "delete" | "d" => DebugCommand::Delete(breakpoint(&args)?),
+            "watch" | "w" => DebugCommand::Watch(required(&args, 0, "address")?),
+            "unwatch" => DebugCommand::Unwatch(required(&args, 0, "address")?),
+            "info" | "i" => DebugCommand::Info,
+            "list" | "l" => {
+                DebugCommand::List(optional(&args, 0)?, optional(&args, 1)?.unwrap_or(10))
+            }
+            "mem" | "x" => DebugCommand::Memory(
+                required(&args, 0, "address")?,
+                optional(&args, 1)?.unwrap_or(8),
+            ),
+            "set" => {
+                DebugCommand::Set(required(&args, 0, "address")?, required(&args, 1, "value")?)
+            }
+            "pc" => DebugCommand::Pc(required(&args, 0, "address")?),
+            "rb" => DebugCommand::RelativeBase(required(&args, 0, "value")?),
+            "input" | "in" => DebugCommand::Input(
+                args.iter()
+                    .map(|a| a.parse().map_err(|_| anyhow!("invalid input value '{a}'")))
+                    .collect::<Result<_>>()?,
+            ),
+            "ascii" => DebugCommand::Ascii(rest.to_string()),
+            "clear" => DebugCommand::Clear,
+            "help" | "h" => DebugCommand::Help,
+            "quit" | "q" => DebugCommand::Quit,
+            _ => {
+                return Err(anyhow!(
+                    "unknown command '{command}', type help for commands"
+                ));
+            }
+        };
+        Ok(command)
+    }
+}
+
+fn optional<T: FromStr>(args: &[&str], index: usize) -> Result<Option<T>> {
+    args.get(index)
+        .map(|a| a.parse().map_err(|_| anyhow!("invalid argument '{a}'")))
+        .transpose()
+}
+
+fn required<T: FromStr>(args: &[&str], index: usize, name: &str) -> Result<T> {
+    optional(args, index)?.ok_or_else(|| anyhow!("missing {name}"))
+}
+
+fn breakpoint(args: &[&str]) -> Result<Breakpoint> {
+    let arg = args.first().ok_or(anyhow!("missing address or opcode"))?;
+    match (arg.parse(), Opcode::from_mnemonic(arg)) {
+        (Ok(address), _) => Ok(Breakpoint::Address(address)),
+        (_, Some(opcode)) => Ok(Breakpoint::Opcode(opcode)),
+        _ => Err(anyhow!("invalid address or opcode '{arg}'")),
+    }
+}
+
+/// Reads commands from `input` until quit or end of input.
+pub fn repl(debugger: &mut Debugger, input: impl BufRead, mut out: impl Write) -> Result<()> {
+    writeln!(out, "Intcode debugger, type help for commands")?;
+    print_location(debugger, &mut out)?;
+    let mut last_command: Option<DebugCommand> = None;
+    let mut lines = input.lines();
+    loop {
+        write!(out, "(icdb) ")?;
+        out.flush()?;
+        let Some(line) = lines.next() else {
+            writeln!(out)?;
+            break;
+        };
+        let line = line?;
+        let command = if line.trim().is_empty() {
+            match &last_command {
+                Some(command) => command.clone(),
+                None => continue,
+            }
+        } else {
+            match line.parse::<DebugCommand>() {
+                Ok(command) => command,
+                Err(err) => {
+                    writeln!(out, "{err}")?;
+                    continue;
+                }
+            }
+        };
+        if command == DebugCommand::Quit {
+            break;
+        }
+        execute(debugger, &command, &mut out)?;
+        last_command = Some(command);
+    }
+    Ok(())
+}
+
+fn execute(debugger: &mut Debugger, command: &DebugCommand, out: &mut impl Write) -> Result<()> {
+    match command {
+        DebugCommand::Step(steps) => {
+            let reason = debugger.step(*steps);
+            print_output(debugger, out)?;
+            writeln!(out, "{reason}")?;
+            print_location(debugger, out)?;
+        }
+        DebugCommand::Continue => {
+            let reason = debugger.run();
+            print_output(debugger, out)?;
+            writeln!(out, "{reason}")?;
+            print_location(debugger, out)?;
+        }
+        DebugCommand::Break(breakpoint) => {
+            debugger.add_breakpoint(*breakpoint);
+        }
+        DebugCommand::Delete(breakpoint) => {
+            if !debugger.remove_breakpoint(*breakpoint) {
+                writeln!(out, "no breakpoint at {breakpoint}")?;
+            }
+        }
+        DebugCommand::Watch(address) => {
+            debugger.add_watchpoint(*address);
+        }
+        DebugCommand::Unwatch(address) => {
+            if !debugger.remove_watchpoint(*address) {
+                writeln!(out, "no watchpoint at {address}")?;
+            }
+        }
+        DebugCommand::Info => {
+            let computer = debugger.computer();
+            writeln!(
+                out,
+                "pc {}, relative base {}, steps {}",
+                computer.pc(),
+                computer.relative_base(),
+                debugger.steps()
+            )?;
+            writeln!(out, "input: {}", join(debugger.input()))?;
+            let breakpoints: Vec<String> = debugger.breakpoints().map(|b| b.to_string()).collect();
+            writeln!(out, "breakpoints: {}", breakpoints.join(", "))?;
+            writeln!(out, "watchpoints: {}", join(debugger.watchpoints()))?;
+        }
+        DebugCommand::List(address, count) => {
+            let address = address.unwrap_or(debugger.computer().pc());
+            write!(out, "{}", debugger.listing(address, *count))?;
+        }
+        DebugCommand::Memory(address, count) => {
+            let values: Vec<i64> = (*address..*address + *count as i64)
+                .map(|a| debugger.computer().get_address(a))
+                .collect();
+            for (index, chunk) in values.chunks(8).enumerate() {
+                writeln!(out, "{:>5}: {}", address + index as i64 * 8, join(chunk))?;
+            }
+        }
+        DebugCommand::Set(address, value) => debugger.computer_mut().set_address(*address, *value),
+        DebugCommand::Pc(address) => {
+            debugger.computer_mut().set_pc(*address);
+            print_location(debugger, out)?;
+        }
+        DebugCommand::RelativeBase(value) => debugger.computer_mut().set_relative_base(*value),
+        DebugCommand::Input(values) => debugger.push_input(values.iter().copied()),
+        DebugCommand::Ascii(text) => {
+            debugger.push_input(text.chars().chain(['\n']).map(|c| c as i64));
+        }
+        DebugCommand::Clear => debugger.input_mut().clear(),
+        DebugCommand::Help => writeln!(out, "{HELP}")?,
+        DebugCommand::Quit => (),
+    }
+    Ok(())
+}
+
+fn join<'a>(values: impl IntoIterator<Item = &'a i64>) -> String {
+    let values: Vec<String> = values.into_iter().map(|v| v.to_string()).collect();
+    values.join(", ")
+}
+
+/// Prints output as text, if it is ascii, otherwise as values.
+fn print_output(debugger: &mut Debugger, out: &mut impl Write) -> Result<()> {
+    let output = debugger.take_output();
+    if output.is_empty() {
+        return Ok(());
+    }
+    let text: Option<String> = output
+        .iter()
+        .map(|v| {
+            u8::try_from(*v)
+                .ok()
+                .filter(|b| b.is_ascii_graphic() || *b == b' ' || *b == b'\n')
+                .map(char::from)
+        })
+        .collect();
+    match text {
+        Some(text) if text.ends_with('\n') => write!(out, "{text}")?,
+        Some(text) => writeln!(out, "{text}")?,
+        None => writeln!(out, "output: {}", join(&output))?,
+    }
+    Ok(())
+}
+
+fn print_location(debugger: &Debugger, out: &mut impl Write) -> Result<()> {
+    let line = debugger.listing(debugger.computer().pc(), 1);
+    match line.lines.first() {
+        Some(line) => writeln!(out, "=> {line}")?,
+        None => writeln!(out, "=> {}: outside of program", debugger.computer().pc())?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2019::intcode::IntCodeComputer;
+
+    #[test]
+    fn test_parse_debug_command() -> Result<()> {
+        assert_eq!("s".parse::<DebugCommand>()?, DebugCommand::Step(1));
+        assert_eq!("step 20".parse::<DebugCommand>()?, DebugCommand::Step(20));
+        assert_eq!(
+            "b 12".parse::<DebugCommand>()?,
+            DebugCommand::Break(Breakpoint::Address(12))
+        );
+        assert_eq!(
+            "b out".parse::<DebugCommand>()?,
+            DebugCommand::Break(Breakpoint::Opcode(Opcode::Output))
+        );
+        assert_eq!(
+            "in 1, -2 3".parse::<DebugCommand>()?,
+            DebugCommand::Input(vec![1, -2, 3])
+        );
+        assert_eq!(
+            "ascii NOT A J".parse::<DebugCommand>()?,
+            DebugCommand::Ascii("NOT A J".into())
+        );
+        assert_eq!(
+            "x 100".parse::<DebugCommand>()?,
+            DebugCommand::Memory(100, 8)
+        );
+        assert!("b foo".parse::<DebugCommand>().is_err());
+        assert!("set 1".parse::<DebugCommand>().is_err());
+        assert!("jump".parse::<DebugCommand>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_repl() -> Result<()> {
+        // counts [20] from input down to zero and outputs each value
+        let mut debugger = Debugger::from(IntCodeComputer::from(
+            "3,20,4,20,1001,20,-1,20,1005,20,2,99",
+        ));
+        let commands = "in 300\nb out\nc\n\nd out\nset 20 1\nw 20\nc\nc\ninfo\nq\n";
+        let mut out: Vec<u8> = Vec::new();
+        repl(&mut debugger, commands.as_bytes(), &mut out)?;
+        let out = String::from_utf8(out)?;
+        let lines: Vec<&str> = out.split("(icdb) ").flat_map(|o| o.lines()).collect();
+        assert_eq!(
+            lines,
+            [
+                "Intcode debugger, type help for commands",
+                "=>     0: in [20]                      ; 3,20",
+                "breakpoint at opcode out",
+                "=>     2: out [20]                     ; 4,20",
+                "output: 300",
+                "breakpoint at opcode out",
+                "=>     2: out [20]                     ; 4,20",
+                "output: 1",
+                "watchpoint [20] changed from 1 to 0",
+                "=>     8: jt [20], #2                  ; 1005,20,2",
+                "halted",
+                "=>    11: hlt                          ; 99",
+                "pc 11, relative base 0, steps 7",
+                "input: ",
+                "breakpoints: ",
+                "watchpoints: 20",
+            ]
+        );
+        Ok(())
+    }
+}
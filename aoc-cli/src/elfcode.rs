@@ -0,0 +1,40 @@
+//!elfcode.rs
+
+use crate::cli::{DecompileArgs, ElfcodeArgs, ElfcodeCommand, ProfileArgs};
+use anyhow::{Context, Result};
+use aoc_2018::elfcode::{ElfcodeMachine, Program, decompile};
+use std::path::Path;
+
+/// Returns false, if the elfcode tool failed.
+pub fn elfcode(args: &ElfcodeArgs) -> Result<bool> {
+    match &args.command {
+        ElfcodeCommand::Decompile(args) => decompile_file(args),
+        ElfcodeCommand::Profile(args) => profile_file(args),
+    }
+}
+
+fn read_program(file: &Path) -> Result<Program> {
+    let source = std::fs::read_to_string(file)
+        .with_context(|| format!("failed to read elfcode file {}", file.display()))?;
+    Program::parse(source.trim())
+        .with_context(|| format!("invalid elfcode file {}", file.display()))
+}
+
+fn decompile_file(args: &DecompileArgs) -> Result<bool> {
+    let program = read_program(&args.file)?;
+    print!("{}", decompile(&program));
+    Ok(true)
+}
+
+fn profile_file(args: &ProfileArgs) -> Result<bool> {
+    let program = read_program(&args.file)?;
+    let mut machine = ElfcodeMachine::new(&program);
+    machine.registers[0] = args.register_0;
+    let status = machine.run(args.budget);
+    println!("status: {status:?}");
+    println!("registers: {:?}", machine.registers);
+    let mut profile = machine.profile();
+    profile.hot.truncate(args.top);
+    print!("{profile}");
+    Ok(true)
+}
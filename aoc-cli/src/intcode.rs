@@ -0,0 +1,48 @@
+//!intcode.rs
+
+use crate::cli::{AsmArgs, DebugArgs, DisasmArgs, IntcodeArgs, IntcodeCommand};
+use crate::debug::repl;
+use anyhow::{Context, Result, anyhow};
+use aoc_2019::intcode::{Debugger, IntCodeComputer, assemble};
+use std::path::Path;
+
+/// Returns false, if the Intcode tool failed.
+pub fn intcode(args: &IntcodeArgs) -> Result<bool> {
+    match &args.command {
+        IntcodeCommand::Disasm(args) => disasm(args),
+        IntcodeCommand::Asm(args) => asm(args),
+        IntcodeCommand::Debug(args) => debug(args),
+    }
+}
+
+fn load_program(file: &Path) -> Result<IntCodeComputer> {
+    let program = std::fs::read_to_string(file)
+        .with_context(|| format!("failed to read Intcode file {}", file.display()))?;
+    let code = IntCodeComputer::from(program.trim());
+    if code.memory().is_empty() {
+        return Err(anyhow!("no Intcode program found in {}", file.display()));
+    }
+    Ok(code)
+}
+
+fn disasm(args: &DisasmArgs) -> Result<bool> {
+    let code = load_program(&args.file)?;
+    print!("{}", code.disassemble());
+    Ok(true)
+}
+
+fn asm(args: &AsmArgs) -> Result<bool> {
+    let source = std::fs::read_to_string(&args.file)
+        .with_context(|| format!("failed to read assembly file {}", args.file.display()))?;
+    let assembly =
+        assemble(&source).with_context(|| format!("failed to assemble {}", args.file.display()))?;
+    println!("{assembly}");
+    Ok(true)
+}
+
+fn debug(args: &DebugArgs) -> Result<bool> {
+    let mut debugger = Debugger::from(load_program(&args.file)?);
+    debugger.push_input(args.input.iter().copied());
+    repl(&mut debugger, std::io::stdin().lock(), std::io::stdout())?;
+    Ok(true)
+}
@@ -0,0 +1,58 @@
+//!main.rs
+
+mod answers;
+mod bench;
+mod cli;
+mod debug;
+mod elfcode;
+mod intcode;
+mod run;
+mod table;
+
+use anyhow::Result;
+use aoc_common::{Registry, set_input_dir};
+use clap::Parser;
+use cli::{Cli, Command};
+use std::process::ExitCode;
+
+/// Solvers of all years.
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .extend(aoc_2015::registry())
+        .extend(aoc_2016::registry())
+        .extend(aoc_2017::registry())
+        .extend(aoc_2018::registry())
+        .extend(aoc_2019::registry())
+        .extend(aoc_2020::registry())
+        .extend(aoc_2021::registry())
+        .extend(aoc_2022::registry())
+        .extend(aoc_2023::registry())
+        .extend(aoc_2024::registry())
+        .extend(aoc_2025::registry());
+    registry
+}
+
+/// Returns false, if any selected day failed.
+fn execute(cli: Cli) -> Result<bool> {
+    if let Some(dir) = cli.input_dir {
+        set_input_dir(dir)?;
+    }
+    match cli.command {
+        Command::Run(args) => run::run(&registry(), &args),
+        Command::Bench(args) => bench::bench(&registry(), &args),
+        Command::Intcode(args) => intcode::intcode(&args),
+        Command::Elfcode(args) => elfcode::elfcode(&args),
+    }
+}
+
+fn main() -> ExitCode {
+    match execute(Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Error occurred: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//!run.rs
+
+use crate::answers::{AnswerDb, DEFAULT_INPUT_ID, Verdict};
+use crate::cli::RunArgs;
+use crate::table::print_table;
+use anyhow::{Result, anyhow};
+use aoc_common::{Registry, Solver, SolverFactory, read_file, read_input, read_path};
+use std::any::Any;
+use std::fmt::Display;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::time::{Duration, Instant};
+
+/// Outcome of one part of a day.
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+    /// Part has not been selected or does not exist (day 25).
+    Skipped,
+}
+
+impl Outcome {
+    fn error(err: impl Display) -> Self {
+        Outcome::Error(format!("{err:#}"))
+    }
+
+    fn is_error(&self) -> bool {
+        matches!(self, Outcome::Error(_))
+    }
+
+    fn cell(&self, verdict: &Verdict) -> String {
+        match self {
+            Outcome::Answer(answer) if answer.contains('\n') => format!("see below ({verdict})"),
+            Outcome::Answer(answer) => match verdict {
+                Verdict::Fail(expected) if !expected.contains('\n') => {
+                    format!("{answer} (FAIL, expected {expected})")
+                }
+                _ => format!("{answer} ({verdict})"),
+            },
+            Outcome::Error(err) => format!("ERROR: {err}"),
+            Outcome::Skipped => "-".into(),
+        }
+    }
+}
+
+impl From<Result<String>> for Outcome {
+    fn from(value: Result<String>) -> Self {
+        match value {
+            Ok(answer) => Outcome::Answer(answer),
+            Err(err) => Outcome::error(err),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub part_1: Outcome,
+    pub part_2: Outcome,
+    pub elapsed: Duration,
+    /// Verdicts of part 1 and 2, only meaningful for answers.
+    pub verdicts: [Verdict; 2],
+}
+
+impl DayResult {
+    fn new(year: u16, day: u8, part_1: Outcome, part_2: Outcome, elapsed: Duration) -> Self {
+        DayResult {
+            year,
+            day,
+            part_1,
+            part_2,
+            elapsed,
+            verdicts: [Verdict::Unknown, Verdict::Unknown],
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.part_1.is_error() || self.part_2.is_error()
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.is_error() || self.verdicts.iter().any(|v| v.is_fail())
+    }
+
+    fn answers(&self) -> impl Iterator<Item = (u8, &String, &Verdict)> {
+        [(1, &self.part_1), (2, &self.part_2)]
+            .into_iter()
+            .zip(self.verdicts.iter())
+            .filter_map(|((part, outcome), verdict)| match outcome {
+                Outcome::Answer(answer) => Some((part, answer, verdict)),
+                _ => None,
+            })
+    }
+
+    /// Compares answers with answer database. Answers of the input directory, which are not
+    /// in the database, are compared with an expected answer file next to the input, if any.
+    pub fn verify(&mut self, db: &AnswerDb, input_id: &str) {
+        for (index, outcome) in [&self.part_1, &self.part_2].into_iter().enumerate() {
+            if let Outcome::Answer(answer) = outcome {
+                let part = index as u8 + 1;
+                let mut verdict = db.verify(self.year, self.day, input_id, part, answer);
+                if verdict == Verdict::Unknown && input_id == DEFAULT_INPUT_ID {
+                    verdict = verify_expected_file(self.year, self.day, part, answer);
+                }
+                self.verdicts[index] = verdict;
+            }
+        }
+    }
+
+    /// Stores unknown answers in answer database. Returns number of new answers.
+    pub fn record(&self, db: &mut AnswerDb, input_id: &str) -> usize {
+        let mut recorded = 0;
+        for (part, answer, verdict) in self.answers() {
+            if *verdict == Verdict::Unknown {
+                db.set(self.year, self.day, input_id, part, answer);
+                recorded += 1;
+            }
+        }
+        recorded
+    }
+}
+
+/// Multi line answers (e.g. letters rendered by 2019 days 08 and 11) are kept in files like
+/// `day_08_expected_part_2.txt` of the input directory.
+fn verify_expected_file(year: u16, day: u8, part: u8, answer: &str) -> Verdict {
+    match read_file(year, &format!("day_{day:02}_expected_part_{part}.txt")) {
+        Ok(expected) if expected == answer => Verdict::Pass,
+        Ok(expected) => Verdict::Fail(expected.to_string()),
+        Err(_) => Verdict::Unknown,
+    }
+}
+
+/// Runs all selected days, compares answers with answer database and prints result table.
+/// Returns false, if any day failed or gave a wrong answer.
+pub fn run(registry: &Registry, args: &RunArgs) -> Result<bool> {
+    let selected: Vec<(u16, u8, SolverFactory)> = registry
+        .iter()
+        .filter(|(year, day, _)| args.selection.contains(*year, *day))
+        .collect();
+    if selected.is_empty() {
+        return Err(anyhow!("no solver found for selection"));
+    }
+    let max_runtime = args.runtime_limit.max_runtime(&args.selection);
+    let (selected, slow): (Vec<_>, Vec<_>) = selected.into_iter().partition(|(_, _, factory)| {
+        max_runtime.is_none_or(|max| factory().expected_runtime() <= max)
+    });
+    let mut db = AnswerDb::load(&args.answers)?;
+    let input_id = args.input_id();
+
+    let mut results = Vec::with_capacity(selected.len());
+    for (year, day, factory) in selected {
+        let input = match &args.input {
+            Some(path) => read_path(path),
+            None => read_input(year, day),
+        };
+        let mut result = match input {
+            Ok(input) => execute_day(year, day, factory, input, args.part),
+            Err(err) => DayResult::new(
+                year,
+                day,
+                Outcome::error(err),
+                Outcome::Skipped,
+                Duration::ZERO,
+            ),
+        };
+        result.verify(&db, &input_id);
+        results.push(result);
+    }
+
+    if !results.is_empty() {
+        print_results(&results);
+    }
+    if let (Some(max), false) = (max_runtime, slow.is_empty()) {
+        let days: Vec<String> = slow
+            .iter()
+            .map(|(year, day, _)| format!("{year} day {day:02}"))
+            .collect();
+        println!(
+            "skipped {} days with expected run time above {max:?}: {}",
+            slow.len(),
+            days.join(", ")
+        );
+        println!("use --include-slow or --max-runtime to run them");
+    }
+    let failed = results.iter().filter(|r| r.is_failed()).count();
+    if failed > 0 {
+        println!("{failed} of {} days failed", results.len());
+    }
+    let unknown = results
+        .iter()
+        .flat_map(|r| r.answers())
+        .filter(|(_, _, verdict)| **verdict == Verdict::Unknown)
+        .count();
+    if args.record {
+        let recorded: usize = results.iter().map(|r| r.record(&mut db, &input_id)).sum();
+        if recorded > 0 {
+            db.save(&args.answers)?;
+            println!(
+                "recorded {recorded} new answers in {}",
+                args.answers.display()
+            );
+        }
+    } else if unknown > 0 {
+        println!("{unknown} answers unknown, verify them and store them with --record");
+    }
+    Ok(failed == 0)
+}
+
+/// Executes one day. Panics of solvers are caught and reported as errors.
+pub fn execute_day(
+    year: u16,
+    day: u8,
+    factory: SolverFactory,
+    input: &'static str,
+    part: Option<u8>,
+) -> DayResult {
+    let start = Instant::now();
+    let mut solver = factory();
+    let (part_1, part_2) = catch_unwind(AssertUnwindSafe(|| solve(solver.as_mut(), input, part)))
+        .unwrap_or_else(|payload| (Outcome::Error(panic_message(payload)), Outcome::Skipped));
+    DayResult::new(year, day, part_1, part_2, start.elapsed())
+}
+
+/// Message of a caught panic.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".into());
+    format!("panicked: {message}")
+}
+
+fn solve(solver: &mut dyn Solver, input: &'static str, part: Option<u8>) -> (Outcome, Outcome) {
+    if let Err(err) = solver.parse(input) {
+        return (Outcome::error(err), Outcome::Skipped);
+    }
+    let part_1 = Outcome::from(solver.part_1());
+    // part 2 depends on part 1
+    if part == Some(1) || part_1.is_error() {
+        return (part_1, Outcome::Skipped);
+    }
+    let part_2 = solver.part_2().map_or(Outcome::Skipped, Outcome::from);
+    if part == Some(2) {
+        return (Outcome::Skipped, part_2);
+    }
+    (part_1, part_2)
+}
+
+fn print_results(results: &[DayResult]) {
+    let rows: Vec<[String; 5]> = results
+        .iter()
+        .map(|r| {
+            [
+                r.year.to_string(),
+                format!("{:02}", r.day),
+                r.part_1.cell(&r.verdicts[0]),
+                r.part_2.cell(&r.verdicts[1]),
+                format!("{:.2?}", r.elapsed),
+            ]
+        })
+        .collect();
+    print_table(["year", "day", "part 1", "part 2", "time"], &rows);
+
+    // answers with multiple lines, e.g. letters drawn on a screen
+    for r in results.iter() {
+        for (part, answer, verdict) in r.answers() {
+            let expected = match verdict {
+                Verdict::Fail(expected) => Some(expected),
+                _ => None,
+            };
+            if answer.contains('\n') || expected.is_some_and(|e| e.contains('\n')) {
+                println!("\n{} day {:02} part {part}:\n{answer}", r.year, r.day);
+                if let Some(expected) = expected {
+                    println!("expected:\n{expected}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//!table.rs
+
+/// Prints a markdown like table with left aligned columns.
+pub fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(|h| h.chars().count());
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let print_row = |row: &[&str; N]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("| {} |", cells.join(" | "));
+    };
+    print_row(&header);
+    let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    println!("|-{}-|", separator.join("-|-"));
+    for row in rows.iter() {
+        print_row(&row.each_ref().map(|cell| cell.as_str()));
+    }
+}
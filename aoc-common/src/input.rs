@@ -0,0 +1,58 @@
+//!input.rs
+
+// Puzzle inputs and example snippets are hidden in a private repository. They are
+// loaded at run time from an input directory with the layout
+// `<input_dir>/aoc-<year>/day_<day>.txt`. The input directory is chosen by
+// 1. set_input_dir(), e.g. from a command line flag,
+// 2. environment variable AOC_INPUT_DIR,
+// 3. default: aoc_input next to this workspace.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../aoc_input");
+
+static INPUT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Overrides the input directory for this process. Must be called before the first input is read.
+pub fn set_input_dir(dir: impl Into<PathBuf>) -> Result<()> {
+    let dir = dir.into();
+    INPUT_DIR
+        .set(dir)
+        .map_err(|dir| anyhow::anyhow!("input directory already set, ignoring {}", dir.display()))
+}
+
+/// Returns the input directory of this process.
+pub fn input_dir() -> PathBuf {
+    INPUT_DIR
+        .get_or_init(|| {
+            std::env::var_os(INPUT_DIR_ENV)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+        })
+        .clone()
+}
+
+/// Reads puzzle input of given year and day.
+pub fn read_input(year: u16, day: u8) -> Result<&'static str> {
+    read_file(year, &format!("day_{day:02}.txt"))
+}
+
+/// Reads any file of given year from input directory, e.g. examples.
+///
+/// Content is leaked to `&'static str`, which keeps the semantics of the former
+/// `include_str!()`. Each file is read once per run, so this is no issue.
+pub fn read_file(year: u16, file_name: &str) -> Result<&'static str> {
+    read_path(input_dir().join(format!("aoc-{year}")).join(file_name))
+}
+
+/// Reads input from any path, e.g. an input given on the command line.
+pub fn read_path(path: impl AsRef<Path>) -> Result<&'static str> {
+    let path = path.as_ref();
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read input file {}", path.display()))?;
+    Ok(Box::leak(content.into_boxed_str()))
+}
@@ -0,0 +1,10 @@
+//!lib.rs
+
+pub mod input;
+pub mod register_machine;
+pub mod registry;
+pub mod solution;
+
+pub use input::{input_dir, read_file, read_input, read_path, set_input_dir};
+pub use registry::{Registry, SolverFactory};
+pub use solution::{LONG_RUNTIME, Solution, Solver, solver};
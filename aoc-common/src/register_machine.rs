@@ -0,0 +1,256 @@
+//!register_machine.rs
+
+//! Small register machine, which is shared by the assembly like days (e.g. 2015 day 23,
+//! 2016 day 12, 2017 day 18 and 2020 day 08). Each day defines its own instruction set
+//! as dialect by implementing [`InstructionSet`].
+
+use std::ops::{Index, IndexMut};
+
+/// Registers 'a' to 'z'.
+pub const NUM_REGISTERS: usize = 26;
+
+/// Register file with fixed size, which is indexed by register name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Registers([i64; NUM_REGISTERS]);
+
+fn register_index(register: char) -> usize {
+    assert!(register.is_ascii_lowercase(), "unknown register {register}");
+    (register as u8 - b'a') as usize
+}
+
+impl Index<char> for Registers {
+    type Output = i64;
+
+    fn index(&self, register: char) -> &Self::Output {
+        &self.0[register_index(register)]
+    }
+}
+
+impl IndexMut<char> for Registers {
+    fn index_mut(&mut self, register: char) -> &mut Self::Output {
+        &mut self.0[register_index(register)]
+    }
+}
+
+/// Operand of instructions, which is either a number or a register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    Digit(i64),
+    Char(char),
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        let c = value.chars().next().unwrap();
+        if c.is_alphabetic() {
+            Value::Char(c)
+        } else {
+            Value::Digit(value.parse().unwrap())
+        }
+    }
+}
+
+impl Value {
+    pub fn value(&self, registers: &Registers) -> i64 {
+        match self {
+            Value::Char(reg) => registers[*reg],
+            Value::Digit(digit) => *digit,
+        }
+    }
+    pub fn register(&self) -> Option<char> {
+        if let Value::Char(c) = *self {
+            Some(c)
+        } else {
+            None
+        }
+    }
+}
+
+/// Control flow after executing one instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// continue at pc + offset
+    Jump(i64),
+    /// continue at pc + offset, but return from run(), e.g. after output of a value
+    Yield(i64),
+    /// stay at pc and return from run(), e.g. if instruction waits for input
+    Block,
+}
+
+impl Flow {
+    pub const NEXT: Flow = Flow::Jump(1);
+}
+
+/// Result of step() and run().
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// only returned by step()
+    Running,
+    /// pc left program
+    Halted,
+    Yielded,
+    Blocked,
+    /// trace hook stopped execution
+    Interrupted,
+    OutOfBudget,
+}
+
+/// Instruction set of one dialect.
+pub trait InstructionSet: Copy {
+    /// State of dialect besides registers and pc, e.g. queues of sent values.
+    type State: Default;
+
+    /// Executes instruction at machine.pc. Dialects may change the program, e.g. to
+    /// toggle instructions.
+    fn execute(self, machine: &mut Machine<Self>) -> Flow;
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine<I: InstructionSet> {
+    pub program: Vec<I>,
+    pub registers: Registers,
+    pub pc: i64,
+    pub state: I::State,
+    /// number of executed instructions without blocked ones
+    pub steps: u64,
+}
+
+impl<I: InstructionSet> Machine<I> {
+    pub fn new(program: Vec<I>) -> Self {
+        Self {
+            program,
+            registers: Registers::default(),
+            pc: 0,
+            state: I::State::default(),
+            steps: 0,
+        }
+    }
+    pub fn instruction(&self) -> Option<I> {
+        usize::try_from(self.pc)
+            .ok()
+            .and_then(|pc| self.program.get(pc))
+            .copied()
+    }
+    pub fn step(&mut self) -> Status {
+        let Some(instruction) = self.instruction() else {
+            return Status::Halted;
+        };
+        let flow = instruction.execute(self);
+        match flow {
+            Flow::Jump(offset) => {
+                self.steps += 1;
+                self.pc += offset;
+                Status::Running
+            }
+            Flow::Yield(offset) => {
+                self.steps += 1;
+                self.pc += offset;
+                Status::Yielded
+            }
+            Flow::Block => Status::Blocked,
+        }
+    }
+    /// Runs at most budget instructions.
+    pub fn run(&mut self, budget: u64) -> Status {
+        self.run_traced(budget, |_| true)
+    }
+    /// Runs at most budget instructions and calls trace before each instruction. Execution
+    /// stops, if trace returns false.
+    pub fn run_traced(&mut self, budget: u64, mut trace: impl FnMut(&Self) -> bool) -> Status {
+        for _ in 0..budget {
+            if self.instruction().is_some() && !trace(self) {
+                return Status::Interrupted;
+            }
+            match self.step() {
+                Status::Running => (),
+                status => return status,
+            }
+        }
+        if self.instruction().is_some() {
+            Status::OutOfBudget
+        } else {
+            Status::Halted
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy)]
+    enum Countdown {
+        Set(char, Value),
+        Dec(char),
+        Out(char),
+        Jnz(Value, i64),
+        In(char),
+    }
+
+    #[derive(Debug, Default)]
+    struct Io {
+        input: Option<i64>,
+        output: Vec<i64>,
+    }
+
+    impl InstructionSet for Countdown {
+        type State = Io;
+
+        fn execute(self, machine: &mut Machine<Self>) -> Flow {
+            match self {
+                Countdown::Set(reg, val) => machine.registers[reg] = val.value(&machine.registers),
+                Countdown::Dec(reg) => machine.registers[reg] -= 1,
+                Countdown::Out(reg) => {
+                    machine.state.output.push(machine.registers[reg]);
+                    return Flow::Yield(1);
+                }
+                Countdown::Jnz(val, offset) if val.value(&machine.registers) != 0 => {
+                    return Flow::Jump(offset);
+                }
+                Countdown::Jnz(..) => (),
+                Countdown::In(reg) => match machine.state.input.take() {
+                    Some(input) => machine.registers[reg] = input,
+                    None => return Flow::Block,
+                },
+            }
+            Flow::NEXT
+        }
+    }
+
+    #[test]
+    fn test_register_machine() {
+        let program = vec![
+            Countdown::Set('a', Value::from("3")),
+            Countdown::Out('a'),
+            Countdown::Dec('a'),
+            Countdown::Jnz(Value::from("a"), -2),
+            Countdown::In('z'),
+        ];
+        let mut machine = Machine::new(program);
+        assert_eq!(machine.run(1), Status::OutOfBudget);
+        assert_eq!(machine.run(100), Status::Yielded);
+        assert_eq!(machine.state.output, [3]);
+        while machine.run(100) == Status::Yielded {}
+        assert_eq!(machine.state.output, [3, 2, 1]);
+        assert_eq!(machine.pc, 4);
+        assert_eq!(machine.run(100), Status::Blocked);
+        machine.state.input = Some(42);
+        assert_eq!(machine.run(100), Status::Halted);
+        assert_eq!(machine.registers['z'], 42);
+        assert_eq!(machine.steps, 11);
+
+        // trace stops before first execution of pc 2
+        let mut machine = Machine::new(machine.program);
+        let mut trace = Vec::new();
+        let mut status = Status::Yielded;
+        while status == Status::Yielded {
+            status = machine.run_traced(100, |m| {
+                trace.push(m.pc);
+                m.pc != 2
+            });
+        }
+        assert_eq!(status, Status::Interrupted);
+        assert_eq!(trace, [0, 1, 2]);
+    }
+}
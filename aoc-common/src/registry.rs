@@ -0,0 +1,77 @@
+//!registry.rs
+
+use crate::solution::{Solution, Solver, solver};
+use std::collections::BTreeMap;
+
+pub type SolverFactory = fn() -> Box<dyn Solver>;
+
+/// Maps (year, day) to a factory of the boxed solver of this day.
+///
+/// Alternative implementations of a day are registered as named variants. They are not
+/// part of [`Registry::iter`], but can be compared with the main solver, e.g. in benchmarks.
+#[derive(Default, Clone)]
+pub struct Registry {
+    days: BTreeMap<(u16, u8), SolverFactory>,
+    variants: BTreeMap<(u16, u8, &'static str), SolverFactory>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, year: u16, day: u8) -> &mut Self {
+        self.days.insert((year, day), solver::<S>);
+        self
+    }
+
+    pub fn register_variant<S: Solution + 'static>(
+        &mut self,
+        year: u16,
+        day: u8,
+        variant: &'static str,
+    ) -> &mut Self {
+        self.variants.insert((year, day, variant), solver::<S>);
+        self
+    }
+
+    pub fn extend(&mut self, other: Registry) -> &mut Self {
+        self.days.extend(other.days);
+        self.variants.extend(other.variants);
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<Box<dyn Solver>> {
+        self.days.get(&(year, day)).map(|factory| factory())
+    }
+
+    /// Iterates in order of (year, day).
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u8, SolverFactory)> + '_ {
+        self.days
+            .iter()
+            .map(|(&(year, day), &factory)| (year, day, factory))
+    }
+
+    /// Iterates variants in order of (year, day, variant).
+    pub fn iter_variants(
+        &self,
+    ) -> impl Iterator<Item = (u16, u8, &'static str, SolverFactory)> + '_ {
+        self.variants
+            .iter()
+            .map(|(&(year, day, variant), &factory)| (year, day, variant, factory))
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.days.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn len(&self) -> usize {
+        self.days.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+}
@@ -0,0 +1,84 @@
+//!solution.rs
+
+use anyhow::{Result, anyhow};
+use std::fmt::Display;
+use std::time::Duration;
+
+/// Expected run time of days, which used to be excluded by feature `long-run-time`.
+pub const LONG_RUNTIME: Duration = Duration::from_secs(60);
+
+/// Common interface of all day modules.
+///
+/// Input is `&'static str` (see [`crate::read_input`]), which allows challenges to borrow from it.
+/// part_2() is always called after part_1() on the same instance, therefore part 2 may
+/// depend on state, which has been computed in part 1.
+pub trait Solution: Sized {
+    /// Day 25 has no part 2.
+    const HAS_PART_2: bool = true;
+
+    /// Rough run time of parse, part 1 and part 2 in release mode. The runner skips days,
+    /// which exceed its max run time. Fast days keep the default.
+    const EXPECTED_RUNTIME: Duration = Duration::ZERO;
+
+    fn parse(input: &'static str) -> Result<Self>;
+
+    fn part_1(&mut self) -> Result<impl Display>;
+
+    fn part_2(&mut self) -> Result<impl Display> {
+        Err::<String, _>(anyhow!("no part 2"))
+    }
+}
+
+/// Object safe version of [`Solution`], which is used by [`crate::Registry`].
+pub trait Solver {
+    fn parse(&mut self, input: &'static str) -> Result<()>;
+
+    fn part_1(&mut self) -> Result<String>;
+
+    /// Returns None, if day has no part 2.
+    fn part_2(&mut self) -> Option<Result<String>>;
+
+    fn expected_runtime(&self) -> Duration;
+}
+
+struct SolutionSolver<S: Solution> {
+    challenge: Option<S>,
+}
+
+impl<S: Solution> SolutionSolver<S> {
+    fn challenge(&mut self) -> Result<&mut S> {
+        self.challenge
+            .as_mut()
+            .ok_or_else(|| anyhow!("input has not been parsed"))
+    }
+}
+
+impl<S: Solution> Solver for SolutionSolver<S> {
+    fn parse(&mut self, input: &'static str) -> Result<()> {
+        self.challenge = Some(S::parse(input)?);
+        Ok(())
+    }
+
+    fn part_1(&mut self) -> Result<String> {
+        Ok(self.challenge()?.part_1()?.to_string())
+    }
+
+    fn part_2(&mut self) -> Option<Result<String>> {
+        if !S::HAS_PART_2 {
+            return None;
+        }
+        Some(
+            self.challenge()
+                .and_then(|challenge| Ok(challenge.part_2()?.to_string())),
+        )
+    }
+
+    fn expected_runtime(&self) -> Duration {
+        S::EXPECTED_RUNTIME
+    }
+}
+
+/// Creates boxed [`Solver`] of given [`Solution`].
+pub fn solver<S: Solution + 'static>() -> Box<dyn Solver> {
+    Box::new(SolutionSolver::<S> { challenge: None })
+}